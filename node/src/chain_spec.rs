@@ -1,10 +1,16 @@
-use node_template_runtime::{AccountId, RuntimeGenesisConfig, Signature, WASM_BINARY};
+use node_template_runtime::{
+	pallet_game::{self, PropertyInfoData, PropertyMetadata, PropertyType, Role},
+	AccountId, GameModule, Runtime, RuntimeGenesisConfig, Signature, WASM_BINARY,
+};
 use sc_service::ChainType;
 use sc_telemetry::serde_json;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_core::{sr25519, Pair, Public};
-use sp_runtime::traits::{IdentifyAccount, Verify};
+use sp_runtime::{
+	traits::{IdentifyAccount, Verify},
+	BoundedVec,
+};
 
 // The URL for the telemetry server.
 // const STAGING_TELEMETRY_URL: &str = "wss://telemetry.polkadot.io/submit/";
@@ -52,6 +58,42 @@ pub fn get_pallet_account() -> AccountId {
 	pallet_account_id[0].clone()
 }
 
+/// Generate the properties that are available for games on the test chains.
+///
+/// The price of every property is 220_000 and the salt of a property is `realxdeal dev salt`
+/// followed by its id, e.g. `realxdeal dev salt 147229391`. The salts are fixed so that every
+/// node builds the same genesis, which means that anybody can settle games on these chains and
+/// they must never be used for a chain with real value.
+pub fn game_properties() -> Vec<PropertyInfoData<Runtime>> {
+	[
+		(147229391, "London", PropertyType::Flat, 2, 1_704_067_200),
+		(146480642, "Manchester", PropertyType::Terraced, 3, 1_706_745_600),
+		(147031382, "Bristol", PropertyType::SemiDetached, 3, 1_709_251_200),
+		(147095938, "Leeds", PropertyType::Detached, 4, 1_711_929_600),
+	]
+	.into_iter()
+	.map(|(id, region, property_type, bedrooms, listing_date)| {
+		let data: BoundedVec<u8, <Runtime as pallet_game::Config>::StringLimit> =
+			format!("realxdeal test property {}", id)
+				.into_bytes()
+				.try_into()
+				.expect("short; qed");
+		let salt = format!("realxdeal dev salt {}", id);
+		PropertyInfoData {
+			id,
+			commitment: GameModule::property_commitment(220_000, salt.as_bytes(), &data),
+			data,
+			metadata: PropertyMetadata {
+				region: region.as_bytes().to_vec().try_into().expect("short; qed"),
+				property_type,
+				bedrooms,
+				listing_date,
+			},
+		}
+	})
+	.collect()
+}
+
 pub fn development_config() -> Result<ChainSpec, String> {
 	Ok(ChainSpec::builder(
 		WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?,
@@ -73,6 +115,11 @@ pub fn development_config() -> Result<ChainSpec, String> {
 			get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
 			get_pallet_account(),
 		],
+		// Registered players
+		vec![
+			get_account_id_from_seed::<sr25519::Public>("Alice"),
+			get_account_id_from_seed::<sr25519::Public>("Bob"),
+		],
 		true,
 	))
 	.with_properties(chain_spec_properties())
@@ -108,6 +155,15 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 			get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
 			get_pallet_account(),
 		],
+		// Registered players
+		vec![
+			get_account_id_from_seed::<sr25519::Public>("Alice"),
+			get_account_id_from_seed::<sr25519::Public>("Bob"),
+			get_account_id_from_seed::<sr25519::Public>("Charlie"),
+			get_account_id_from_seed::<sr25519::Public>("Dave"),
+			get_account_id_from_seed::<sr25519::Public>("Eve"),
+			get_account_id_from_seed::<sr25519::Public>("Ferdie"),
+		],
 		true,
 	))
 	.with_properties(chain_spec_properties())
@@ -119,6 +175,7 @@ fn testnet_genesis(
 	initial_authorities: Vec<(AuraId, GrandpaId)>,
 	root_key: AccountId,
 	endowed_accounts: Vec<AccountId>,
+	players: Vec<AccountId>,
 	_enable_println: bool,
 ) -> serde_json::Value {
 	serde_json::json!({
@@ -134,7 +191,15 @@ fn testnet_genesis(
 		},
 		"sudo": {
 			// Assign network admin rights.
-			"key": Some(root_key),
+			"key": Some(root_key.clone()),
+		},
+		"gameModule": {
+			// The sudo account can register further players.
+			"roles": vec![(Role::Registrar, root_key)],
+			"properties": game_properties(),
+			// Every player starts with 50 points like after `register_user`.
+			"players": players.into_iter().map(|k| (k, 50)).collect::<Vec<_>>(),
+			"startRound": true,
 		},
 	})
 }
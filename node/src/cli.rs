@@ -1,3 +1,4 @@
+use crate::property_import::ImportPropertiesCmd;
 use sc_cli::RunCmd;
 
 #[derive(Debug, clap::Parser)]
@@ -51,4 +52,7 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Convert a JSON or CSV file of properties into `add_properties` calls or a genesis patch.
+	ImportProperties(ImportPropertiesCmd),
 }
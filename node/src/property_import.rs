@@ -0,0 +1,147 @@
+//! Command that converts a JSON or CSV file of properties into `add_properties` calls or into a
+//! genesis patch of the game pallet.
+//!
+//! Every entry of the file has the fields `id`, `data`, `price`, `salt`, `region`,
+//! `property_type`, `bedrooms` and `listing_date`. The price never leaves the machine, only the
+//! commitment of price, salt and data is written into the output.
+
+use node_template_runtime::{
+	pallet_game::{self, PropertyInfoData, PropertyMetadata, PropertyType},
+	GameModule, Runtime, RuntimeCall, SudoCall,
+};
+use sc_telemetry::serde_json;
+use sp_core::{bytes::to_hex, Encode, Get};
+use sp_runtime::BoundedVec;
+use std::{collections::BTreeSet, fs::File, path::PathBuf};
+
+type StringLimit = <Runtime as pallet_game::Config>::StringLimit;
+type MaxPropertiesPerBatch = <Runtime as pallet_game::Config>::MaxPropertiesPerBatch;
+
+/// A property as it is listed in the import file.
+#[derive(Debug, serde::Deserialize)]
+pub struct PropertyEntry {
+	pub id: u32,
+	pub data: String,
+	pub price: u64,
+	pub salt: String,
+	pub region: String,
+	pub property_type: PropertyType,
+	pub bedrooms: u8,
+	/// Unix timestamp in seconds of the date when the property has been listed.
+	pub listing_date: u64,
+}
+
+/// Format of the import file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum InputFormat {
+	Json,
+	Csv,
+}
+
+/// What is produced from the properties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+	/// Hex encoded `add_properties` calls, one per line and batch.
+	Call,
+	/// Genesis patch with the properties of the game pallet.
+	Genesis,
+}
+
+/// The `import-properties` command.
+#[derive(Debug, clap::Parser)]
+pub struct ImportPropertiesCmd {
+	/// Path of the JSON or CSV file with the properties.
+	#[arg(value_name = "FILE")]
+	pub input: PathBuf,
+
+	/// Format of the file. Derived from the file extension if not given.
+	#[arg(long, value_enum)]
+	pub format: Option<InputFormat>,
+
+	/// What should be produced from the properties.
+	#[arg(long, value_enum, default_value_t = OutputFormat::Call)]
+	pub output: OutputFormat,
+
+	/// Wrap every `add_properties` call into a `sudo` call.
+	#[arg(long)]
+	pub sudo: bool,
+}
+
+impl ImportPropertiesCmd {
+	/// Run the command.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let properties = self
+			.read_entries()?
+			.into_iter()
+			.map(to_property)
+			.collect::<Result<Vec<_>, _>>()?;
+		let mut ids = BTreeSet::new();
+		if let Some(property) = properties.iter().find(|property| !ids.insert(property.id)) {
+			return Err(format!("Property {} is listed more than once", property.id).into());
+		}
+		match self.output {
+			OutputFormat::Call =>
+				for batch in properties.chunks(MaxPropertiesPerBatch::get() as usize) {
+					let properties = batch.to_vec().try_into().expect("batches are bounded; qed");
+					let mut call =
+						RuntimeCall::GameModule(pallet_game::Call::add_properties { properties });
+					if self.sudo {
+						call = RuntimeCall::Sudo(SudoCall::sudo { call: Box::new(call) });
+					}
+					println!("{}", to_hex(&call.encode(), false));
+				},
+			OutputFormat::Genesis => {
+				let patch = serde_json::json!({ "gameModule": { "properties": properties } });
+				let patch = serde_json::to_string_pretty(&patch)
+					.map_err(|e| format!("Failed to serialize the genesis patch: {}", e))?;
+				println!("{}", patch);
+			},
+		}
+		Ok(())
+	}
+
+	fn read_entries(&self) -> sc_cli::Result<Vec<PropertyEntry>> {
+		let format = match self.format {
+			Some(format) => format,
+			None => match self.input.extension().and_then(|extension| extension.to_str()) {
+				Some("json") => InputFormat::Json,
+				Some("csv") => InputFormat::Csv,
+				_ => return Err("Unknown file extension, please specify `--format`".into()),
+			},
+		};
+		let file = File::open(&self.input)?;
+		match format {
+			InputFormat::Json => serde_json::from_reader(file)
+				.map_err(|e| format!("Failed to parse the JSON file: {}", e).into()),
+			InputFormat::Csv => csv::Reader::from_reader(file)
+				.deserialize()
+				.collect::<Result<Vec<_>, _>>()
+				.map_err(|e| format!("Failed to parse the CSV file: {}", e).into()),
+		}
+	}
+}
+
+/// Converts an entry of the import file into a property with the commitment of its price.
+fn to_property(entry: PropertyEntry) -> Result<PropertyInfoData<Runtime>, String> {
+	let id = entry.id;
+	let bounded = |value: String, field: &str| -> Result<BoundedVec<u8, StringLimit>, String> {
+		value
+			.into_bytes()
+			.try_into()
+			.map_err(|_| format!("The {} of property {} is too long", field, id))
+	};
+	let data = bounded(entry.data, "data")?;
+	// The salt is revealed as the secret of `submit_result`, so it has the same bound.
+	let salt = bounded(entry.salt, "salt")?;
+	Ok(PropertyInfoData {
+		id,
+		commitment: GameModule::property_commitment(entry.price, &salt, &data),
+		data,
+		metadata: PropertyMetadata {
+			region: bounded(entry.region, "region")?,
+			property_type: entry.property_type,
+			bedrooms: entry.bedrooms,
+			listing_date: entry.listing_date,
+		},
+	})
+}
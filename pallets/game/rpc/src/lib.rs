@@ -0,0 +1,169 @@
+//! RPC interface to query the state of the RealXDeal game.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_game_runtime_api::{
+	GameApi as GameRuntimeApi, ListingDetails, NftColor, OfferDetails, OpenGame, PlayerProfile,
+};
+
+/// Error code of a failed runtime api call.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Game RPC methods.
+#[rpc(client, server)]
+pub trait GameApi<BlockHash, AccountId, CollectionId, ItemId, Balance, BlockNumber> {
+	/// Returns the profile and the leaderboard ranks of a player.
+	#[method(name = "game_playerProfile")]
+	fn player_profile(
+		&self,
+		player: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<PlayerProfile<BlockNumber>>>;
+
+	/// Returns the ongoing games of a player.
+	#[method(name = "game_openGames")]
+	fn open_games(
+		&self,
+		player: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<OpenGame<BlockNumber>>>;
+
+	/// Returns the all-time leaderboard with the points of the players.
+	#[method(name = "game_leaderboard")]
+	fn leaderboard(&self, at: Option<BlockHash>) -> RpcResult<Vec<(AccountId, u32)>>;
+
+	/// Returns the leaderboard of the current round with the points of the players in the round.
+	#[method(name = "game_roundLeaderboard")]
+	fn round_leaderboard(&self, at: Option<BlockHash>) -> RpcResult<Vec<(AccountId, u32)>>;
+
+	/// Returns the listings, optionally filtered by the owner and the color of the nft.
+	#[method(name = "game_listings")]
+	fn listings(
+		&self,
+		owner: Option<AccountId>,
+		color: Option<NftColor>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ListingDetails<AccountId, CollectionId, ItemId, Balance, BlockNumber>>>;
+
+	/// Returns the offers, optionally filtered by the owner and the color of the offered nft.
+	#[method(name = "game_offers")]
+	fn offers(
+		&self,
+		owner: Option<AccountId>,
+		color: Option<NftColor>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<OfferDetails<AccountId, CollectionId, ItemId>>>;
+}
+
+/// Provides the game RPC methods.
+pub struct Game<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Game<C, Block> {
+	/// Creates a new instance of the game RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+fn runtime_error_into_rpc_err(error: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Unable to query the game state.",
+		Some(format!("{:?}", error)),
+	))
+	.into()
+}
+
+impl<C, Block, AccountId, CollectionId, ItemId, Balance, BlockNumber>
+	GameApiServer<<Block as BlockT>::Hash, AccountId, CollectionId, ItemId, Balance, BlockNumber>
+	for Game<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: GameRuntimeApi<Block, AccountId, CollectionId, ItemId, Balance, BlockNumber>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	CollectionId: Codec + Serialize + Send + Sync + 'static,
+	ItemId: Codec + Serialize + Send + Sync + 'static,
+	Balance: Codec + Serialize + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + Send + Sync + 'static,
+{
+	fn player_profile(
+		&self,
+		player: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<PlayerProfile<BlockNumber>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.player_profile(at, player)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn open_games(
+		&self,
+		player: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<OpenGame<BlockNumber>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.open_games(at, player)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn leaderboard(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<(AccountId, u32)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().leaderboard(at).map_err(runtime_error_into_rpc_err)
+	}
+
+	fn round_leaderboard(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(AccountId, u32)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.round_leaderboard(at)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn listings(
+		&self,
+		owner: Option<AccountId>,
+		color: Option<NftColor>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ListingDetails<AccountId, CollectionId, ItemId, Balance, BlockNumber>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.listings(at, owner, color)
+			.map_err(runtime_error_into_rpc_err)
+	}
+
+	fn offers(
+		&self,
+		owner: Option<AccountId>,
+		color: Option<NftColor>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<OfferDetails<AccountId, CollectionId, ItemId>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.offers(at, owner, color)
+			.map_err(runtime_error_into_rpc_err)
+	}
+}
@@ -0,0 +1,48 @@
+//! Runtime api to query the state of the RealXDeal game.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_game::{ListingDetails, NftColor, OfferDetails, OpenGame, PlayerProfile};
+
+sp_api::decl_runtime_apis! {
+	/// The api to query the players, games and the marketplace of the game.
+	///
+	/// Version 2 added the price and the expiry to the listings.
+	#[api_version(2)]
+	pub trait GameApi<AccountId, CollectionId, ItemId, Balance, BlockNumber>
+	where
+		AccountId: Codec,
+		CollectionId: Codec,
+		ItemId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Returns the profile and the leaderboard ranks of a player.
+		fn player_profile(player: AccountId) -> Option<PlayerProfile<BlockNumber>>;
+
+		/// Returns the ongoing games of a player.
+		fn open_games(player: AccountId) -> Vec<OpenGame<BlockNumber>>;
+
+		/// Returns the all-time leaderboard with the points of the players.
+		fn leaderboard() -> Vec<(AccountId, u32)>;
+
+		/// Returns the leaderboard of the current round with the points of the players in the
+		/// round.
+		fn round_leaderboard() -> Vec<(AccountId, u32)>;
+
+		/// Returns the listings, optionally filtered by the owner and the color of the nft.
+		fn listings(
+			owner: Option<AccountId>,
+			color: Option<NftColor>,
+		) -> Vec<ListingDetails<AccountId, CollectionId, ItemId, Balance, BlockNumber>>;
+
+		/// Returns the offers, optionally filtered by the owner and the color of the offered nft.
+		fn offers(
+			owner: Option<AccountId>,
+			color: Option<NftColor>,
+		) -> Vec<OfferDetails<AccountId, CollectionId, ItemId>>;
+	}
+}
@@ -0,0 +1,104 @@
+//! Queries that back the `GameApi` runtime api.
+
+use crate::*;
+use frame_system::pallet_prelude::*;
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+	/// Returns the profile of a player together with the ranks on the all-time leaderboard and
+	/// the leaderboard of the current round.
+	pub fn player_profile(player: AccountIdOf<T>) -> Option<PlayerProfile<BlockNumberFor<T>>> {
+		let user = Users::<T>::get(&player)?;
+		let rank_on = |leaderboard: LeaderboardOf<T>| {
+			leaderboard
+				.iter()
+				.position(|(account, _)| *account == player)
+				.map(|index| index as u32 + 1)
+		};
+		let rank = rank_on(Leaderboard::<T>::get());
+		let round_rank = rank_on(RoundLeaderboard::<T>::get());
+		Some(PlayerProfile {
+			points: user.points,
+			wins: user.wins,
+			losses: user.losses,
+			practise_rounds: user.practise_rounds,
+			last_played_round: user.last_played_round,
+			next_token_request: user.next_token_request,
+			nfts: user.nfts,
+			moderation: user.moderation,
+			rank,
+			round_rank,
+		})
+	}
+
+	/// Returns the ongoing games of a player.
+	pub fn open_games(player: AccountIdOf<T>) -> Vec<OpenGame<BlockNumberFor<T>>> {
+		PlayerGames::<T>::get(player)
+			.into_iter()
+			.filter_map(|game_id| {
+				let game_info = GameInfo::<T>::get(game_id)?;
+				let expiry_block = Self::game_expiry(&game_info);
+				let property = game_info.property;
+				Some(OpenGame {
+					game_id,
+					difficulty: game_info.difficulty,
+					property_id: property.id,
+					data: property.data.into_inner(),
+					region: property.metadata.region.into_inner(),
+					property_type: property.metadata.property_type,
+					bedrooms: property.metadata.bedrooms,
+					listing_date: property.metadata.listing_date,
+					guess: game_info.guess,
+					expiry_block,
+				})
+			})
+			.collect()
+	}
+
+	/// Returns the listings, optionally filtered by the owner and the color of the listed nft.
+	pub fn listings_by(
+		owner: Option<AccountIdOf<T>>,
+		color: Option<NftColor>,
+	) -> Vec<
+		ListingDetails<
+			AccountIdOf<T>,
+			CollectionId<T>,
+			ItemId<T>,
+			CurrencyBalanceOf<T>,
+			BlockNumberFor<T>,
+		>,
+	> {
+		Listings::<T>::iter()
+			.filter(|(_, listing)| owner.as_ref().map_or(true, |owner| listing.owner == *owner))
+			.map(|(listing_id, listing)| ListingDetails {
+				listing_id,
+				color: CollectionColor::<T>::get(listing.collection_id),
+				owner: listing.owner,
+				collection_id: listing.collection_id,
+				item_id: listing.item_id,
+				price: listing.price,
+				expiry: listing.expiry,
+			})
+			.filter(|listing| color.is_none() || listing.color == color)
+			.collect()
+	}
+
+	/// Returns the offers, optionally filtered by the owner and the color of the offered nft.
+	pub fn offers_by(
+		owner: Option<AccountIdOf<T>>,
+		color: Option<NftColor>,
+	) -> Vec<OfferDetails<AccountIdOf<T>, CollectionId<T>, ItemId<T>>> {
+		Offers::<T>::iter()
+			.filter(|(_, offer)| owner.as_ref().map_or(true, |owner| offer.owner == *owner))
+			.map(|(offer_id, offer)| OfferDetails {
+				offer_id,
+				color: CollectionColor::<T>::get(offer.collection_id),
+				owner: offer.owner,
+				listing_id: offer.listing_id,
+				collection_id: offer.collection_id,
+				item_id: offer.item_id,
+			})
+			.filter(|offer| color.is_none() || offer.color == color)
+			.collect()
+	}
+}
@@ -0,0 +1,114 @@
+//! Settlement of the auctions of the marketplace.
+//!
+//! Auctioned nfts are held in the escrow of the pallet account like listed nfts. The bids are
+//! reserved from the bidders and the highest bid is paid out when the auction is settled in the
+//! block in which it ends.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, sp_runtime::traits::One, traits::BalanceStatus};
+use frame_system::pallet_prelude::*;
+
+/// The amount of blocks after the extended end of an auction that are tried if the blocks
+/// before are full.
+const MAX_EXTENSION_ATTEMPTS: u32 = 10;
+
+impl<T: Config> Pallet<T> {
+	/// Adds an auction to the auctions that are settled in the end block.
+	pub(crate) fn schedule_auction_end(
+		auction_id: u32,
+		end_block: BlockNumberFor<T>,
+	) -> DispatchResult {
+		AuctionsEnding::<T>::try_append(end_block, auction_id)
+			.map_err(|_| Error::<T>::TooManyAuctions)?;
+		Ok(())
+	}
+
+	/// Adds an extended auction to the auctions that are settled in the first block from the
+	/// extended end on that has room for it and returns that block.
+	pub(crate) fn schedule_auction_extension(
+		auction_id: u32,
+		extended_end: BlockNumberFor<T>,
+	) -> Result<BlockNumberFor<T>, DispatchError> {
+		let mut end_block = extended_end;
+		for _ in 0..MAX_EXTENSION_ATTEMPTS {
+			if AuctionsEnding::<T>::try_append(end_block, auction_id).is_ok() {
+				return Ok(end_block);
+			}
+			end_block = end_block.saturating_add(One::one());
+		}
+		Err(Error::<T>::TooManyAuctions.into())
+	}
+
+	/// Removes an auction from the auctions that are settled in the end block.
+	pub(crate) fn unschedule_auction_end(auction_id: u32, end_block: BlockNumberFor<T>) {
+		AuctionsEnding::<T>::mutate_exists(end_block, |auctions| {
+			if let Some(ids) = auctions {
+				ids.retain(|id| *id != auction_id);
+				if ids.is_empty() {
+					*auctions = None;
+				}
+			}
+		});
+	}
+
+	/// Settles an auction. The highest bidder pays the amount of the bid to the owner minus the
+	/// marketplace fee and receives the nft. Without a bid the nft goes back to the owner. If the
+	/// reserved funds of the bidder no longer cover the bid, the auction fails: the nft goes back
+	/// to the owner and what is left of the bid is unreserved.
+	pub(crate) fn do_settle_auction(auction_id: u32) -> DispatchResult {
+		let auction_info =
+			Auctions::<T>::take(auction_id).ok_or(Error::<T>::AuctionDoesNotExist)?;
+		let (winner, amount) = match auction_info.highest_bid {
+			Some((bidder, amount)) => {
+				HighestBidCount::<T>::mutate(&bidder, |count| *count = count.saturating_sub(1));
+				let bid_reserve = <T as pallet::Config>::Currency::reserved_balance(&bidder)
+					.saturating_sub(RegistrationDeposits::<T>::get(&bidder).unwrap_or_default());
+				if bid_reserve < amount {
+					<T as pallet::Config>::Currency::unreserve(&bidder, bid_reserve);
+					Self::release_nft(
+						&auction_info.owner,
+						auction_info.collection_id,
+						auction_info.item_id,
+					)?;
+					Self::deposit_event(Event::<T>::AuctionFailed {
+						auction_id,
+						owner: auction_info.owner,
+						bidder,
+						amount,
+					});
+					return Ok(());
+				}
+				let fee = T::MarketplaceFee::get() * amount;
+				<T as pallet::Config>::Currency::repatriate_reserved(
+					&bidder,
+					&Self::account_id(),
+					fee,
+					BalanceStatus::Free,
+				)?;
+				<T as pallet::Config>::Currency::repatriate_reserved(
+					&bidder,
+					&auction_info.owner,
+					amount.saturating_sub(fee),
+					BalanceStatus::Free,
+				)?;
+				Self::release_nft(&bidder, auction_info.collection_id, auction_info.item_id)?;
+				(Some(bidder), Some(amount))
+			},
+			None => {
+				Self::release_nft(
+					&auction_info.owner,
+					auction_info.collection_id,
+					auction_info.item_id,
+				)?;
+				(None, None)
+			},
+		};
+		Self::deposit_event(Event::<T>::AuctionSettled {
+			auction_id,
+			owner: auction_info.owner,
+			winner,
+			amount,
+		});
+		Ok(())
+	}
+}
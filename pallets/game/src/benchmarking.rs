@@ -8,14 +8,45 @@ use frame_benchmarking::v2::*;
 use frame_support::{
 	assert_ok,
 	traits::{OnFinalize, OnInitialize},
+	BoundedVec,
 };
-use frame_system::RawOrigin;
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+
+/// Returns a property of the benchmarks with the price 220000 and its own data. The salt of the
+/// commitment is the secret that the benchmarks reveal.
+fn test_property<T: Config>(id: u32) -> PropertyInfoData<T> {
+	let mut data = b"realxdeal benchmark property ".to_vec();
+	data.extend_from_slice(&id.to_le_bytes());
+	let data: BoundedVec<u8, <T as Config>::StringLimit> = data.try_into().unwrap();
+	PropertyInfoData {
+		id,
+		commitment: GameModule::<T>::property_commitment(
+			220000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes(),
+			&data,
+		),
+		data,
+		metadata: PropertyMetadata {
+			region: "Liverpool".as_bytes().to_vec().try_into().unwrap(),
+			property_type: PropertyType::Terraced,
+			bedrooms: 2,
+			listing_date: 1_714_521_600,
+		},
+	}
+}
+
+fn add_test_properties<T: Config>() {
+	for id in [147229391, 146480642, 147031382, 147095938] {
+		assert_ok!(GameModule::<T>::do_add_property(test_property::<T>(id)));
+	}
+}
 
 fn create_setup<T: Config>() -> T::AccountId {
 	let caller: T::AccountId = whitelisted_caller();
 	let admin: T::AccountId = account("admin", 0, 0);
 	assert_ok!(GameModule::<T>::setup_game(RawOrigin::Root.into()));
-	assert_ok!(GameModule::<T>::add_to_admins(RawOrigin::Root.into(), admin.clone()));
+	add_test_properties::<T>();
+	assert_ok!(GameModule::<T>::grant_role(RawOrigin::Root.into(), Role::Registrar, admin.clone()));
 	assert_ok!(GameModule::<T>::register_user(RawOrigin::Signed(admin).into(), caller.clone()));
 	caller
 }
@@ -27,16 +58,123 @@ fn practise_round<T: Config>(caller: T::AccountId, game_id: u32) {
 	));
 	assert_ok!(GameModule::<T>::submit_answer(
 		RawOrigin::Signed(caller.clone()).into(),
-		20,
+		220000,
+		game_id
+	));
+	assert_ok!(GameModule::<T>::check_result(
+		RawOrigin::Root.into(),
+		game_id,
+		220000,
+		"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
+	));
+}
+
+fn win_nft<T: Config>(caller: T::AccountId, game_id: u32) {
+	assert_ok!(GameModule::<T>::play_game(
+		RawOrigin::Signed(caller.clone()).into(),
+		crate::DifficultyLevel::Player
+	));
+	assert_ok!(GameModule::<T>::submit_answer(
+		RawOrigin::Signed(caller.clone()).into(),
+		220000,
 		game_id
 	));
 	assert_ok!(GameModule::<T>::check_result(
 		RawOrigin::Root.into(),
-		20,
 		game_id,
-		20,
-		"test".as_bytes().to_vec().try_into().unwrap(),
+		220000,
+		"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
+	));
+}
+
+fn give_nft<T: Config>(owner: T::AccountId, color_index: u32) -> (CollectionId<T>, ItemId<T>) {
+	let collection_id =
+		GameModule::<T>::round_collection(GameModule::<T>::current_round(), color_index).unwrap();
+	let item_id: ItemId<T> = NextColorId::<T>::get(collection_id).into();
+	NextColorId::<T>::mutate(collection_id, |next_item_id| *next_item_id += 1);
+	assert_ok!(pallet_nfts::Pallet::<T>::do_mint(
+		collection_id.into(),
+		item_id.into(),
+		Some(GameModule::<T>::account_id()),
+		GameModule::<T>::account_id(),
+		GameModule::<T>::default_item_config(),
+		|_, _| Ok(())
 	));
+	assert_ok!(GameModule::<T>::release_nft(&owner, collection_id, item_id));
+	(collection_id, item_id)
+}
+
+/// Moves the game to the round `rounds` and puts the player on the archived leaderboard of every
+/// round before as its champion.
+fn past_rounds<T: Config>(player: T::AccountId, rounds: u32) {
+	let score = Score { points: 100, wins: 1, achieved: 1u32.into() };
+	for round in 1..rounds {
+		RoundScores::<T>::insert(round, &player, score.clone());
+		RoundChampion::<T>::insert(round, &player);
+		PlayerRounds::<T>::insert(&player, round, ());
+		Rounds::<T>::insert(
+			round,
+			RoundInfo {
+				start_block: 1u32.into(),
+				end_block: None,
+				closed_block: Some(1u32.into()),
+				champion: Some(player.clone()),
+				games_played: 1,
+				nfts_minted: 1,
+				leaderboard: BoundedVec::truncate_from(sp_std::vec![(
+					player.clone(),
+					score.clone()
+				)]),
+			},
+		);
+	}
+	CurrentRound::<T>::put(rounds);
+}
+
+fn fund_faucet<T: Config>() {
+	let _ = <T as Config>::Currency::deposit_creating(
+		&GameModule::<T>::account_id(),
+		T::FaucetCap::get().saturating_add(<T as Config>::Currency::minimum_balance()),
+	);
+}
+
+fn funded_bidder<T: Config>(name: &'static str) -> T::AccountId {
+	let bidder: T::AccountId = account(name, 0, 0);
+	let admin: T::AccountId = account("admin", 0, 0);
+	assert_ok!(GameModule::<T>::register_user(RawOrigin::Signed(admin).into(), bidder.clone()));
+	<T as Config>::Currency::make_free_balance_be(
+		&bidder,
+		<T as Config>::Currency::minimum_balance().saturating_mul(100u32.into()),
+	);
+	bidder
+}
+
+fn prize_pool_with_places<T: Config>(
+	places: u32,
+	share: Permill,
+) -> sp_std::vec::Vec<T::AccountId> {
+	let admin: T::AccountId = account("admin", 0, 0);
+	let players = (0..places)
+		.map(|index| {
+			let player: T::AccountId = account("player", index, 0);
+			assert_ok!(GameModule::<T>::register_user(
+				RawOrigin::Signed(admin.clone()).into(),
+				player.clone()
+			));
+			assert_ok!(GameModule::<T>::give_points(
+				RawOrigin::Root.into(),
+				player.clone(),
+				100 + index
+			));
+			player
+		})
+		.collect();
+	let shares = sp_std::vec![share; places as usize].try_into().unwrap();
+	assert_ok!(GameModule::<T>::set_prize_distribution(RawOrigin::Root.into(), shares));
+	let donor = funded_bidder::<T>("donor");
+	let amount = <T as Config>::Currency::minimum_balance().saturating_mul(10u32.into());
+	assert_ok!(GameModule::<T>::fund_prize_pool(RawOrigin::Signed(donor).into(), amount));
+	players
 }
 
 #[benchmarks]
@@ -54,11 +192,20 @@ mod benchmarks {
 		let caller: T::AccountId = account("caller", 0, 0);
 		assert_ok!(GameModule::<T>::setup_game(RawOrigin::Root.into()));
 		let admin: T::AccountId = account("admin", 0, 0);
-		assert_ok!(GameModule::<T>::add_to_admins(RawOrigin::Root.into(), admin.clone()));
+		assert_ok!(GameModule::<T>::grant_role(
+			RawOrigin::Root.into(),
+			Role::Registrar,
+			admin.clone()
+		));
+		fund_faucet::<T>();
 		#[extrinsic_call]
 		register_user(RawOrigin::Signed(admin), caller.clone());
 
-		assert!(GameModule::<T>::users(caller).is_some());
+		assert!(GameModule::<T>::users(&caller).is_some());
+		assert_eq!(
+			GameModule::<T>::faucet_usage().1,
+			<T as Config>::Currency::free_balance(&caller)
+		);
 	}
 
 	#[benchmark]
@@ -95,10 +242,9 @@ mod benchmarks {
 
 		assert_ok!(GameModule::<T>::check_result(
 			RawOrigin::Root.into(),
-			220000,
 			1,
 			220000,
-			"test".as_bytes().to_vec().try_into().unwrap(),
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
 		));
 		assert_eq!(GameModule::<T>::users::<AccountIdOf<T>>(caller).unwrap().nfts.xorange, 1);
 	}
@@ -120,10 +266,9 @@ mod benchmarks {
 		#[extrinsic_call]
 		check_result(
 			RawOrigin::Root,
-			220000,
 			1,
 			220000,
-			"test".as_bytes().to_vec().try_into().unwrap(),
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
 		);
 
 		assert_eq!(GameModule::<T>::users::<AccountIdOf<T>>(caller).unwrap().nfts.xorange, 1);
@@ -145,13 +290,12 @@ mod benchmarks {
 		));
 		assert_ok!(GameModule::<T>::check_result(
 			RawOrigin::Root.into(),
-			220000,
 			1,
 			220000,
-			"test".as_bytes().to_vec().try_into().unwrap(),
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
 		));
 		#[extrinsic_call]
-		list_nft(RawOrigin::Signed(caller.clone()), 0.into(), 0.into());
+		list_nft(RawOrigin::Signed(caller.clone()), 0.into(), 0.into(), None, None);
 
 		assert_eq!(GameModule::<T>::listings(0).unwrap().owner, caller);
 	}
@@ -172,15 +316,16 @@ mod benchmarks {
 		));
 		assert_ok!(GameModule::<T>::check_result(
 			RawOrigin::Root.into(),
-			220000,
 			1,
 			220000,
-			"test".as_bytes().to_vec().try_into().unwrap(),
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
 		));
 		assert_ok!(GameModule::<T>::list_nft(
 			RawOrigin::Signed(caller.clone()).into(),
 			0.into(),
-			0.into()
+			0.into(),
+			None,
+			None
 		));
 		#[extrinsic_call]
 		delist_nft(RawOrigin::Signed(caller), 0);
@@ -204,15 +349,16 @@ mod benchmarks {
 		));
 		assert_ok!(GameModule::<T>::check_result(
 			RawOrigin::Root.into(),
-			220000,
 			1,
 			220000,
-			"test".as_bytes().to_vec().try_into().unwrap(),
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
 		));
 		assert_ok!(GameModule::<T>::list_nft(
 			RawOrigin::Signed(caller.clone()).into(),
 			0.into(),
-			0.into()
+			0.into(),
+			None,
+			None
 		));
 		let caller2: T::AccountId = account("caller2", 0, 0);
 		let admin: T::AccountId = account("admin", 0, 0);
@@ -232,19 +378,18 @@ mod benchmarks {
 		));
 		assert_ok!(GameModule::<T>::check_result(
 			RawOrigin::Root.into(),
-			220000,
 			3,
 			220000,
-			"test".as_bytes().to_vec().try_into().unwrap(),
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
 		));
 		#[extrinsic_call]
-		make_offer(RawOrigin::Signed(caller2.clone()), 0, 0.into(), 1.into());
+		make_offer(RawOrigin::Signed(caller2.clone()), 0, 0.into(), 1.into(), None);
 
 		assert_eq!(GameModule::<T>::offers(0).unwrap().owner, caller2);
 	}
 
 	#[benchmark]
-	fn handle_offer() {
+	fn withdraw_offer() {
 		let caller = create_setup::<T>();
 		current_block::<T>(30u32.into());
 		practise_round::<T>(caller.clone(), 0);
@@ -259,15 +404,16 @@ mod benchmarks {
 		));
 		assert_ok!(GameModule::<T>::check_result(
 			RawOrigin::Root.into(),
-			220000,
 			1,
 			220000,
-			"test".as_bytes().to_vec().try_into().unwrap(),
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
 		));
 		assert_ok!(GameModule::<T>::list_nft(
 			RawOrigin::Signed(caller.clone()).into(),
 			0.into(),
-			0.into()
+			0.into(),
+			None,
+			None
 		));
 		let caller2: T::AccountId = account("caller2", 0, 0);
 		let admin: T::AccountId = account("admin", 0, 0);
@@ -287,10 +433,72 @@ mod benchmarks {
 		));
 		assert_ok!(GameModule::<T>::check_result(
 			RawOrigin::Root.into(),
+			3,
 			220000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
+		));
+		assert_ok!(GameModule::<T>::make_offer(
+			RawOrigin::Signed(caller2.clone()).into(),
+			0,
+			0.into(),
+			1.into(),
+			None
+		));
+		#[extrinsic_call]
+		withdraw_offer(RawOrigin::Signed(caller2.clone()), 0);
+
+		assert!(GameModule::<T>::offers(0).is_none());
+		assert_eq!(GameModule::<T>::users::<AccountIdOf<T>>(caller2).unwrap().nfts.xorange, 1);
+	}
+
+	#[benchmark]
+	fn handle_offer() {
+		let caller = create_setup::<T>();
+		current_block::<T>(30u32.into());
+		practise_round::<T>(caller.clone(), 0);
+		assert_ok!(GameModule::<T>::play_game(
+			RawOrigin::Signed(caller.clone()).into(),
+			crate::DifficultyLevel::Player
+		));
+		assert_ok!(GameModule::<T>::submit_answer(
+			RawOrigin::Signed(caller.clone()).into(),
+			220000,
+			1
+		));
+		assert_ok!(GameModule::<T>::check_result(
+			RawOrigin::Root.into(),
+			1,
+			220000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
+		));
+		assert_ok!(GameModule::<T>::list_nft(
+			RawOrigin::Signed(caller.clone()).into(),
+			0.into(),
+			0.into(),
+			None,
+			None
+		));
+		let caller2: T::AccountId = account("caller2", 0, 0);
+		let admin: T::AccountId = account("admin", 0, 0);
+		assert_ok!(GameModule::<T>::register_user(
+			RawOrigin::Signed(admin).into(),
+			caller2.clone()
+		));
+		practise_round::<T>(caller2.clone(), 2);
+		assert_ok!(GameModule::<T>::play_game(
+			RawOrigin::Signed(caller2.clone()).into(),
+			crate::DifficultyLevel::Player
+		));
+		assert_ok!(GameModule::<T>::submit_answer(
+			RawOrigin::Signed(caller2.clone()).into(),
+			220000,
+			3
+		));
+		assert_ok!(GameModule::<T>::check_result(
+			RawOrigin::Root.into(),
 			3,
 			220000,
-			"test".as_bytes().to_vec().try_into().unwrap(),
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
 		));
 		assert_eq!(
 			GameModule::<T>::users::<AccountIdOf<T>>(caller2.clone()).unwrap().nfts.xorange,
@@ -300,7 +508,8 @@ mod benchmarks {
 			RawOrigin::Signed(caller2.clone()).into(),
 			0,
 			0.into(),
-			1.into()
+			1.into(),
+			None
 		));
 
 		#[extrinsic_call]
@@ -310,51 +519,589 @@ mod benchmarks {
 		assert_eq!(GameModule::<T>::listings(0).is_none(), true);
 	}
 
+	#[benchmark]
+	fn buy_nft() {
+		let caller = create_setup::<T>();
+		current_block::<T>(30u32.into());
+		practise_round::<T>(caller.clone(), 0);
+		assert_ok!(GameModule::<T>::play_game(
+			RawOrigin::Signed(caller.clone()).into(),
+			crate::DifficultyLevel::Player
+		));
+		assert_ok!(GameModule::<T>::submit_answer(
+			RawOrigin::Signed(caller.clone()).into(),
+			220000,
+			1
+		));
+		assert_ok!(GameModule::<T>::check_result(
+			RawOrigin::Root.into(),
+			1,
+			220000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
+		));
+		let price = <T as Config>::Currency::minimum_balance().saturating_mul(10u32.into());
+		assert_ok!(GameModule::<T>::list_nft(
+			RawOrigin::Signed(caller.clone()).into(),
+			0.into(),
+			0.into(),
+			Some(price),
+			None
+		));
+		let caller2: T::AccountId = account("caller2", 0, 0);
+		let admin: T::AccountId = account("admin", 0, 0);
+		assert_ok!(GameModule::<T>::register_user(
+			RawOrigin::Signed(admin).into(),
+			caller2.clone()
+		));
+		<T as Config>::Currency::make_free_balance_be(
+			&caller2,
+			<T as Config>::Currency::minimum_balance().saturating_mul(100u32.into()),
+		);
+		<T as Config>::Currency::make_free_balance_be(
+			&GameModule::<T>::account_id(),
+			<T as Config>::Currency::minimum_balance(),
+		);
+		#[extrinsic_call]
+		buy_nft(RawOrigin::Signed(caller2.clone()), 0);
+
+		assert!(GameModule::<T>::listings(0).is_none());
+		assert_eq!(GameModule::<T>::users::<AccountIdOf<T>>(caller2).unwrap().nfts.xorange, 1);
+	}
+
+	#[benchmark]
+	fn create_auction() {
+		let caller = create_setup::<T>();
+		current_block::<T>(30u32.into());
+		practise_round::<T>(caller.clone(), 0);
+		win_nft::<T>(caller.clone(), 1);
+		let min_bid = <T as Config>::Currency::minimum_balance();
+		#[extrinsic_call]
+		create_auction(RawOrigin::Signed(caller.clone()), 0.into(), 0.into(), min_bid);
+
+		assert_eq!(GameModule::<T>::auctions(0).unwrap().owner, caller);
+	}
+
+	#[benchmark]
+	fn bid() {
+		let caller = create_setup::<T>();
+		current_block::<T>(30u32.into());
+		practise_round::<T>(caller.clone(), 0);
+		win_nft::<T>(caller.clone(), 1);
+		let min_bid = <T as Config>::Currency::minimum_balance();
+		assert_ok!(GameModule::<T>::create_auction(
+			RawOrigin::Signed(caller).into(),
+			0.into(),
+			0.into(),
+			min_bid
+		));
+		let bidder = funded_bidder::<T>("bidder");
+		assert_ok!(GameModule::<T>::bid(RawOrigin::Signed(bidder).into(), 0, min_bid));
+		let caller2 = funded_bidder::<T>("caller2");
+		let amount = min_bid.saturating_mul(10u32.into());
+		#[extrinsic_call]
+		bid(RawOrigin::Signed(caller2.clone()), 0, amount);
+
+		assert_eq!(GameModule::<T>::auctions(0).unwrap().highest_bid, Some((caller2, amount)));
+	}
+
+	#[benchmark]
+	fn settle_auction() {
+		let caller = create_setup::<T>();
+		current_block::<T>(30u32.into());
+		practise_round::<T>(caller.clone(), 0);
+		win_nft::<T>(caller.clone(), 1);
+		let min_bid = <T as Config>::Currency::minimum_balance().saturating_mul(10u32.into());
+		assert_ok!(GameModule::<T>::create_auction(
+			RawOrigin::Signed(caller).into(),
+			0.into(),
+			0.into(),
+			min_bid
+		));
+		let caller2 = funded_bidder::<T>("caller2");
+		assert_ok!(GameModule::<T>::bid(RawOrigin::Signed(caller2.clone()).into(), 0, min_bid));
+		<T as Config>::Currency::make_free_balance_be(
+			&GameModule::<T>::account_id(),
+			<T as Config>::Currency::minimum_balance(),
+		);
+		#[block]
+		{
+			assert_ok!(GameModule::<T>::do_settle_auction(0));
+		}
+
+		assert!(GameModule::<T>::auctions(0).is_none());
+		assert_eq!(GameModule::<T>::users::<AccountIdOf<T>>(caller2).unwrap().nfts.xorange, 1);
+	}
+
+	#[benchmark]
+	fn expire_listing() {
+		let caller = create_setup::<T>();
+		current_block::<T>(30u32.into());
+		practise_round::<T>(caller.clone(), 0);
+		win_nft::<T>(caller.clone(), 1);
+		let expiry: BlockNumberFor<T> = 40u32.into();
+		assert_ok!(GameModule::<T>::list_nft(
+			RawOrigin::Signed(caller.clone()).into(),
+			0.into(),
+			0.into(),
+			None,
+			Some(expiry)
+		));
+		#[block]
+		{
+			assert_ok!(GameModule::<T>::expire_listing(0, expiry));
+		}
+
+		assert!(GameModule::<T>::listings(0).is_none());
+		assert_eq!(GameModule::<T>::users::<AccountIdOf<T>>(caller).unwrap().nfts.xorange, 1);
+	}
+
+	#[benchmark]
+	fn expire_offer() {
+		let caller = create_setup::<T>();
+		current_block::<T>(30u32.into());
+		practise_round::<T>(caller.clone(), 0);
+		win_nft::<T>(caller.clone(), 1);
+		assert_ok!(GameModule::<T>::list_nft(
+			RawOrigin::Signed(caller).into(),
+			0.into(),
+			0.into(),
+			None,
+			None
+		));
+		let caller2 = funded_bidder::<T>("caller2");
+		practise_round::<T>(caller2.clone(), 2);
+		win_nft::<T>(caller2.clone(), 3);
+		let expiry: BlockNumberFor<T> = 40u32.into();
+		assert_ok!(GameModule::<T>::make_offer(
+			RawOrigin::Signed(caller2.clone()).into(),
+			0,
+			0.into(),
+			1.into(),
+			Some(expiry)
+		));
+		#[block]
+		{
+			assert_ok!(GameModule::<T>::expire_offer(0, expiry));
+		}
+
+		assert!(GameModule::<T>::offers(0).is_none());
+		assert_eq!(GameModule::<T>::users::<AccountIdOf<T>>(caller2).unwrap().nfts.xorange, 1);
+	}
+
+	#[benchmark]
+	fn place_standing_order() {
+		let caller = create_setup::<T>();
+		let caller2 = funded_bidder::<T>("caller2");
+		let (blue_collection, blue_item) = give_nft::<T>(caller2.clone(), 2);
+		let (green_collection, green_item) = give_nft::<T>(caller.clone(), 7);
+		assert_ok!(GameModule::<T>::place_standing_order(
+			RawOrigin::Signed(caller2.clone()).into(),
+			blue_collection,
+			blue_item,
+			NftColor::Xgreen
+		));
+		#[extrinsic_call]
+		place_standing_order(
+			RawOrigin::Signed(caller.clone()),
+			green_collection,
+			green_item,
+			NftColor::Xblue,
+		);
+
+		assert!(GameModule::<T>::standing_orders(0).is_none());
+		assert_eq!(GameModule::<T>::users::<AccountIdOf<T>>(caller).unwrap().nfts.xblue, 1);
+		assert_eq!(GameModule::<T>::users::<AccountIdOf<T>>(caller2).unwrap().nfts.xgreen, 1);
+	}
+
+	#[benchmark]
+	fn cancel_standing_order() {
+		let caller = create_setup::<T>();
+		let (collection_id, item_id) = give_nft::<T>(caller.clone(), 2);
+		assert_ok!(GameModule::<T>::place_standing_order(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection_id,
+			item_id,
+			NftColor::Xgreen
+		));
+		#[extrinsic_call]
+		cancel_standing_order(RawOrigin::Signed(caller.clone()), 0);
+
+		assert!(GameModule::<T>::standing_orders(0).is_none());
+		assert_eq!(GameModule::<T>::users::<AccountIdOf<T>>(caller).unwrap().nfts.xblue, 1);
+	}
+
+	#[benchmark]
+	fn fill_standing_order() {
+		let caller = create_setup::<T>();
+		let caller2 = funded_bidder::<T>("caller2");
+		let (blue_collection, blue_item) = give_nft::<T>(caller2.clone(), 2);
+		let (green_collection, green_item) = give_nft::<T>(caller.clone(), 7);
+		assert_ok!(GameModule::<T>::place_standing_order(
+			RawOrigin::Signed(caller2).into(),
+			blue_collection,
+			blue_item,
+			NftColor::Xgreen
+		));
+		assert_ok!(GameModule::<T>::escrow_nft(&caller, green_collection, green_item));
+		#[block]
+		{
+			assert_ok!(GameModule::<T>::fill_standing_order(
+				0,
+				&caller,
+				green_collection,
+				green_item,
+				None
+			));
+		}
+
+		assert_eq!(GameModule::<T>::users::<AccountIdOf<T>>(caller).unwrap().nfts.xblue, 1);
+	}
+
 	#[benchmark]
 	fn add_property() {
 		assert_ok!(GameModule::<T>::setup_game(RawOrigin::Root.into()));
-		let new_property = PropertyInfoData {
-			id: 147031382,
-			data: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
-		};
+		add_test_properties::<T>();
+		let new_property = test_property::<T>(147000000);
 		#[extrinsic_call]
 		add_property(RawOrigin::Root, new_property);
-		assert_eq!(GameModule::<T>::game_properties().len(), 5);
+		assert_eq!(GameModule::<T>::available_property_count(), 5);
 	}
 
 	#[benchmark]
 	fn remove_property() {
 		assert_ok!(GameModule::<T>::setup_game(RawOrigin::Root.into()));
+		add_test_properties::<T>();
 		#[extrinsic_call]
 		remove_property(RawOrigin::Root, 146480642);
 
-		assert_eq!(GameModule::<T>::game_properties().len(), 3);
+		assert_eq!(GameModule::<T>::available_property_count(), 3);
+	}
+
+	#[benchmark]
+	fn request_token() {
+		let caller = create_setup::<T>();
+		fund_faucet::<T>();
+		<T as Config>::Currency::make_free_balance_be(
+			&caller,
+			<T as Config>::Currency::minimum_balance(),
+		);
+		current_block::<T>(30u32.into());
+		current_block::<T>(100801u32.into());
+		#[extrinsic_call]
+		request_token(RawOrigin::Signed(caller.clone()));
+
+		assert!(
+			<T as Config>::Currency::free_balance(&caller) >
+				<T as Config>::Currency::minimum_balance()
+		);
+	}
+
+	#[benchmark]
+	fn submit_result() {
+		let caller = create_setup::<T>();
+		let oracle: T::AccountId = account("oracle", 0, 0);
+		assert_ok!(GameModule::<T>::grant_role(
+			RawOrigin::Root.into(),
+			Role::Settler,
+			oracle.clone()
+		));
+		current_block::<T>(30u32.into());
+		practise_round::<T>(caller.clone(), 0);
+		assert_ok!(GameModule::<T>::play_game(
+			RawOrigin::Signed(caller.clone()).into(),
+			crate::DifficultyLevel::Player
+		));
+		assert_ok!(GameModule::<T>::submit_answer(
+			RawOrigin::Signed(caller.clone()).into(),
+			220000,
+			1
+		));
+		current_block::<T>(frame_system::Pallet::<T>::block_number() + T::GameDuration::get());
+		#[extrinsic_call]
+		submit_result(
+			RawOrigin::Signed(oracle),
+			1,
+			220000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
+		);
+
+		assert_eq!(GameModule::<T>::users::<AccountIdOf<T>>(caller).unwrap().nfts.xorange, 1);
+	}
+
+	#[benchmark]
+	fn set_scoring_config() {
+		let mut config = crate::ScoringConfig::<T>::default_for(crate::DifficultyLevel::Pro);
+		config.entry_points = 100;
+		#[extrinsic_call]
+		set_scoring_config(RawOrigin::Root, crate::DifficultyLevel::Pro, config.clone());
+
+		assert_eq!(GameModule::<T>::scoring_config(crate::DifficultyLevel::Pro), config);
+	}
+
+	#[benchmark]
+	fn add_properties(n: Linear<1, { <T as Config>::MaxPropertiesPerBatch::get() }>) {
+		let properties: BoundedVec<PropertyInfoData<T>, <T as Config>::MaxPropertiesPerBatch> =
+			(0..n)
+				.map(test_property::<T>)
+				.collect::<sp_std::vec::Vec<_>>()
+				.try_into()
+				.unwrap();
+		#[extrinsic_call]
+		add_properties(RawOrigin::Root, properties);
+
+		assert_eq!(GameModule::<T>::available_property_count(), n);
+	}
+
+	#[benchmark]
+	fn start_round() {
+		#[extrinsic_call]
+		start_round(RawOrigin::Root, None, Some(100u32.into()));
+
+		assert_eq!(GameModule::<T>::current_round(), 1);
+		assert!(GameModule::<T>::round_active());
+	}
+
+	#[benchmark]
+	fn end_round() {
+		create_setup::<T>();
+		let places = <T as Config>::LeaderboardLimit::get();
+		prize_pool_with_places::<T>(places, Permill::from_rational(1, places));
+		#[extrinsic_call]
+		end_round(RawOrigin::Root);
+
+		assert!(!GameModule::<T>::round_active());
+		assert!(GameModule::<T>::rounds(1).unwrap().closed_block.is_some());
+		assert_eq!(RoundPrizes::<T>::iter_prefix(1).count() as u32, places);
 	}
 
 	#[benchmark]
-	fn add_to_admins() {
+	fn fund_prize_pool() {
+		create_setup::<T>();
+		let donor = funded_bidder::<T>("donor");
+		let amount = <T as Config>::Currency::minimum_balance().saturating_mul(10u32.into());
+		#[extrinsic_call]
+		fund_prize_pool(RawOrigin::Signed(donor), amount);
+
+		assert_eq!(GameModule::<T>::prize_pool(), amount);
+	}
+
+	#[benchmark]
+	fn set_prize_distribution() {
+		let places = <T as Config>::LeaderboardLimit::get();
+		let shares: BoundedVec<Permill, <T as Config>::LeaderboardLimit> =
+			sp_std::vec![Permill::from_rational(1, places); places as usize]
+				.try_into()
+				.unwrap();
+		#[extrinsic_call]
+		set_prize_distribution(RawOrigin::Root, shares.clone());
+
+		assert_eq!(GameModule::<T>::prize_distribution(), shares);
+	}
+
+	#[benchmark]
+	fn claim_prize() {
+		create_setup::<T>();
+		let winner = prize_pool_with_places::<T>(1, Permill::from_percent(50)).remove(0);
+		assert_ok!(GameModule::<T>::end_round(RawOrigin::Root.into()));
+		#[extrinsic_call]
+		claim_prize(RawOrigin::Signed(winner.clone()), 1);
+
+		assert!(GameModule::<T>::round_prizes(1, winner).is_none());
+	}
+
+	#[benchmark]
+	fn grant_role() {
+		let account: T::AccountId = account("account", 0, 0);
+		#[extrinsic_call]
+		grant_role(RawOrigin::Root, Role::Registrar, account.clone());
+
+		assert!(GameModule::<T>::has_role(&account, Role::Registrar));
+	}
+
+	#[benchmark]
+	fn revoke_role() {
+		let account: T::AccountId = account("account", 0, 0);
+		assert_ok!(GameModule::<T>::grant_role(
+			RawOrigin::Root.into(),
+			Role::Registrar,
+			account.clone()
+		));
+		#[extrinsic_call]
+		revoke_role(RawOrigin::Root, Role::Registrar, account.clone());
+
+		assert!(!GameModule::<T>::has_role(&account, Role::Registrar));
+	}
+
+	#[benchmark]
+	fn register() {
 		assert_ok!(GameModule::<T>::setup_game(RawOrigin::Root.into()));
-		let new_admin: T::AccountId = account("new_admin", 1, 0);
+		let caller: T::AccountId = account("caller", 0, 0);
+		<T as Config>::Currency::make_free_balance_be(
+			&caller,
+			T::RegistrationDeposit::get().saturating_add(
+				<T as Config>::Currency::minimum_balance().saturating_mul(100u32.into()),
+			),
+		);
 		#[extrinsic_call]
-		add_to_admins(RawOrigin::Root, new_admin);
+		register(RawOrigin::Signed(caller.clone()), None);
+
+		assert!(GameModule::<T>::users(&caller).is_some());
+		assert_eq!(
+			GameModule::<T>::registration_deposits(caller),
+			Some(T::RegistrationDeposit::get())
+		);
 	}
 
 	#[benchmark]
-	fn remove_from_admins() {
+	fn unregister(r: Linear<1, 100>) {
 		assert_ok!(GameModule::<T>::setup_game(RawOrigin::Root.into()));
-		let new_admin: T::AccountId = account("new_admin", 0, 0);
-		assert_ok!(GameModule::<T>::add_to_admins(RawOrigin::Root.into(), new_admin.clone()));
+		let caller: T::AccountId = account("caller", 0, 0);
+		<T as Config>::Currency::make_free_balance_be(
+			&caller,
+			T::RegistrationDeposit::get().saturating_add(
+				<T as Config>::Currency::minimum_balance().saturating_mul(100u32.into()),
+			),
+		);
+		assert_ok!(GameModule::<T>::register(RawOrigin::Signed(caller.clone()).into(), None));
+		assert_ok!(GameModule::<T>::give_points(RawOrigin::Root.into(), caller.clone(), 100));
+		past_rounds::<T>(caller.clone(), r);
 		#[extrinsic_call]
-		remove_from_admins(RawOrigin::Root, new_admin);
+		unregister(RawOrigin::Signed(caller.clone()), r);
+
+		assert!(GameModule::<T>::users(&caller).is_none());
+		assert!(GameModule::<T>::leaderboard().is_empty());
+		assert!(GameModule::<T>::rounds(1).map_or(true, |round| round.leaderboard.is_empty()));
 	}
 
 	#[benchmark]
-	fn request_token() {
+	fn deregister(n: Linear<0, 100>, r: Linear<1, 100>) {
+		assert_ok!(GameModule::<T>::setup_game(RawOrigin::Root.into()));
+		let caller: T::AccountId = account("caller", 0, 0);
+		<T as Config>::Currency::make_free_balance_be(
+			&caller,
+			T::RegistrationDeposit::get().saturating_add(
+				<T as Config>::Currency::minimum_balance().saturating_mul(100u32.into()),
+			),
+		);
+		assert_ok!(GameModule::<T>::register(RawOrigin::Signed(caller.clone()).into(), None));
+		for _ in 0..n {
+			give_nft::<T>(caller.clone(), 0);
+		}
+		past_rounds::<T>(caller.clone(), r);
+		#[extrinsic_call]
+		deregister(RawOrigin::Signed(caller.clone()), caller.clone(), n, r);
+
+		assert!(GameModule::<T>::users(&caller).is_none());
+		assert!(GameModule::<T>::leaderboard().is_empty());
+		assert!(GameModule::<T>::rounds(1).map_or(true, |round| round.leaderboard.is_empty()));
+	}
+
+	#[benchmark]
+	fn create_invite_codes(n: Linear<1, { <T as Config>::MaxInviteCodesPerCall::get() }>) {
+		let code_hashes: BoundedVec<T::Hash, <T as Config>::MaxInviteCodesPerCall> = (0..n)
+			.map(|index| {
+				GameModule::<T>::invite_code_hash(
+					&index.to_le_bytes(),
+					&account("player", index, 0),
+				)
+			})
+			.collect::<sp_std::vec::Vec<_>>()
+			.try_into()
+			.unwrap();
+		#[extrinsic_call]
+		create_invite_codes(RawOrigin::Root, code_hashes);
+
+		assert!(GameModule::<T>::invite_codes(GameModule::<T>::invite_code_hash(
+			&0u32.to_le_bytes(),
+			&account("player", 0, 0)
+		))
+		.is_some());
+	}
+
+	#[benchmark]
+	fn suspend_player() {
 		let caller = create_setup::<T>();
-		current_block::<T>(30u32.into());
-		current_block::<T>(100801u32.into());
+		assert_ok!(GameModule::<T>::suspend_player(
+			RawOrigin::Root.into(),
+			caller.clone(),
+			10u32.into(),
+			Default::default()
+		));
+		#[extrinsic_call]
+		suspend_player(RawOrigin::Root, caller.clone(), 20u32.into(), Default::default());
+
+		assert_eq!(
+			GameModule::<T>::users(&caller).unwrap().moderation,
+			ModerationState::Suspended(20u32.into())
+		);
+		assert!(GameModule::<T>::suspensions_ending(BlockNumberFor::<T>::from(10u32)).is_empty());
+	}
+
+	#[benchmark]
+	fn ban_player() {
+		let caller = create_setup::<T>();
+		assert_ok!(GameModule::<T>::suspend_player(
+			RawOrigin::Root.into(),
+			caller.clone(),
+			10u32.into(),
+			Default::default()
+		));
+		#[extrinsic_call]
+		ban_player(RawOrigin::Root, caller.clone(), Default::default());
+
+		assert_eq!(GameModule::<T>::users(&caller).unwrap().moderation, ModerationState::Banned);
+	}
+
+	#[benchmark]
+	fn reinstate_player() {
+		let caller = create_setup::<T>();
+		assert_ok!(GameModule::<T>::suspend_player(
+			RawOrigin::Root.into(),
+			caller.clone(),
+			10u32.into(),
+			Default::default()
+		));
+		assert_ok!(GameModule::<T>::file_appeal(
+			RawOrigin::Signed(caller.clone()).into(),
+			Default::default()
+		));
+		#[extrinsic_call]
+		reinstate_player(RawOrigin::Root, caller.clone(), Default::default());
+
+		assert_eq!(GameModule::<T>::users(&caller).unwrap().moderation, ModerationState::Active);
+		assert!(GameModule::<T>::appeals(&caller).is_none());
+	}
+
+	#[benchmark]
+	fn file_appeal() {
+		let caller = create_setup::<T>();
+		assert_ok!(GameModule::<T>::ban_player(
+			RawOrigin::Root.into(),
+			caller.clone(),
+			Default::default()
+		));
 		#[extrinsic_call]
-		request_token(RawOrigin::Signed(caller));
+		file_appeal(RawOrigin::Signed(caller.clone()), Default::default());
+
+		assert!(GameModule::<T>::appeals(&caller).is_some());
+	}
+
+	#[benchmark]
+	fn reject_appeal() {
+		let caller = create_setup::<T>();
+		assert_ok!(GameModule::<T>::ban_player(
+			RawOrigin::Root.into(),
+			caller.clone(),
+			Default::default()
+		));
+		assert_ok!(GameModule::<T>::file_appeal(
+			RawOrigin::Signed(caller.clone()).into(),
+			Default::default()
+		));
+		#[extrinsic_call]
+		reject_appeal(RawOrigin::Root, caller.clone(), Default::default());
+
+		assert!(GameModule::<T>::appeals(&caller).is_none());
 	}
 
 	impl_benchmark_test_suite!(GameModule, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,356 @@
+//! Escrow of the nfts that are listed or offered on the marketplace.
+//!
+//! Listed and offered nfts are held by the pallet account until the listing or the offer is
+//! closed. While a nft is in escrow it does not count towards the collected colors of its owner.
+//! The account that put a nft into the escrow is tracked until the nft is released, so that a
+//! player with nfts on the marketplace can not deregister.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Moves a nft of the owner into the escrow of the pallet account.
+	pub(crate) fn escrow_nft(
+		owner: &AccountIdOf<T>,
+		collection_id: CollectionId<T>,
+		item_id: ItemId<T>,
+	) -> DispatchResult {
+		ensure!(
+			pallet_nfts::Pallet::<T>::owner(collection_id.into(), item_id.into()) ==
+				Some(owner.clone()),
+			Error::<T>::NoPermission
+		);
+		let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
+		pallet_nfts::Pallet::<T>::unlock_item_transfer(
+			pallet_origin,
+			collection_id.into(),
+			item_id.into(),
+		)?;
+		pallet_nfts::Pallet::<T>::do_transfer(
+			collection_id.into(),
+			item_id.into(),
+			Self::account_id(),
+			|_, _| Ok(()),
+		)?;
+		let points = Self::debit_nft(owner, collection_id)?;
+		EscrowDepositors::<T>::insert(
+			collection_id,
+			item_id,
+			EscrowDeposit { depositor: owner.clone(), points },
+		);
+		EscrowedNftCount::<T>::mutate(owner, |count| *count = count.saturating_add(1));
+		Ok(())
+	}
+
+	/// Releases a nft from the escrow to the receiver. A nft that is returned to its depositor
+	/// gives back the points that were deducted for it.
+	pub(crate) fn release_nft(
+		receiver: &AccountIdOf<T>,
+		collection_id: CollectionId<T>,
+		item_id: ItemId<T>,
+	) -> DispatchResult {
+		let mut refund = None;
+		if let Some(deposit) = EscrowDepositors::<T>::take(collection_id, item_id) {
+			EscrowedNftCount::<T>::mutate(&deposit.depositor, |count| {
+				*count = count.saturating_sub(1)
+			});
+			if deposit.depositor == *receiver {
+				refund = Some(deposit.points);
+			}
+		}
+		pallet_nfts::Pallet::<T>::do_transfer(
+			collection_id.into(),
+			item_id.into(),
+			receiver.clone(),
+			|_, _| Ok(()),
+		)?;
+		let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
+		pallet_nfts::Pallet::<T>::lock_item_transfer(
+			pallet_origin,
+			collection_id.into(),
+			item_id.into(),
+		)?;
+		Self::credit_nft(receiver, collection_id, refund)
+	}
+
+	/// Returns the nfts of all remaining offers of a listing to their owners.
+	pub(crate) fn refund_offers(listing_id: u32) -> DispatchResult {
+		for offer_id in ListingOffers::<T>::take(listing_id) {
+			if let Some(offer) = Offers::<T>::take(offer_id) {
+				Self::unschedule_offer_expiry(offer_id, offer.expiry);
+				Self::release_nft(&offer.owner, offer.collection_id, offer.item_id)?;
+				Self::deposit_event(Event::<T>::OfferRefunded { owner: offer.owner, offer_id });
+			}
+		}
+		Ok(())
+	}
+
+	/// Adds a nft that the account received to its collected colors together with the points.
+	/// With `refund` the account gets these points instead of the points of the color.
+	///
+	/// Only nfts of the round the collected colors refer to are counted. A nft of the current
+	/// round moves the user on to the current round.
+	fn credit_nft(
+		account: &AccountIdOf<T>,
+		collection_id: CollectionId<T>,
+		refund: Option<u32>,
+	) -> DispatchResult {
+		let Some(before) = Users::<T>::get(account) else { return Ok(()) };
+		let mut user = before.clone();
+		let current_round = Self::current_round();
+		if !RoundCollections::<T>::get(user.last_played_round).contains(&collection_id) {
+			if !RoundCollections::<T>::get(current_round).contains(&collection_id) {
+				return Ok(());
+			}
+			user.enter_round(current_round);
+		}
+		let color = Self::collection_color(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		user.add_nft_color(color.clone())?;
+		let points = refund.unwrap_or_else(|| user.calculate_points(color));
+		user.points = user.points.checked_add(points).ok_or(Error::<T>::ArithmeticOverflow)?;
+		Users::<T>::insert(account, user.clone());
+		Self::update_leaderboards(account, &before, &user);
+		if user.has_four_of_all_colors() &&
+			user.last_played_round == current_round &&
+			Self::round_active()
+		{
+			Self::end_game(account.clone())?;
+		}
+		Ok(())
+	}
+
+	/// Removes a nft that left the account from its collected colors together with the points
+	/// and returns the points that were deducted. The points do not drop below the points
+	/// floor, so that a nft can always be escrowed.
+	fn debit_nft(
+		account: &AccountIdOf<T>,
+		collection_id: CollectionId<T>,
+	) -> Result<u32, DispatchError> {
+		let Some(before) = Users::<T>::get(account) else { return Ok(0) };
+		let mut user = before.clone();
+		if !RoundCollections::<T>::get(user.last_played_round).contains(&collection_id) {
+			return Ok(0);
+		}
+		let color = Self::collection_color(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		user.sub_nft_color(color.clone())?;
+		let points = user.subtracting_calculate_points(color);
+		let deducted = user.deduct_points(points, T::PointsFloor::get());
+		Users::<T>::insert(account, user.clone());
+		Self::update_leaderboards(account, &before, &user);
+		Ok(deducted)
+	}
+
+	/// Checks that every escrowed nft is held by the pallet account and counted for its depositor,
+	/// that the highest bids are counted for their bidders, that the offer index matches the
+	/// offers and the standing orders, that every running auction and every listing and offer
+	/// that expires is scheduled, that the suspensions ending and the appeals match the
+	/// moderation of the players, that the bans are kept in the banned accounts, that the scores
+	/// match the points of the users, that the leaderboards are sorted, that the unclaimed prizes
+	/// add up to their total and that the collected colors of every user match the nfts the user
+	/// owns.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		use frame_support::traits::tokens::nonfungibles_v2::InspectEnumerable;
+
+		let escrowed = |collection_id: CollectionId<T>, item_id: ItemId<T>| {
+			pallet_nfts::Pallet::<T>::owner(collection_id.into(), item_id.into()) ==
+				Some(Self::account_id())
+		};
+		let swept = ExpirySweepBlock::<T>::get();
+		let unswept = |expiry: &BlockNumberFor<T>| swept.map_or(true, |swept| *expiry >= swept);
+		for (listing_id, listing) in Listings::<T>::iter() {
+			ensure!(
+				escrowed(listing.collection_id, listing.item_id),
+				"Listed nft is not held by the pallet"
+			);
+			if let Some(expiry) = listing.expiry.filter(|expiry| unswept(expiry)) {
+				ensure!(
+					ListingsExpiring::<T>::get(expiry).contains(&listing_id),
+					"Listing is missing in the listings expiring"
+				);
+			}
+		}
+		for (_, listing_ids) in ListingsExpiring::<T>::iter() {
+			ensure!(
+				listing_ids.iter().all(|listing_id| Listings::<T>::contains_key(listing_id)),
+				"Closed listing in the listings expiring"
+			);
+		}
+		for (_, offer_ids) in OffersExpiring::<T>::iter() {
+			ensure!(
+				offer_ids.iter().all(|offer_id| Offers::<T>::contains_key(offer_id)),
+				"Closed offer in the offers expiring"
+			);
+		}
+		for (auction_id, auction) in Auctions::<T>::iter() {
+			ensure!(
+				escrowed(auction.collection_id, auction.item_id),
+				"Auctioned nft is not held by the pallet"
+			);
+			ensure!(
+				auction.end_block <= <frame_system::Pallet<T>>::block_number() ||
+					AuctionsEnding::<T>::get(auction.end_block).contains(&auction_id),
+				"Auction is missing in the auctions ending"
+			);
+		}
+		for (offer_id, offer) in Offers::<T>::iter() {
+			ensure!(
+				escrowed(offer.collection_id, offer.item_id),
+				"Offered nft is not held by the pallet"
+			);
+			ensure!(Listings::<T>::contains_key(offer.listing_id), "Offer for a closed listing");
+			if let Some(expiry) = offer.expiry.filter(|expiry| unswept(expiry)) {
+				ensure!(
+					OffersExpiring::<T>::get(expiry).contains(&offer_id),
+					"Offer is missing in the offers expiring"
+				);
+			}
+			ensure!(
+				ListingOffers::<T>::get(offer.listing_id).contains(&offer_id),
+				"Offer is missing in the offer index"
+			);
+		}
+		for (order_id, order) in StandingOrders::<T>::iter() {
+			ensure!(
+				escrowed(order.collection_id, order.item_id),
+				"Nft of a standing order is not held by the pallet"
+			);
+			ensure!(
+				Self::collection_color(order.collection_id) == Some(order.give.clone()),
+				"Standing order offers a nft of another color"
+			);
+			ensure!(
+				StandingOrdersByColor::<T>::get(&order.give, &order.want).contains(&order_id),
+				"Standing order is missing in the standing orders by color"
+			);
+		}
+		for (give, want, order_ids) in StandingOrdersByColor::<T>::iter() {
+			for order_id in order_ids {
+				ensure!(
+					StandingOrders::<T>::get(order_id)
+						.map_or(false, |order| order.give == give && order.want == want),
+					"Standing orders by color contain an unknown order"
+				);
+			}
+		}
+		for (listing_id, offer_ids) in ListingOffers::<T>::iter() {
+			ensure!(Listings::<T>::contains_key(listing_id), "Offer index of a closed listing");
+			for offer_id in offer_ids {
+				ensure!(
+					Offers::<T>::get(offer_id).map(|offer| offer.listing_id) == Some(listing_id),
+					"Offer index contains an unknown offer"
+				);
+			}
+		}
+		for (collection_id, item_id, _) in EscrowDepositors::<T>::iter() {
+			ensure!(escrowed(collection_id, item_id), "Deposited nft is not held by the pallet");
+		}
+		for (account, count) in EscrowedNftCount::<T>::iter() {
+			ensure!(
+				EscrowDepositors::<T>::iter_values()
+					.filter(|deposit| deposit.depositor == account)
+					.count() ==
+					count as usize,
+				"Escrowed nft count does not match the deposited nfts"
+			);
+		}
+		for (account, count) in HighestBidCount::<T>::iter() {
+			ensure!(
+				Auctions::<T>::iter_values()
+					.filter(|auction| {
+						auction.highest_bid.as_ref().map(|(bidder, _)| bidder) == Some(&account)
+					})
+					.count() ==
+					count as usize,
+				"Highest bid count does not match the auctions"
+			);
+		}
+		for (until, players) in SuspensionsEnding::<T>::iter() {
+			for player in players {
+				ensure!(
+					Users::<T>::get(&player).map(|user| user.moderation) ==
+						Some(ModerationState::Suspended(until)) ||
+						SuspendedAccounts::<T>::get(&player) == Some(until),
+					"Suspensions ending contain a player that is not suspended until then"
+				);
+			}
+		}
+		for (player, until) in SuspendedAccounts::<T>::iter() {
+			ensure!(
+				!Users::<T>::contains_key(&player),
+				"Suspended account of a player that is registered"
+			);
+			ensure!(
+				SuspensionsEnding::<T>::get(until).contains(&player),
+				"Suspended account is missing in the suspensions ending"
+			);
+		}
+		for (player, round) in PlayerRounds::<T>::iter_keys() {
+			ensure!(Users::<T>::contains_key(&player), "Rounds of a player that is not registered");
+			ensure!(round <= Self::current_round(), "Round of a player has not started yet");
+		}
+		for (player, _) in Appeals::<T>::iter() {
+			ensure!(!Self::is_active(&player), "Appeal of a player that is not restricted");
+		}
+		for (leaderboard, runners_up) in [
+			(Leaderboard::<T>::get(), LeaderboardRunnersUp::<T>::get()),
+			(RoundLeaderboard::<T>::get(), RoundRunnersUp::<T>::get()),
+		] {
+			ensure!(
+				runners_up.is_empty() || leaderboard.len() as u32 == T::LeaderboardLimit::get(),
+				"Runners-up of a leaderboard that is not full"
+			);
+			let ranked = leaderboard.into_iter().chain(runners_up).collect::<sp_std::vec::Vec<_>>();
+			ensure!(
+				ranked.windows(2).all(|pair| pair[0].1.rank_cmp(&pair[1].1).is_le()),
+				"Leaderboard is not sorted by rank"
+			);
+		}
+		for (account, score) in
+			Leaderboard::<T>::get().into_iter().chain(LeaderboardRunnersUp::<T>::get())
+		{
+			ensure!(
+				Users::<T>::get(&account).map(|user| user.points) == Some(score.points),
+				"Leaderboard does not match the points of the user"
+			);
+		}
+		for (account, score) in Scores::<T>::iter() {
+			ensure!(
+				Users::<T>::get(&account).map(|user| user.points) == Some(score.points),
+				"Score does not match the points of the user"
+			);
+		}
+		let unclaimed = RoundPrizes::<T>::iter_values()
+			.fold(CurrencyBalanceOf::<T>::default(), |total, amount| total.saturating_add(amount));
+		ensure!(
+			unclaimed == UnclaimedPrizes::<T>::get(),
+			"Unclaimed prizes do not match the prizes of the rounds"
+		);
+		for (account, user) in Users::<T>::iter() {
+			let mut owned = CollectedColors::default();
+			for collection_id in RoundCollections::<T>::get(user.last_played_round) {
+				let color =
+					Self::collection_color(collection_id).ok_or("Collection without a color")?;
+				let collection_id: <T as pallet_nfts::Config>::CollectionId = collection_id.into();
+				for _ in pallet_nfts::Pallet::<T>::owned_in_collection(&collection_id, &account) {
+					owned.add_nft_color(color.clone())?;
+				}
+			}
+			ensure!(owned == user.nfts, "Collected colors do not match the owned nfts");
+			ensure!(
+				(user.moderation == ModerationState::Banned) ==
+					BannedAccounts::<T>::contains_key(&account),
+				"Ban of the player does not match the banned accounts"
+			);
+			if let ModerationState::Suspended(until) = user.moderation {
+				ensure!(
+					until <= <frame_system::Pallet<T>>::block_number() ||
+						SuspensionsEnding::<T>::get(until).contains(&account),
+					"Suspension is missing in the suspensions ending"
+				);
+			}
+		}
+		Ok(())
+	}
+}
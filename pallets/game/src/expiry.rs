@@ -0,0 +1,136 @@
+//! Expiry of the listings and offers of the marketplace.
+//!
+//! Listings and offers can expire in a given block. The escrowed nfts of expired listings and
+//! offers are returned to their owners in `on_idle`, as far as the remaining weight of the block
+//! allows. Blocks that could not be swept completely are continued in the following blocks.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, weights::WeightMeter};
+use frame_system::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Returns true if the expiry block has been reached.
+	pub(crate) fn is_expired(expiry: Option<BlockNumberFor<T>>) -> bool {
+		expiry.map_or(false, |expiry| expiry <= <frame_system::Pallet<T>>::block_number())
+	}
+
+	/// Checks that the expiry block is in the future.
+	pub(crate) fn ensure_valid_expiry(expiry: BlockNumberFor<T>) -> DispatchResult {
+		ensure!(expiry > <frame_system::Pallet<T>>::block_number(), Error::<T>::InvalidExpiry);
+		Ok(())
+	}
+
+	/// Removes a closed listing from the listings that expire in its expiry block.
+	pub(crate) fn unschedule_listing_expiry(listing_id: u32, expiry: Option<BlockNumberFor<T>>) {
+		let Some(expiry) = expiry else { return };
+		ListingsExpiring::<T>::mutate_exists(expiry, |listings| {
+			if let Some(ids) = listings {
+				ids.retain(|id| *id != listing_id);
+				if ids.is_empty() {
+					*listings = None;
+				}
+			}
+		});
+	}
+
+	/// Removes a closed offer from the offers that expire in its expiry block.
+	pub(crate) fn unschedule_offer_expiry(offer_id: u32, expiry: Option<BlockNumberFor<T>>) {
+		let Some(expiry) = expiry else { return };
+		OffersExpiring::<T>::mutate_exists(expiry, |offers| {
+			if let Some(ids) = offers {
+				ids.retain(|id| *id != offer_id);
+				if ids.is_empty() {
+					*offers = None;
+				}
+			}
+		});
+	}
+
+	/// Returns the nfts of the listings and offers that expired until block `n`, without
+	/// using more than `limit` weight.
+	pub(crate) fn sweep_expired(n: BlockNumberFor<T>, limit: Weight) -> Weight {
+		let mut meter = WeightMeter::with_limit(limit);
+		if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+			return meter.consumed();
+		}
+		let listing_weight = <T as Config>::WeightInfo::expire_listing().saturating_add(
+			<T as Config>::WeightInfo::withdraw_offer()
+				.saturating_mul(T::MaxOffersPerListing::get().into()),
+		);
+		let offer_weight = <T as Config>::WeightInfo::expire_offer();
+		let mut block = ExpirySweepBlock::<T>::get().unwrap_or(n);
+		while block <= n {
+			if meter.try_consume(T::DbWeight::get().reads_writes(2, 2)).is_err() {
+				break;
+			}
+			let mut offers = OffersExpiring::<T>::take(block);
+			while let Some(offer_id) = offers.first().copied() {
+				if meter.try_consume(offer_weight).is_err() {
+					break;
+				}
+				offers.remove(0);
+				if let Err(error) = Self::expire_offer(offer_id, block) {
+					log::warn!(target: LOG_TARGET, "Offer {} did not expire: {:?}", offer_id, error);
+				}
+			}
+			if !offers.is_empty() {
+				OffersExpiring::<T>::insert(block, offers);
+				break;
+			}
+			let mut listings = ListingsExpiring::<T>::take(block);
+			while let Some(listing_id) = listings.first().copied() {
+				if meter.try_consume(listing_weight).is_err() {
+					break;
+				}
+				listings.remove(0);
+				if let Err(error) = Self::expire_listing(listing_id, block) {
+					log::warn!(
+						target: LOG_TARGET,
+						"Listing {} did not expire: {:?}",
+						listing_id,
+						error
+					);
+				}
+			}
+			if !listings.is_empty() {
+				ListingsExpiring::<T>::insert(block, listings);
+				break;
+			}
+			block = block.saturating_add(1u32.into());
+		}
+		ExpirySweepBlock::<T>::put(block);
+		meter.consumed()
+	}
+
+	/// Closes a listing that expires in `block` and returns its nft and the nfts of its offers.
+	pub(crate) fn expire_listing(listing_id: u32, block: BlockNumberFor<T>) -> DispatchResult {
+		let Some(listing) = Listings::<T>::get(listing_id) else { return Ok(()) };
+		if listing.expiry != Some(block) {
+			return Ok(());
+		}
+		frame_support::storage::with_storage_layer(|| {
+			Listings::<T>::remove(listing_id);
+			Self::refund_offers(listing_id)?;
+			Self::release_nft(&listing.owner, listing.collection_id, listing.item_id)?;
+			Self::deposit_event(Event::<T>::ListingExpired { listing_id, owner: listing.owner });
+			Ok(())
+		})
+	}
+
+	/// Closes an offer that expires in `block` and returns its nft.
+	pub(crate) fn expire_offer(offer_id: u32, block: BlockNumberFor<T>) -> DispatchResult {
+		let Some(offer) = Offers::<T>::get(offer_id) else { return Ok(()) };
+		if offer.expiry != Some(block) {
+			return Ok(());
+		}
+		frame_support::storage::with_storage_layer(|| {
+			Offers::<T>::remove(offer_id);
+			ListingOffers::<T>::mutate(offer.listing_id, |offers| {
+				offers.retain(|id| *id != offer_id)
+			});
+			Self::release_nft(&offer.owner, offer.collection_id, offer.item_id)?;
+			Self::deposit_event(Event::<T>::OfferExpired { offer_id, owner: offer.owner });
+			Ok(())
+		})
+	}
+}
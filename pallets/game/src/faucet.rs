@@ -0,0 +1,57 @@
+//! Faucet of the game.
+//!
+//! The faucet drips token from the pallet account to the players, so the total issuance is not
+//! changed. A drip only tops the free balance of a player up to the faucet target and the faucet
+//! does not drip more than its cap within a faucet period. The funds of the prize pool and of the
+//! prizes that have not been claimed yet are never dripped.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, sp_runtime::traits::Zero};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+impl<T: Config> Pallet<T> {
+	/// Returns the amount that the faucet drips to the account.
+	pub(crate) fn faucet_amount(
+		account: &AccountIdOf<T>,
+	) -> Result<CurrencyBalanceOf<T>, Error<T>> {
+		let missing = T::FaucetTarget::get()
+			.saturating_sub(<T as pallet::Config>::Currency::free_balance(account));
+		ensure!(!missing.is_zero(), Error::<T>::FaucetTargetReached);
+		let (_, dripped) = Self::current_faucet_usage();
+		let remaining = T::FaucetCap::get().saturating_sub(dripped);
+		ensure!(!remaining.is_zero(), Error::<T>::FaucetCapReached);
+		let available = <T as pallet::Config>::Currency::free_balance(&Self::account_id())
+			.saturating_sub(<T as pallet::Config>::Currency::minimum_balance())
+			.saturating_sub(Self::prize_pool())
+			.saturating_sub(Self::unclaimed_prizes());
+		ensure!(!available.is_zero(), Error::<T>::FaucetDepleted);
+		Ok(T::FaucetDripAmount::get().min(missing).min(remaining).min(available))
+	}
+
+	/// Transfers the amount from the pallet account to the account and counts it towards the
+	/// cap of the current faucet period.
+	pub(crate) fn drip(account: &AccountIdOf<T>, amount: CurrencyBalanceOf<T>) -> DispatchResult {
+		<T as pallet::Config>::Currency::transfer(
+			&Self::account_id(),
+			account,
+			amount,
+			ExistenceRequirement::KeepAlive,
+		)?;
+		let (start, dripped) = Self::current_faucet_usage();
+		FaucetUsage::<T>::put((start, dripped.saturating_add(amount)));
+		Self::deposit_event(Event::<T>::FaucetDrip { player: account.clone(), amount });
+		Ok(())
+	}
+
+	/// Returns the first block of the current faucet period together with the amount that the
+	/// faucet dripped in it.
+	fn current_faucet_usage() -> (BlockNumberFor<T>, CurrencyBalanceOf<T>) {
+		let current_block_number = <frame_system::Pallet<T>>::block_number();
+		let (start, dripped) = Self::faucet_usage();
+		if current_block_number >= start.saturating_add(T::FaucetCapPeriod::get()) {
+			(current_block_number, Zero::zero())
+		} else {
+			(start, dripped)
+		}
+	}
+}
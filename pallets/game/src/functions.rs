@@ -1,5 +1,6 @@
 use crate::*;
 use frame_support::pallet_prelude::*;
+use frame_support::sp_runtime::traits::Hash;
 use frame_system::pallet_prelude::*;
 
 impl<T: Config> Pallet<T> {
@@ -8,376 +9,457 @@ impl<T: Config> Pallet<T> {
 		<T as pallet::Config>::PalletId::get().into_account_truncating()
 	}
 
+	/// Calculates the commitment of a property from its price, the salt and the property data.
+	pub fn property_commitment(price: u64, salt: &[u8], data: &[u8]) -> T::Hash {
+		T::Hashing::hash_of(&(price, salt, data))
+	}
+
+	/// Verifies the revealed price of a game against the commitment and settles the game with the
+	/// answer that the player submitted.
+	pub fn do_settle_game(
+		game_id: u32,
+		price: u64,
+		secret: BoundedVec<u8, <T as Config>::StringLimit>,
+	) -> DispatchResult {
+		let game_info = GameInfo::<T>::take(game_id).ok_or(Error::<T>::NoActiveGame)?;
+		ensure!(
+			Self::property_commitment(price, &secret, &game_info.property.data) ==
+				game_info.property.commitment,
+			Error::<T>::CommitmentMismatch
+		);
+		Self::close_game(game_id, &game_info);
+		Self::reveal_property(game_info.property.id);
+		if let Some(guess) = game_info.guess {
+			let difference_value = ((price as i64)
+				.checked_sub(guess as i64)
+				.ok_or(Error::<T>::ArithmeticUnderflow)?)
+			.checked_mul(1000)
+			.ok_or(Error::<T>::MultiplyError)?
+			.checked_div(price as i64)
+			.ok_or(Error::<T>::DivisionError)?
+			.abs();
+			Self::do_check_result(
+				difference_value.try_into().map_err(|_| Error::<T>::ConversionError)?,
+				game_id,
+				game_info,
+				secret,
+			)?;
+		} else {
+			Self::no_answer_result(game_info, game_id)?;
+		}
+		Ok(())
+	}
+
+	/// Returns the scoring table of a difficulty level.
+	pub fn scoring_config(difficulty: DifficultyLevel) -> ScoringConfig<T> {
+		ScoringConfigs::<T>::get(difficulty.clone())
+			.unwrap_or_else(|| ScoringConfig::default_for(difficulty))
+	}
+
 	/// checks if the signer has enough points to start a game.
 	pub fn check_enough_points(
 		signer: AccountIdOf<T>,
 		game_type: DifficultyLevel,
 	) -> DispatchResult {
-		if game_type == DifficultyLevel::Pro {
-			ensure!(
-				Self::users(signer.clone())
-					.ok_or(Error::<T>::UserNotRegistered)?
-					.practise_rounds > 0,
-				Error::<T>::NoPractise
-			);
-			ensure!(
-				Self::users(signer).ok_or(Error::<T>::UserNotRegistered)?.points >= 50,
-				Error::<T>::NotEnoughPoints
-			);
-		} else if game_type == DifficultyLevel::Player {
-			ensure!(
-				Self::users(signer.clone())
-					.ok_or(Error::<T>::UserNotRegistered)?
-					.practise_rounds > 0,
-				Error::<T>::NoPractise
-			);
-			ensure!(
-				Self::users(signer).ok_or(Error::<T>::UserNotRegistered)?.points >= 25,
-				Error::<T>::NotEnoughPoints
-			);
+		let user = Self::users(signer).ok_or(Error::<T>::UserNotRegistered)?;
+		if game_type == DifficultyLevel::Practice {
+			ensure!(user.practise_rounds < 5, Error::<T>::TooManyPractise);
 		} else {
+			ensure!(user.practise_rounds > 0, Error::<T>::NoPractise);
 			ensure!(
-				Self::users(signer).ok_or(Error::<T>::UserNotRegistered)?.practise_rounds < 5,
-				Error::<T>::TooManyPractise
+				user.points >= Self::scoring_config(game_type).entry_points,
+				Error::<T>::NotEnoughPoints
 			);
 		}
 		Ok(())
 	}
 
 	/// checks the answer and distributes the rewards accordingly.
-	pub fn do_check_result(difference: u64, game_id: u32, game_info: GameData<T>, secret: BoundedVec<u8, <T as Config>::StringLimit> ) -> DispatchResult {
+	pub fn do_check_result(
+		difference: u64,
+		game_id: u32,
+		game_info: GameData<T>,
+		secret: BoundedVec<u8, <T as Config>::StringLimit>,
+	) -> DispatchResult {
 		ensure!(game_info.guess.is_some(), Error::<T>::NoGuess);
-		if game_info.difficulty == DifficultyLevel::Pro {
-			match difference {
-				0..=10 => {
-					let (hashi, _) = T::GameRandomness::random(&[game_id as u8]);
-					let u32_value = u32::from_le_bytes(
-						hashi.as_ref()[4..8].try_into().map_err(|_| Error::<T>::ConversionError)?,
-					);
-					let random_number = (u32_value % 8)
-						.checked_add(
-							8 * (Self::current_round()
-								.checked_sub(1)
-								.ok_or(Error::<T>::ArithmeticUnderflow)?),
-						)
-						.ok_or(Error::<T>::ArithmeticOverflow)?;
-					let collection_id: <T as pallet::Config>::CollectionId = random_number.into();
-					let next_item_id = NextColorId::<T>::get(collection_id);
-					let item_id: ItemId<T> = next_item_id.into();
-					let next_item_id =
-						next_item_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					NextColorId::<T>::insert(collection_id, next_item_id);
-					pallet_nfts::Pallet::<T>::do_mint(
-						collection_id.into(),
-						item_id.into(),
-						Some(Self::account_id()),
-						game_info.player.clone(),
-						Self::default_item_config(),
-						|_, _| Ok(()),
-					)?;
-					let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
-					pallet_nfts::Pallet::<T>::lock_item_transfer(
-						pallet_origin,
-						collection_id.into(),
-						item_id.into(),
-					)?;
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					let color = Self::collection_color(collection_id)
-						.ok_or(Error::<T>::CollectionUnknown)?;
-					user.add_nft_color(color.clone())?;
-					let points = user.calculate_points(color);
-					user.points =
-						user.points.checked_add(points).ok_or(Error::<T>::ArithmeticOverflow)?;
-					user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user.clone());
-					if user.has_four_of_all_colors() {
-						Self::end_game(game_info.player.clone())?;
-					}
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points, won: true, nft_received: true });
-				},
-				11..=30 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_add(50).ok_or(Error::<T>::ArithmeticOverflow)?;
-					user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 50, won: true, nft_received: false });
-				},
-				31..=50 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_add(30).ok_or(Error::<T>::ArithmeticOverflow)?;
-					user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 30, won: true, nft_received: false });
-				},
-				51..=100 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_add(10).ok_or(Error::<T>::ArithmeticOverflow)?;
-					user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 10, won: true, nft_received: false });
-				},
-				101..=150 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(10).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 10, won: false, nft_received: false });
-				},
-				151..=200 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(20).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 20, won: false, nft_received: false });
-				},
-				201..=250 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(30).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 30, won: false, nft_received: false });
-				},
-				251..=300 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(40).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 40, won: false, nft_received: false });
-				},
-				_ => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(50).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 50, won: false, nft_received: false });
-				},
+		let config = Self::scoring_config(game_info.difficulty.clone());
+		let before = Self::users(game_info.player.clone()).ok_or(Error::<T>::UserNotRegistered)?;
+		let mut user = before.clone();
+		if config.nft_threshold.map_or(false, |threshold| difference <= threshold) {
+			let color = Self::mint_random_nft(game_id, game_info.player.clone())?;
+			user.enter_round(Self::current_round());
+			user.add_nft_color(color.clone())?;
+			let points = user.calculate_points(color);
+			user.points = user.points.checked_add(points).ok_or(Error::<T>::ArithmeticOverflow)?;
+			if game_info.difficulty == DifficultyLevel::Practice {
+				user.practise_rounds =
+					user.practise_rounds.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+			} else {
+				user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+			}
+			Users::<T>::insert(game_info.player.clone(), user.clone());
+			Self::update_leaderboards(&game_info.player, &before, &user);
+			if user.has_four_of_all_colors() {
+				Self::end_game(game_info.player.clone())?;
 			}
-		} else if game_info.difficulty == DifficultyLevel::Player {
-			match difference {
-				0..=10 => {
-					let (hashi, _) = T::GameRandomness::random(&[game_id as u8]);
-					let u32_value = u32::from_le_bytes(
-						hashi.as_ref()[4..8].try_into().map_err(|_| Error::<T>::ConversionError)?,
-					);
-					let random_number = (u32_value % 8)
-						.checked_add(
-							8 * (Self::current_round()
-								.checked_sub(1)
-								.ok_or(Error::<T>::ArithmeticUnderflow)?),
-						)
-						.ok_or(Error::<T>::ArithmeticOverflow)?;
-					let collection_id: <T as pallet::Config>::CollectionId = random_number.into();
-					let next_item_id = NextColorId::<T>::get(collection_id);
-					let item_id: ItemId<T> = next_item_id.into();
-					let next_item_id =
-						next_item_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					NextColorId::<T>::insert(collection_id, next_item_id);
-					pallet_nfts::Pallet::<T>::do_mint(
-						collection_id.into(),
-						item_id.into(),
-						Some(Self::account_id()),
-						game_info.player.clone(),
-						Self::default_item_config(),
-						|_, _| Ok(()),
-					)?;
-					let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
-					pallet_nfts::Pallet::<T>::lock_item_transfer(
-						pallet_origin,
-						collection_id.into(),
-						item_id.into(),
-					)?;
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					let color = Self::collection_color(collection_id)
-						.ok_or(Error::<T>::CollectionUnknown)?;
-					user.add_nft_color(color.clone())?;
-					let points = user.calculate_points(color);
+			Self::deposit_event(Event::<T>::ResultChecked {
+				game_id,
+				secret,
+				points,
+				won: true,
+				nft_received: true,
+			});
+		} else {
+			let (points, won) = match config.outcome(difference) {
+				ScoreOutcome::Reward(points) => {
 					user.points =
 						user.points.checked_add(points).ok_or(Error::<T>::ArithmeticOverflow)?;
-					user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user.clone());
-					if user.has_four_of_all_colors() {
-						Self::end_game(game_info.player.clone())?;
-					}
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points, won: true, nft_received: true });
-				},
-				11..=30 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_add(25).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 25, won: true, nft_received: false });
-				},
-				31..=50 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_add(15).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 15, won: true, nft_received: false });
-				},
-				51..=100 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_add(5).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 5, won: true, nft_received: false });
-				},
-				101..=150 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(5).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 5, won: false, nft_received: false });
-				},
-				151..=200 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(10).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 10, won: false, nft_received: false });
-				},
-				201..=250 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(15).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 15, won: false, nft_received: false });
-				},
-				251..=300 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(20).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 20, won: false, nft_received: false });
-				},
-				_ => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(25).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 25, won: false, nft_received: false });
+					(points, true)
 				},
+				ScoreOutcome::Penalty(points) =>
+					(user.deduct_points(points, T::PointsFloor::get()), false),
+			};
+			if game_info.difficulty == DifficultyLevel::Practice {
+				user.practise_rounds =
+					user.practise_rounds.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+			} else if won {
+				user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+			} else {
+				user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
 			}
-		} else {
-			let mut user =
-				Self::users(game_info.player.clone()).ok_or(Error::<T>::UserNotRegistered)?;
-			user.points = user.points.checked_add(5).ok_or(Error::<T>::ArithmeticUnderflow)?;
-			user.practise_rounds =
-				user.practise_rounds.checked_add(1).ok_or(Error::<T>::ArithmeticUnderflow)?;
-			Users::<T>::insert(game_info.player.clone(), user);
-			Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 5, won: true, nft_received: false });
+			Users::<T>::insert(game_info.player.clone(), user.clone());
+			Self::update_leaderboards(&game_info.player, &before, &user);
+			Self::deposit_event(Event::<T>::ResultChecked {
+				game_id,
+				secret,
+				points,
+				won,
+				nft_received: false,
+			});
 		}
-		let user = Self::users(game_info.player.clone()).ok_or(Error::<T>::UserNotRegistered)?;
-		Self::update_leaderboard(game_info.player, user.points)?;
 		Ok(())
 	}
 
-	pub fn update_leaderboard(user_id: AccountIdOf<T>, new_points: u32) -> DispatchResult {
-		let mut leaderboard = Self::leaderboard();
-		let leaderboard_size = leaderboard.len();
+	/// Draws a random number for a game.
+	///
+	/// The subject of the draw contains its purpose, the game, the player and the block number,
+	/// so that every draw of a block gets its own value.
+	pub fn random_number(
+		purpose: &[u8],
+		game_id: u32,
+		player: &AccountIdOf<T>,
+	) -> Result<u32, DispatchError> {
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		let subject = (purpose, game_id, player, block_number).encode();
+		let (hashi, _) = T::GameRandomness::random(&subject);
+		let u32_value = u32::from_le_bytes(
+			hashi.as_ref()[4..8].try_into().map_err(|_| Error::<T>::ConversionError)?,
+		);
+		Ok(u32_value)
+	}
+
+	/// Mints a nft of a random color of the current round to the player.
+	pub fn mint_random_nft(
+		game_id: u32,
+		player: AccountIdOf<T>,
+	) -> Result<NftColor, DispatchError> {
+		let u32_value = Self::random_number(randomness::NFT_SUBJECT, game_id, &player)?;
+		let color_index = u32_value % 8;
+		let collection_id = Self::round_collection(Self::current_round(), color_index)?;
+		let next_item_id = NextColorId::<T>::get(collection_id);
+		let item_id: ItemId<T> = next_item_id.into();
+		let next_item_id = next_item_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+		NextColorId::<T>::insert(collection_id, next_item_id);
+		pallet_nfts::Pallet::<T>::do_mint(
+			collection_id.into(),
+			item_id.into(),
+			Some(Self::account_id()),
+			player,
+			Self::default_item_config(),
+			|_, _| Ok(()),
+		)?;
+		let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
+		pallet_nfts::Pallet::<T>::lock_item_transfer(
+			pallet_origin,
+			collection_id.into(),
+			item_id.into(),
+		)?;
+		let color = NftColor::from_index(color_index as usize).ok_or(Error::<T>::InvalidIndex)?;
+		Rounds::<T>::mutate(Self::current_round(), |round| {
+			if let Some(round) = round {
+				round.nfts_minted = round.nfts_minted.saturating_add(1);
+			}
+		});
+		Ok(color)
+	}
+
+	/// Returns the collection of a round that belongs to the color with the given index.
+	pub fn round_collection(
+		round: u32,
+		color_index: u32,
+	) -> Result<CollectionId<T>, DispatchError> {
+		RoundCollections::<T>::get(round)
+			.get(color_index as usize)
+			.copied()
+			.ok_or(Error::<T>::CollectionUnknown.into())
+	}
 
-		if let Some((_, user_points)) = leaderboard.iter_mut().find(|(id, _)| *id == user_id) {
-			*user_points = new_points;
-			leaderboard.sort_by(|a, b| b.1.cmp(&a.1));
-			Leaderboard::<T>::put(leaderboard);
-			return Ok(());
+	/// Updates the all-time leaderboard and the leaderboard of the current round after the
+	/// points or the wins of a user changed.
+	///
+	/// While a round is active, the points and wins the user gained or lost are also added to the
+	/// score of the user in the current round. Only the accounts on a leaderboard and its
+	/// runners-up are ranked again, so that the update does not depend on the number of players.
+	pub fn update_leaderboards(account: &AccountIdOf<T>, before: &User<T>, after: &User<T>) {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let score = Scores::<T>::mutate(account, |score| {
+			if score.points != after.points {
+				score.points = after.points;
+				score.achieved = now;
+			}
+			score.wins = after.wins;
+			score.clone()
+		});
+		Leaderboard::<T>::mutate(|leaderboard| {
+			LeaderboardRunnersUp::<T>::mutate(|runners_up| {
+				Self::rank_on_leaderboard(leaderboard, runners_up, account, Some(score))
+			})
+		});
+		if !Self::round_active() {
+			return;
 		}
-		if new_points > 0 &&
-			(leaderboard_size < 10 ||
-				new_points > leaderboard.last().map(|(_, points)| *points).unwrap_or(0))
-		{
-			if leaderboard.len() >= 10 {
-				leaderboard.pop();
+		let round = Self::current_round();
+		let score = RoundScores::<T>::mutate(round, account, |score| {
+			let points = score.points.saturating_add(after.points).saturating_sub(before.points);
+			if points != score.points {
+				score.points = points;
+				score.achieved = now;
 			}
-			leaderboard
-				.try_push((user_id, new_points))
-				.map_err(|_| Error::<T>::InvalidIndex)?;
-			leaderboard.sort_by(|a, b| b.1.cmp(&a.1));
-			Leaderboard::<T>::put(leaderboard);
+			score.wins = score.wins.saturating_add(after.wins.saturating_sub(before.wins));
+			score.clone()
+		});
+		PlayerRounds::<T>::insert(account, round, ());
+		RoundLeaderboard::<T>::mutate(|leaderboard| {
+			RoundRunnersUp::<T>::mutate(|runners_up| {
+				Self::rank_on_leaderboard(leaderboard, runners_up, account, Some(score))
+			})
+		});
+	}
+
+	/// Removes an account from the leaderboards together with its all-time score and its score
+	/// of the current round. The runners-up move up into the places that become free.
+	pub(crate) fn remove_from_leaderboards(account: &AccountIdOf<T>) {
+		let round = Self::current_round();
+		Scores::<T>::remove(account);
+		RoundScores::<T>::remove(round, account);
+		Leaderboard::<T>::mutate(|leaderboard| {
+			LeaderboardRunnersUp::<T>::mutate(|runners_up| {
+				Self::rank_on_leaderboard(leaderboard, runners_up, account, None)
+			})
+		});
+		RoundLeaderboard::<T>::mutate(|leaderboard| {
+			RoundRunnersUp::<T>::mutate(|runners_up| {
+				Self::rank_on_leaderboard(leaderboard, runners_up, account, None)
+			})
+		});
+	}
+
+	/// Moves an account to the place of its score on a leaderboard and its runners-up.
+	///
+	/// The runners-up are the accounts that rank right below a full leaderboard. They move up
+	/// when an account on the leaderboard drops or is removed. Accounts without points or
+	/// without a score leave both. If both are full, the last runner-up drops out, unless the
+	/// score ranks below it.
+	pub(crate) fn rank_on_leaderboard(
+		leaderboard: &mut LeaderboardOf<T>,
+		runners_up: &mut LeaderboardOf<T>,
+		account: &AccountIdOf<T>,
+		score: Option<Score<BlockNumberFor<T>>>,
+	) {
+		let limit = T::LeaderboardLimit::get() as usize;
+		let mut ranked = sp_std::mem::take(leaderboard).into_inner();
+		ranked.append(&mut sp_std::mem::take(runners_up).into_inner());
+		ranked.retain(|(id, _)| id != account);
+		if let Some(score) = score.filter(|score| score.points > 0) {
+			let index = ranked
+				.iter()
+				.position(|(_, other)| score.rank_cmp(other).is_lt())
+				.unwrap_or(ranked.len());
+			ranked.insert(index, (account.clone(), score));
 		}
-		Ok(())
+		ranked.truncate(limit.saturating_mul(2));
+		let rest = ranked.split_off(ranked.len().min(limit));
+		*leaderboard = LeaderboardOf::<T>::truncate_from(ranked);
+		*runners_up = LeaderboardOf::<T>::truncate_from(rest);
 	}
 
-	pub fn swap_user_points(
-		nft_holder: AccountIdOf<T>,
-		collection_id_add: CollectionId<T>,
-		collection_id_sub: CollectionId<T>,
-	) -> DispatchResult {
-		let mut user = Self::users(nft_holder.clone()).ok_or(Error::<T>::UserNotRegistered)?;
-		let color_add =
-			Self::collection_color(collection_id_add).ok_or(Error::<T>::CollectionUnknown)?;
-		let color_sub =
-			Self::collection_color(collection_id_sub).ok_or(Error::<T>::CollectionUnknown)?;
-		user.add_nft_color(color_add.clone())?;
-		let points = user.calculate_points(color_add);
-		user.points = user.points.checked_add(points).ok_or(Error::<T>::ArithmeticOverflow)?;
-		user.sub_nft_color(color_sub.clone())?;
-		let points = user.subtracting_calculate_points(color_sub);
-		user.points = user.points.checked_sub(points).ok_or(Error::<T>::ArithmeticOverflow)?;
-		Users::<T>::insert(nft_holder.clone(), user.clone());
-		Self::update_leaderboard(nft_holder.clone(), user.points)?;
-		if user.has_four_of_all_colors() {
-			Self::end_game(nft_holder)?;
+	/// Ranks the scores on a new leaderboard and its runners-up.
+	pub(crate) fn rank_scores(
+		scores: impl Iterator<Item = (AccountIdOf<T>, Score<BlockNumberFor<T>>)>,
+	) -> (LeaderboardOf<T>, LeaderboardOf<T>) {
+		let mut leaderboard = LeaderboardOf::<T>::default();
+		let mut runners_up = LeaderboardOf::<T>::default();
+		for (account, score) in scores {
+			Self::rank_on_leaderboard(&mut leaderboard, &mut runners_up, &account, Some(score));
 		}
-		Ok(())
+		(leaderboard, runners_up)
 	}
 
 	/// Handles the case if the player did not answer on time.
 	pub fn no_answer_result(game_info: GameData<T>, game_id: u32) -> DispatchResult {
-		if game_info.difficulty == DifficultyLevel::Pro {
-			let mut user =
-				Self::users(game_info.player.clone()).ok_or(Error::<T>::UserNotRegistered)?;
-			user.points = user.points.checked_sub(50).ok_or(Error::<T>::ArithmeticUnderflow)?;
-			user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-			Users::<T>::insert(game_info.player.clone(), user);
-			Self::deposit_event(Event::<T>::NoAnswer { game_id, points: 50 });
-		} else if game_info.difficulty == DifficultyLevel::Player {
-			let mut user =
+		if game_info.difficulty != DifficultyLevel::Practice {
+			let penalty = Self::scoring_config(game_info.difficulty.clone()).no_answer_penalty;
+			let before =
 				Self::users(game_info.player.clone()).ok_or(Error::<T>::UserNotRegistered)?;
-			user.points = user.points.checked_sub(25).ok_or(Error::<T>::ArithmeticUnderflow)?;
+			let mut user = before.clone();
+			let points = user.deduct_points(penalty, T::PointsFloor::get());
 			user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-			Users::<T>::insert(game_info.player.clone(), user);
-			Self::deposit_event(Event::<T>::NoAnswer { game_id, points: 25 });
+			Users::<T>::insert(game_info.player.clone(), user.clone());
+			Self::update_leaderboards(&game_info.player, &before, &user);
+			Self::deposit_event(Event::<T>::NoAnswer { game_id, points });
 		}
 		Ok(())
 	}
 
-	pub fn end_game(winner: AccountIdOf<T>) -> DispatchResult {
+	/// Returns the block from which on the player can no longer answer a game.
+	pub fn answer_deadline(game_info: &GameData<T>) -> BlockNumberFor<T> {
+		game_info.start_block.saturating_add(T::GameDuration::get())
+	}
+
+	/// Returns the block in which a game expires.
+	///
+	/// An unanswered game expires at the answer deadline, an answered game has to be settled
+	/// within `SettlementDuration` blocks after it.
+	pub fn game_expiry(game_info: &GameData<T>) -> BlockNumberFor<T> {
+		let deadline = Self::answer_deadline(game_info);
+		if game_info.guess.is_some() {
+			deadline.saturating_add(T::SettlementDuration::get())
+		} else {
+			deadline
+		}
+	}
+
+	/// Closes a game that reached its expiry block.
+	///
+	/// An unanswered game is handled as not answered, an answered game is closed without a
+	/// result. The price of the property has not been revealed, so it can be used again.
+	pub fn expire_game(game_id: u32) -> DispatchResult {
+		let game_info = GameInfo::<T>::take(game_id).ok_or(Error::<T>::NoActiveGame)?;
+		Self::close_game(game_id, &game_info);
+		Self::release_property(game_info.property.id);
+		if game_info.guess.is_none() {
+			Self::no_answer_result(game_info, game_id)?;
+		} else {
+			Self::deposit_event(Event::<T>::SettlementExpired { game_id });
+		}
+		Ok(())
+	}
+
+	/// Removes a game from the expiry queue.
+	pub fn remove_game_expiry(game_id: u32, game_info: &GameData<T>) {
+		GamesExpiring::<T>::mutate_exists(Self::game_expiry(game_info), |games| {
+			if let Some(game_ids) = games {
+				game_ids.retain(|id| *id != game_id);
+				if game_ids.is_empty() {
+					*games = None;
+				}
+			}
+		});
+	}
+
+	/// Removes a finished game from the active games and the expiry queue. The caller decides
+	/// what happens to the property of the game.
+	pub fn close_game(game_id: u32, game_info: &GameData<T>) {
+		Self::remove_game_expiry(game_id, game_info);
+		ActiveGames::<T>::mutate(|games| games.retain(|id| *id != game_id));
+		PlayerGames::<T>::mutate_exists(game_info.player.clone(), |games| {
+			if let Some(game_ids) = games {
+				game_ids.retain(|id| *id != game_id);
+				if game_ids.is_empty() {
+					*games = None;
+				}
+			}
+		});
+	}
+
+	/// Creates the collections of a new round and starts the round.
+	///
+	/// The round is closed in `end_block` if nobody has collected four nfts of every color before.
+	pub fn do_start_round(end_block: Option<BlockNumberFor<T>>) -> DispatchResult {
+		let round =
+			CurrentRound::<T>::get().checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+		let mut collections = BoundedVec::<CollectionId<T>, ConstU32<8>>::new();
+		for x in 0..8 {
+			if pallet_nfts::NextCollectionId::<T>::get().is_none() {
+				pallet_nfts::NextCollectionId::<T>::set(
+					<T as pallet_nfts::Config>::CollectionId::initial_value(),
+				);
+			};
+			let collection_id = pallet_nfts::NextCollectionId::<T>::get().unwrap();
+			let next_collection_id = collection_id.increment();
+			pallet_nfts::NextCollectionId::<T>::set(next_collection_id);
+			let collection_id: CollectionId<T> = collection_id.into();
+			let pallet_id: AccountIdOf<T> =
+				<T as pallet::Config>::PalletId::get().into_account_truncating();
+			pallet_nfts::Pallet::<T>::do_create_collection(
+				collection_id.into(),
+				pallet_id.clone(),
+				pallet_id.clone(),
+				Self::default_collection_config(),
+				T::CollectionDeposit::get(),
+				pallet_nfts::Event::Created {
+					creator: pallet_id.clone(),
+					owner: pallet_id,
+					collection: collection_id.into(),
+				},
+			)?;
+			let color = NftColor::from_index(x).ok_or(Error::<T>::InvalidIndex)?;
+			CollectionColor::<T>::insert(collection_id, color);
+			collections.try_push(collection_id).map_err(|_| Error::<T>::InvalidIndex)?;
+		}
+		RoundCollections::<T>::insert(round, collections);
+		CurrentRound::<T>::put(round);
+		RoundActive::<T>::put(true);
+		Rounds::<T>::insert(
+			round,
+			RoundInfo {
+				start_block: <frame_system::Pallet<T>>::block_number(),
+				end_block,
+				closed_block: None,
+				champion: None,
+				games_played: 0,
+				nfts_minted: 0,
+				leaderboard: Default::default(),
+			},
+		);
+		Self::deposit_event(Event::<T>::RoundStarted { round, end_block });
+		Ok(())
+	}
+
+	/// Closes the current round, archives its champion and the final leaderboard and awards the
+	/// prizes to the places of the leaderboard.
+	pub fn do_end_round(champion: Option<AccountIdOf<T>>) {
+		let round = Self::current_round();
 		RoundActive::<T>::put(false);
-		RoundChampion::<T>::insert(Self::current_round(), winner);
+		if let Some(champion) = &champion {
+			RoundChampion::<T>::insert(round, champion);
+			PlayerRounds::<T>::insert(champion, round, ());
+		}
+		let leaderboard = RoundLeaderboard::<T>::take();
+		RoundRunnersUp::<T>::kill();
+		Rounds::<T>::mutate(round, |info| {
+			if let Some(info) = info {
+				info.closed_block = Some(<frame_system::Pallet<T>>::block_number());
+				info.champion = champion.clone();
+				info.leaderboard = leaderboard.clone();
+			}
+		});
+		Self::deposit_event(Event::<T>::RoundEnded { round, champion });
+		Self::award_prizes(round, &leaderboard);
+	}
+
+	pub fn end_game(winner: AccountIdOf<T>) -> DispatchResult {
+		Self::do_end_round(Some(winner));
 		Ok(())
 	}
 
@@ -15,24 +15,40 @@ mod tests;
 mod benchmarking;
 pub mod weights;
 pub use weights::*;
+pub mod api;
+pub mod auction;
+pub mod escrow;
+pub mod expiry;
+pub mod faucet;
 pub mod functions;
+pub mod migrations;
+pub mod moderation;
+pub mod offchain;
+pub mod orders;
+pub mod prizes;
 pub mod properties;
+pub mod randomness;
+pub mod registration;
+pub mod roles;
 pub mod types;
 
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 type BalanceOf<T> = <<T as pallet_nfts::Config>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::Balance;
+type CurrencyBalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+type LeaderboardOf<T> = frame_support::BoundedVec<
+	(AccountIdOf<T>, Score<frame_system::pallet_prelude::BlockNumberFor<T>>),
+	<T as Config>::LeaderboardLimit,
+>;
 
 use frame_support::{
-	traits::{Currency, Incrementable, ReservableCurrency},
+	traits::{Currency, ExistenceRequirement, Incrementable, ReservableCurrency},
 	PalletId,
 };
 
-use frame_support::sp_runtime::{
-	traits::{AccountIdConversion, StaticLookup},
-	Saturating,
-};
+use frame_support::sp_runtime::{traits::AccountIdConversion, Permill, Saturating};
 
 use pallet_nfts::{
 	CollectionConfig, CollectionSetting, CollectionSettings, ItemConfig, ItemSettings, MintSettings,
@@ -44,20 +60,57 @@ use enumflags2::BitFlags;
 
 use frame_support::traits::Randomness;
 
+use frame_system::offchain::{AppCrypto, CreateSignedTransaction};
+
+use sp_core::crypto::KeyTypeId;
+
 pub use types::*;
 
+/// The key type of the oracle keys that are used by the offchain worker to settle games.
+///
+/// The key can be inserted into the keystore of a node with the `author_insertKey` rpc and the
+/// key type `game`.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"game");
+
+/// The log target of this pallet.
+pub const LOG_TARGET: &str = "runtime::game";
+
+/// Crypto of the oracle keys which sign the settlement transactions of the offchain worker.
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		MultiSignature, MultiSigner,
+	};
+	app_crypto!(sr25519, KEY_TYPE);
+
+	/// The oracle key that is used to sign the transactions of the offchain worker.
+	pub struct OracleAuthId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for OracleAuthId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
-	pub trait Config: frame_system::Config + pallet_nfts::Config //+ pallet_babe::Config
+	pub trait Config:
+		CreateSignedTransaction<Call<Self>> + frame_system::Config + pallet_nfts::Config
 	{
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -92,7 +145,11 @@ pub mod pallet {
 		/// The maximum amount of games that can be played at the same time.
 		#[pallet::constant]
 		type MaxOngoingGames: Get<u32>;
-		/// Randomness used for choosing a random property.
+		/// The maximum amount of games that a single player can play at the same time.
+		#[pallet::constant]
+		type MaxOngoingGamesPerPlayer: Get<u32>;
+		/// Randomness used for choosing a random property and the color of a nft.
+		/// `randomness::VrfRandomness` can be used to back it with a VRF output.
 		type GameRandomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
 		/// The maximum length of data stored in string.
 		#[pallet::constant]
@@ -100,10 +157,78 @@ pub mod pallet {
 		/// The maximum length of leaderboard.
 		#[pallet::constant]
 		type LeaderboardLimit: Get<u32>;
+		/// The maximum amount of accounts that can have the same role.
 		#[pallet::constant]
-		type MaxAdmins: Get<u32>;
+		type MaxRoleMembers: Get<u32>;
 		/// The amount of time until player can request more token.
 		type RequestLimit: Get<BlockNumberFor<Self>>;
+		/// The amount of blocks a player has to submit an answer.
+		#[pallet::constant]
+		type GameDuration: Get<BlockNumberFor<Self>>;
+		/// The amount of blocks after the answer deadline in which an answered game has to be
+		/// settled.
+		#[pallet::constant]
+		type SettlementDuration: Get<BlockNumberFor<Self>>;
+		/// The identifier type for the oracle key of the offchain worker.
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+		/// The maximum amount of accuracy bands in a scoring table.
+		#[pallet::constant]
+		type MaxScoreBands: Get<u32>;
+		/// The amount of points that a penalty can not push a player below.
+		#[pallet::constant]
+		type PointsFloor: Get<u32>;
+		/// The maximum amount of properties that can be added with a single call.
+		#[pallet::constant]
+		type MaxPropertiesPerBatch: Get<u32>;
+		/// The maximum amount of offers that can be made for a single listing.
+		#[pallet::constant]
+		type MaxOffersPerListing: Get<u32>;
+		/// The share of the price of a sold nft that goes to the pallet account.
+		#[pallet::constant]
+		type MarketplaceFee: Get<Permill>;
+		/// The amount of blocks an auction runs.
+		#[pallet::constant]
+		type AuctionDuration: Get<BlockNumberFor<Self>>;
+		/// A bid in the last blocks of an auction extends the auction to this amount of blocks
+		/// after the bid.
+		#[pallet::constant]
+		type AuctionExtension: Get<BlockNumberFor<Self>>;
+		/// The maximum amount of auctions that can end in the same block.
+		#[pallet::constant]
+		type MaxAuctionsPerBlock: Get<u32>;
+		/// The maximum amount of listings and the maximum amount of offers that can expire in
+		/// the same block.
+		#[pallet::constant]
+		type MaxExpiriesPerBlock: Get<u32>;
+		/// The maximum amount of standing orders that can wait for the same pair of colors.
+		#[pallet::constant]
+		type MaxStandingOrdersPerPair: Get<u32>;
+		/// The fee that a player pays into the prize pool for every game that is not a practise
+		/// game.
+		#[pallet::constant]
+		type EntryFee: Get<CurrencyBalanceOf<Self>>;
+		/// The deposit that is reserved from a player that registers itself without an invite
+		/// code.
+		#[pallet::constant]
+		type RegistrationDeposit: Get<CurrencyBalanceOf<Self>>;
+		/// The maximum amount of invite codes that can be created with a single call.
+		#[pallet::constant]
+		type MaxInviteCodesPerCall: Get<u32>;
+		/// The amount of token that the faucet drips to a player at most.
+		#[pallet::constant]
+		type FaucetDripAmount: Get<CurrencyBalanceOf<Self>>;
+		/// The free balance that the faucet tops the balance of a player up to.
+		#[pallet::constant]
+		type FaucetTarget: Get<CurrencyBalanceOf<Self>>;
+		/// The amount of token that the faucet drips at most within a faucet period.
+		#[pallet::constant]
+		type FaucetCap: Get<CurrencyBalanceOf<Self>>;
+		/// The amount of blocks of a faucet period, usually a day.
+		#[pallet::constant]
+		type FaucetCapPeriod: Get<BlockNumberFor<Self>>;
+		/// The maximum amount of suspensions that can end in the same block.
+		#[pallet::constant]
+		type MaxSuspensionsPerBlock: Get<u32>;
 	}
 
 	pub type CollectionId<T> = <T as Config>::CollectionId;
@@ -125,6 +250,17 @@ pub mod pallet {
 	pub(super) type RoundChampion<T: Config> =
 		StorageMap<_, Blake2_128Concat, u32, AccountIdOf<T>, OptionQuery>;
 
+	/// Mapping of a round to its summary.
+	#[pallet::storage]
+	#[pallet::getter(fn rounds)]
+	pub type Rounds<T: Config> = StorageMap<_, Blake2_128Concat, u32, RoundInfo<T>, OptionQuery>;
+
+	/// The start block and the optional end block of the next round if it has been scheduled.
+	#[pallet::storage]
+	#[pallet::getter(fn scheduled_round)]
+	pub type ScheduledRound<T: Config> =
+		StorageValue<_, (BlockNumberFor<T>, Option<BlockNumberFor<T>>), OptionQuery>;
+
 	/// The next item id in a collection.
 	#[pallet::storage]
 	pub(super) type NextColorId<T: Config> =
@@ -136,6 +272,12 @@ pub mod pallet {
 	pub(super) type CollectionColor<T: Config> =
 		StorageMap<_, Blake2_128Concat, <T as pallet::Config>::CollectionId, NftColor, OptionQuery>;
 
+	/// Mapping of a round to its collections, ordered by the index of their color.
+	#[pallet::storage]
+	#[pallet::getter(fn round_collections)]
+	pub type RoundCollections<T: Config> =
+		StorageMap<_, Blake2_128Concat, u32, BoundedVec<CollectionId<T>, ConstU32<8>>, ValueQuery>;
+
 	/// The next id of listings.
 	#[pallet::storage]
 	pub(super) type NextListingId<T> = StorageValue<_, u32, ValueQuery>;
@@ -150,15 +292,52 @@ pub mod pallet {
 	#[pallet::getter(fn game_id)]
 	pub type GameId<T> = StorageValue<_, u32, ValueQuery>;
 
-	/// The leaderboard of the game.
+	/// The all-time leaderboard of the game.
 	#[pallet::storage]
 	#[pallet::getter(fn leaderboard)]
-	pub type Leaderboard<T> = StorageValue<
+	pub type Leaderboard<T> = StorageValue<_, LeaderboardOf<T>, ValueQuery>;
+
+	/// The leaderboard of the current round.
+	#[pallet::storage]
+	#[pallet::getter(fn round_leaderboard)]
+	pub type RoundLeaderboard<T> = StorageValue<_, LeaderboardOf<T>, ValueQuery>;
+
+	/// The accounts that rank right below the all-time leaderboard.
+	#[pallet::storage]
+	#[pallet::getter(fn leaderboard_runners_up)]
+	pub type LeaderboardRunnersUp<T> = StorageValue<_, LeaderboardOf<T>, ValueQuery>;
+
+	/// The accounts that rank right below the leaderboard of the current round.
+	#[pallet::storage]
+	#[pallet::getter(fn round_runners_up)]
+	pub type RoundRunnersUp<T> = StorageValue<_, LeaderboardOf<T>, ValueQuery>;
+
+	/// Mapping of an account to its all-time score.
+	#[pallet::storage]
+	#[pallet::getter(fn scores)]
+	pub type Scores<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, Score<BlockNumberFor<T>>, ValueQuery>;
+
+	/// Mapping of a round and an account to the score the account reached in the round.
+	#[pallet::storage]
+	#[pallet::getter(fn round_scores)]
+	pub type RoundScores<T: Config> = StorageDoubleMap<
 		_,
-		BoundedVec<(AccountIdOf<T>, u32), <T as Config>::LeaderboardLimit>,
+		Twox64Concat,
+		u32,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Score<BlockNumberFor<T>>,
 		ValueQuery,
 	>;
 
+	/// Mapping of a player and a round to whether the player scored in the round or became its
+	/// champion, so that the rounds of a removed player can be cleaned up.
+	#[pallet::storage]
+	#[pallet::getter(fn player_rounds)]
+	pub type PlayerRounds<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, AccountIdOf<T>, Twox64Concat, u32, (), OptionQuery>;
+
 	/// Mapping of an account id to the user data of the account.
 	#[pallet::storage]
 	#[pallet::getter(fn users)]
@@ -191,17 +370,311 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
-	/// A List of test properties
+	/// Mapping of a listing id to the ids of the offers that have been made for the listing.
+	#[pallet::storage]
+	#[pallet::getter(fn listing_offers)]
+	pub type ListingOffers<T: Config> =
+		StorageMap<_, Blake2_128Concat, u32, BoundedVec<u32, T::MaxOffersPerListing>, ValueQuery>;
+
+	/// The next id of auctions.
+	#[pallet::storage]
+	#[pallet::getter(fn next_auction_id)]
+	pub(super) type NextAuctionId<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// Mapping of auction id to the auction data.
+	#[pallet::storage]
+	#[pallet::getter(fn auctions)]
+	pub type Auctions<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		AuctionInfo<<T as pallet::Config>::CollectionId, <T as pallet::Config>::ItemId, T>,
+		OptionQuery,
+	>;
+
+	/// Mapping of a block number to the auctions that end in this block.
+	#[pallet::storage]
+	#[pallet::getter(fn auctions_ending)]
+	pub type AuctionsEnding<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<u32, T::MaxAuctionsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Mapping of a block number to the listings that expire in this block.
+	#[pallet::storage]
+	#[pallet::getter(fn listings_expiring)]
+	pub type ListingsExpiring<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<u32, T::MaxExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Mapping of a block number to the offers that expire in this block.
+	#[pallet::storage]
+	#[pallet::getter(fn offers_expiring)]
+	pub type OffersExpiring<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<u32, T::MaxExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// The first block whose expired listings and offers have not been returned yet.
+	#[pallet::storage]
+	#[pallet::getter(fn expiry_sweep_block)]
+	pub type ExpirySweepBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// The next id of standing orders.
+	#[pallet::storage]
+	#[pallet::getter(fn next_standing_order_id)]
+	pub(super) type NextStandingOrderId<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// Mapping of standing order id to the standing order data.
+	#[pallet::storage]
+	#[pallet::getter(fn standing_orders)]
+	pub type StandingOrders<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		StandingOrderInfo<<T as pallet::Config>::CollectionId, <T as pallet::Config>::ItemId, T>,
+		OptionQuery,
+	>;
+
+	/// Mapping of the offered and the wanted color to the ids of the standing orders, oldest
+	/// first.
+	#[pallet::storage]
+	#[pallet::getter(fn standing_orders_by_color)]
+	pub type StandingOrdersByColor<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NftColor,
+		Blake2_128Concat,
+		NftColor,
+		BoundedVec<u32, T::MaxStandingOrdersPerPair>,
+		ValueQuery,
+	>;
+
+	/// Mapping of an escrowed nft to the account that put it into the escrow and the points
+	/// that were deducted for it.
+	#[pallet::storage]
+	#[pallet::getter(fn escrow_depositors)]
+	pub type EscrowDepositors<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId<T>,
+		Blake2_128Concat,
+		ItemId<T>,
+		EscrowDeposit<T>,
+		OptionQuery,
+	>;
+
+	/// Mapping of an account to the amount of its nfts that are in the escrow.
+	#[pallet::storage]
+	#[pallet::getter(fn escrowed_nft_count)]
+	pub type EscrowedNftCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u32, ValueQuery>;
+
+	/// Mapping of an account to the amount of auctions in which it has the highest bid.
+	#[pallet::storage]
+	#[pallet::getter(fn highest_bid_count)]
+	pub type HighestBidCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u32, ValueQuery>;
+
+	/// Mapping of a property id to the property and its status.
 	#[pallet::storage]
 	#[pallet::getter(fn game_properties)]
 	pub type GameProperties<T: Config> =
-		StorageValue<_, BoundedVec<PropertyInfoData<T>, T::MaxProperty>, ValueQuery>;
+		StorageMap<_, Blake2_128Concat, u32, PropertyRecord<T>, OptionQuery>;
+
+	/// Mapping of a slot of the availability index to the id of an available property.
+	#[pallet::storage]
+	#[pallet::getter(fn available_properties)]
+	pub type AvailableProperties<T: Config> =
+		StorageMap<_, Blake2_128Concat, u32, u32, OptionQuery>;
+
+	/// Mapping of the id of an available property to its slot in the availability index.
+	#[pallet::storage]
+	pub type AvailablePropertySlot<T: Config> =
+		StorageMap<_, Blake2_128Concat, u32, u32, OptionQuery>;
+
+	/// The amount of properties that are available for new games.
+	#[pallet::storage]
+	#[pallet::getter(fn available_property_count)]
+	pub type AvailablePropertyCount<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// Vector of all games that are currently ongoing.
+	#[pallet::storage]
+	#[pallet::getter(fn active_games)]
+	pub type ActiveGames<T: Config> =
+		StorageValue<_, BoundedVec<u32, T::MaxOngoingGames>, ValueQuery>;
+
+	/// Mapping of an account id to the games that the player currently has ongoing.
+	#[pallet::storage]
+	#[pallet::getter(fn player_games)]
+	pub type PlayerGames<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		BoundedVec<u32, T::MaxOngoingGamesPerPlayer>,
+		ValueQuery,
+	>;
+
+	/// Mapping of a block number to the games that expire in this block.
+	#[pallet::storage]
+	#[pallet::getter(fn games_expiring)]
+	pub type GamesExpiring<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<u32, T::MaxOngoingGames>,
+		ValueQuery,
+	>;
+
+	/// Mapping of a role to the accounts that have been granted the role.
+	#[pallet::storage]
+	#[pallet::getter(fn role_members)]
+	pub type RoleMembers<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		Role,
+		BoundedVec<AccountIdOf<T>, T::MaxRoleMembers>,
+		ValueQuery,
+	>;
+
+	/// Mapping of a difficulty level to its scoring table. Levels without an entry use the
+	/// default table of `ScoringConfig::default_for`.
+	#[pallet::storage]
+	pub type ScoringConfigs<T: Config> =
+		StorageMap<_, Blake2_128Concat, DifficultyLevel, ScoringConfig<T>, OptionQuery>;
+
+	/// The funds of the pallet account that are paid out as prizes when the next round closes.
+	#[pallet::storage]
+	#[pallet::getter(fn prize_pool)]
+	pub type PrizePool<T: Config> = StorageValue<_, CurrencyBalanceOf<T>, ValueQuery>;
+
+	/// The shares of the prize pool that the places of the round leaderboard receive, starting
+	/// with the first place.
+	#[pallet::storage]
+	#[pallet::getter(fn prize_distribution)]
+	pub type PrizeDistribution<T: Config> =
+		StorageValue<_, BoundedVec<Permill, T::LeaderboardLimit>, ValueQuery>;
+
+	/// Mapping of a round and an account to the prize the account has not claimed yet.
+	#[pallet::storage]
+	#[pallet::getter(fn round_prizes)]
+	pub type RoundPrizes<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		u32,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		CurrencyBalanceOf<T>,
+		OptionQuery,
+	>;
+
+	/// The total of the prizes that have been awarded and not claimed yet.
+	#[pallet::storage]
+	#[pallet::getter(fn unclaimed_prizes)]
+	pub type UnclaimedPrizes<T: Config> = StorageValue<_, CurrencyBalanceOf<T>, ValueQuery>;
+
+	/// Mapping of the hash of an invite code to whether the code has not been redeemed yet.
+	#[pallet::storage]
+	#[pallet::getter(fn invite_codes)]
+	pub type InviteCodes<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, (), OptionQuery>;
+
+	/// Mapping of a player to the deposit that has been reserved for its registration.
+	#[pallet::storage]
+	#[pallet::getter(fn registration_deposits)]
+	pub type RegistrationDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, CurrencyBalanceOf<T>, OptionQuery>;
+
+	/// The first block of the current faucet period and the amount that the faucet dripped in it.
+	#[pallet::storage]
+	#[pallet::getter(fn faucet_usage)]
+	pub type FaucetUsage<T: Config> =
+		StorageValue<_, (BlockNumberFor<T>, CurrencyBalanceOf<T>), ValueQuery>;
+
+	/// Mapping of a block to the players whose suspension ends in that block.
+	#[pallet::storage]
+	#[pallet::getter(fn suspensions_ending)]
+	pub type SuspensionsEnding<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<AccountIdOf<T>, T::MaxSuspensionsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Mapping of a suspended or banned player to the hash of its open appeal.
+	#[pallet::storage]
+	#[pallet::getter(fn appeals)]
+	pub type Appeals<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, T::Hash, OptionQuery>;
+
+	/// Mapping of a banned account to whether it is banned. The ban is kept when the player is
+	/// removed, so that the account can not register again.
+	#[pallet::storage]
+	#[pallet::getter(fn banned_accounts)]
+	pub type BannedAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, (), OptionQuery>;
 
-	/// Vector of admins who can register users.
+	/// Mapping of a removed player to the block in which its suspension ends. The account can
+	/// not register again until then.
 	#[pallet::storage]
-	#[pallet::getter(fn admins)]
-	pub type Admins<T: Config> =
-		StorageValue<_, BoundedVec<AccountIdOf<T>, T::MaxAdmins>, ValueQuery>;
+	#[pallet::getter(fn suspended_accounts)]
+	pub type SuspendedAccounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The roles that are granted to accounts from genesis on.
+		pub roles: sp_std::vec::Vec<(Role, AccountIdOf<T>)>,
+		/// The properties that are available for games from genesis on.
+		pub properties: sp_std::vec::Vec<PropertyInfoData<T>>,
+		/// The registered players together with their points.
+		pub players: sp_std::vec::Vec<(AccountIdOf<T>, u32)>,
+		/// Whether the first round is started and its collections are created at genesis.
+		pub start_round: bool,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (role, account) in &self.roles {
+				Pallet::<T>::do_grant_role(role.clone(), account.clone())
+					.expect("genesis roles must be unique and within the limit");
+			}
+			for property in &self.properties {
+				Pallet::<T>::do_add_property(property.clone())
+					.expect("genesis properties must have unique ids");
+			}
+			for (player, points) in &self.players {
+				assert!(!Users::<T>::contains_key(player), "genesis players must be unique");
+				let user = User {
+					points: *points,
+					wins: Default::default(),
+					losses: Default::default(),
+					practise_rounds: Default::default(),
+					last_played_round: Default::default(),
+					next_token_request: <T as Config>::RequestLimit::get(),
+					nfts: CollectedColors::default(),
+					moderation: ModerationState::Active,
+				};
+				Users::<T>::insert(player, user);
+				frame_system::Pallet::<T>::inc_providers(player);
+			}
+			if self.start_round {
+				Pallet::<T>::do_start_round(None).expect("genesis round can be started");
+			}
+		}
+	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -212,12 +685,20 @@ pub mod pallet {
 		GameStarted { player: AccountIdOf<T>, game_id: u32 },
 		/// An answer has been submitted.
 		AnswerSubmitted { player: AccountIdOf<T>, game_id: u32, guess: u32 },
-		/// The result has been checked.
+		/// The result has been checked. For a lost game `points` are the points that have actually
+		/// been deducted.
 		ResultChecked { game_id: u32, secret: BoundedVec<u8, <T as Config>::StringLimit>, points: u32, won: bool, nft_received: bool },
-		/// No Answer has been submitted.
+		/// No Answer has been submitted. `points` are the points that have actually been deducted.
 		NoAnswer { game_id: u32, points: u32 },
+		/// An answered game was not settled in time and has been closed without a result.
+		SettlementExpired { game_id: u32 },
 		/// A nft has been listed.
-		NftListed { owner: AccountIdOf<T>, collection_id: CollectionId<T>, item_id: ItemId<T> },
+		NftListed {
+			owner: AccountIdOf<T>,
+			collection_id: CollectionId<T>,
+			item_id: ItemId<T>,
+			price: Option<CurrencyBalanceOf<T>>,
+		},
 		/// A nft has been delisted.
 		NftDelisted { owner: AccountIdOf<T>, collection_id: CollectionId<T>, item_id: ItemId<T> },
 		/// An offer has been made.
@@ -231,14 +712,124 @@ pub mod pallet {
 		OfferWithdrawn { owner: AccountIdOf<T>, offer_id: u32 },
 		/// An offer has been handled.
 		OfferHandeld { offer_id: u32, offer: Offer },
+		/// A listed nft has been sold for its price.
+		NftSold {
+			listing_id: u32,
+			seller: AccountIdOf<T>,
+			buyer: AccountIdOf<T>,
+			price: CurrencyBalanceOf<T>,
+			fee: CurrencyBalanceOf<T>,
+		},
+		/// The nft of an offer has been returned because the listing has been closed.
+		OfferRefunded { owner: AccountIdOf<T>, offer_id: u32 },
 		/// A new player has been registered.
 		NewPlayerRegistered { player: AccountIdOf<T> },
-		/// A new admins has been added.
-		NewAdminAdded { new_admin: AccountIdOf<T> },
-		/// An admin has been removed.
-		AdminRemoved { admin: AccountIdOf<T> },
+		/// A role has been granted to an account.
+		RoleGranted { role: Role, account: AccountIdOf<T> },
+		/// A role has been revoked from an account.
+		RoleRevoked { role: Role, account: AccountIdOf<T> },
 		/// The user received token.
 		TokenReceived { player: AccountIdOf<T> },
+		/// The faucet dripped token to a player.
+		FaucetDrip { player: AccountIdOf<T>, amount: CurrencyBalanceOf<T> },
+		/// A player has been suspended until a block.
+		PlayerSuspended { player: AccountIdOf<T>, until: BlockNumberFor<T>, reason: T::Hash },
+		/// A player has been banned.
+		PlayerBanned { player: AccountIdOf<T>, reason: T::Hash },
+		/// A suspended or banned player has been reinstated by a moderator.
+		PlayerReinstated { player: AccountIdOf<T>, reason: T::Hash },
+		/// The suspension of a player has ended.
+		SuspensionEnded { player: AccountIdOf<T> },
+		/// A suspended or banned player has filed an appeal.
+		AppealFiled { player: AccountIdOf<T>, statement: T::Hash },
+		/// The appeal of a player has been rejected.
+		AppealRejected { player: AccountIdOf<T>, reason: T::Hash },
+		/// A new property has been added.
+		PropertyAdded { property_id: u32 },
+		/// A property has been retired.
+		PropertyRetired { property_id: u32 },
+		/// The scoring table of a difficulty level has been updated.
+		ScoringConfigUpdated { difficulty: DifficultyLevel, config: ScoringConfig<T> },
+		/// A new round has started.
+		RoundStarted { round: u32, end_block: Option<BlockNumberFor<T>> },
+		/// The next round has been scheduled.
+		RoundScheduled { start_block: BlockNumberFor<T>, end_block: Option<BlockNumberFor<T>> },
+		/// A round has ended.
+		RoundEnded { round: u32, champion: Option<AccountIdOf<T>> },
+		/// A nft has been put up for auction.
+		AuctionCreated {
+			auction_id: u32,
+			owner: AccountIdOf<T>,
+			collection_id: CollectionId<T>,
+			item_id: ItemId<T>,
+			min_bid: CurrencyBalanceOf<T>,
+			end_block: BlockNumberFor<T>,
+		},
+		/// A bid has been placed on an auction.
+		BidPlaced {
+			auction_id: u32,
+			bidder: AccountIdOf<T>,
+			amount: CurrencyBalanceOf<T>,
+			end_block: BlockNumberFor<T>,
+		},
+		/// An auction has been settled. Without a winner the nft went back to the owner.
+		AuctionSettled {
+			auction_id: u32,
+			owner: AccountIdOf<T>,
+			winner: Option<AccountIdOf<T>>,
+			amount: Option<CurrencyBalanceOf<T>>,
+		},
+		/// A listing has expired and the nft has been returned to the owner.
+		ListingExpired { listing_id: u32, owner: AccountIdOf<T> },
+		/// An offer has expired and the nft has been returned to the owner.
+		OfferExpired { offer_id: u32, owner: AccountIdOf<T> },
+		/// A standing order has been placed.
+		StandingOrderPlaced {
+			order_id: u32,
+			owner: AccountIdOf<T>,
+			collection_id: CollectionId<T>,
+			item_id: ItemId<T>,
+			give: NftColor,
+			want: NftColor,
+		},
+		/// A standing order has been matched with a new standing order or a new listing.
+		StandingOrderMatched {
+			order_id: u32,
+			owner: AccountIdOf<T>,
+			counterparty: AccountIdOf<T>,
+			listing_id: Option<u32>,
+		},
+		/// A standing order has been cancelled.
+		StandingOrderCancelled { order_id: u32, owner: AccountIdOf<T> },
+		/// Funds have been added to the prize pool.
+		PrizePoolFunded { who: Option<AccountIdOf<T>>, amount: CurrencyBalanceOf<T> },
+		/// The distribution of the prize pool has been updated.
+		PrizeDistributionUpdated { shares: BoundedVec<Permill, T::LeaderboardLimit> },
+		/// A player has been awarded a prize for the place on the leaderboard of a round.
+		PrizeAwarded { round: u32, player: AccountIdOf<T>, amount: CurrencyBalanceOf<T> },
+		/// A player has claimed a prize.
+		PrizeClaimed { round: u32, player: AccountIdOf<T>, amount: CurrencyBalanceOf<T> },
+		/// New invite codes have been created.
+		InviteCodesCreated { amount: u32 },
+		/// An invite code has been redeemed by a player.
+		InviteCodeRedeemed { player: AccountIdOf<T>, code_hash: T::Hash },
+		/// A player has been unregistered and the registration deposit has been returned.
+		PlayerUnregistered { player: AccountIdOf<T>, deposit: CurrencyBalanceOf<T> },
+		/// A player has been deregistered. The data of the player has been removed, the game nfts
+		/// of the player have been burned and the registration deposit has been returned.
+		PlayerDeregistered {
+			player: AccountIdOf<T>,
+			deposit: CurrencyBalanceOf<T>,
+			burned_nfts: u32,
+		},
+		/// The reserved funds of the highest bidder did not cover the bid when the auction ended.
+		/// The nft went back to the owner and what was left of the bid has been unreserved.
+		AuctionFailed {
+			auction_id: u32,
+			owner: AccountIdOf<T>,
+			bidder: AccountIdOf<T>,
+			amount: CurrencyBalanceOf<T>,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -279,65 +870,211 @@ pub mod pallet {
 		NoActiveRound,
 		/// The player is already registered.
 		PlayerAlreadyRegistered,
-		/// The account is already an admin.
-		AccountAlreadyAdmin,
-		/// This account is not an admin.
-		NotAdmin,
-		/// There are already enough admins.
-		TooManyAdmins,
+		/// The account has already been granted the role.
+		RoleAlreadyGranted,
+		/// The account has not been granted the role.
+		RoleNotGranted,
+		/// There are already enough accounts with the role.
+		TooManyRoleMembers,
 		/// The user has to wait to request token.
 		CantRequestToken,
 		/// There has been no guess from the player.
 		NoGuess,
+		/// The revealed price and secret do not match the commitment of the property.
+		CommitmentMismatch,
+		/// The player has too many games ongoing.
+		TooManyPlayerGames,
+		/// The nft threshold and the bands of the scoring table are not sorted ascending or a
+		/// practice table has an nft threshold or entry points.
+		InvalidScoringConfig,
+		/// A property with this id already exists.
+		PropertyAlreadyExists,
+		/// The property has already been retired or its price has been revealed.
+		PropertyAlreadyRetired,
+		/// There is already an active round.
+		RoundAlreadyActive,
+		/// The next round has already been scheduled.
+		RoundAlreadyScheduled,
+		/// The end block of a round has to be after its start block.
+		InvalidRoundSchedule,
+		/// There are already too many offers for this listing.
+		TooManyOffers,
+		/// The listing has no price.
+		ListingNotForSale,
+		/// The auction does not exist.
+		AuctionDoesNotExist,
+		/// The auction has already ended.
+		AuctionEnded,
+		/// The auction has not ended yet.
+		AuctionNotEnded,
+		/// The bid is lower than the minimum bid or the highest bid.
+		BidTooLow,
+		/// Too many auctions end in the same block.
+		TooManyAuctions,
+		/// The expiry block is not in the future.
+		InvalidExpiry,
+		/// Too many listings or offers expire in the same block.
+		TooManyExpiries,
+		/// The listing has expired.
+		ListingHasExpired,
+		/// The offer has expired.
+		OfferHasExpired,
+		/// The standing order does not exist.
+		StandingOrderDoesNotExist,
+		/// The wanted color is the color of the offered nft.
+		SameColor,
+		/// Too many standing orders wait for the same pair of colors.
+		TooManyStandingOrders,
+		/// The shares of the prize distribution add up to more than the prize pool.
+		InvalidPrizeDistribution,
+		/// The pallet account does not have enough free funds.
+		InsufficientPalletFunds,
+		/// There is no prize to claim.
+		NoPrize,
+		/// The invite code does not exist or has already been redeemed.
+		InvalidInviteCode,
+		/// The invite code has already been created.
+		InviteCodeAlreadyExists,
+		/// The player still has ongoing games.
+		PlayerHasOngoingGames,
+		/// The player still has nfts on the marketplace.
+		PlayerHasOpenMarketItems,
+		/// The player owns more game nfts than given.
+		TooManyNfts,
+		/// The free balance of the player is already at the faucet target.
+		FaucetTargetReached,
+		/// The faucet has dripped its cap for the current faucet period.
+		FaucetCapReached,
+		/// The pallet account has no funds left for the faucet.
+		FaucetDepleted,
+		/// The player is suspended.
+		PlayerIsSuspended,
+		/// The player is banned.
+		PlayerIsBanned,
+		/// The player is neither suspended nor banned.
+		PlayerNotRestricted,
+		/// A suspension has to end after the current block.
+		InvalidSuspension,
+		/// There are already too many suspensions that end in this block.
+		TooManySuspensions,
+		/// The player has already filed an appeal.
+		AppealAlreadyFiled,
+		/// The player has not filed an appeal.
+		NoAppeal,
+		/// The player has the highest bid in an auction.
+		PlayerHasOpenBids,
+		/// The player has already answered the game.
+		AnswerAlreadySubmitted,
+		/// The player can still answer the game.
+		AnswerWindowOpen,
+		/// The player has scored in more rounds than given.
+		TooManyRounds,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let mut weight = T::DbWeight::get().reads_writes(1, 1);
+			let expiring_games = GamesExpiring::<T>::take(n);
+			for game_id in expiring_games {
+				let result =
+					frame_support::storage::with_storage_layer(|| Self::expire_game(game_id));
+				if let Err(error) = result {
+					log::warn!(
+						target: LOG_TARGET,
+						"Game {} could not be expired: {:?}",
+						game_id,
+						error
+					);
+				}
+				weight = weight.saturating_add(T::DbWeight::get().reads_writes(4, 4));
+			}
+			weight = weight.saturating_add(T::DbWeight::get().reads(3));
+			if Self::round_active() {
+				let round_ends = Rounds::<T>::get(Self::current_round())
+					.and_then(|round| round.end_block)
+					.map_or(false, |end_block| end_block <= n);
+				if round_ends {
+					Self::do_end_round(None);
+					weight = weight.saturating_add(<T as pallet::Config>::WeightInfo::end_round());
+				}
+			}
+			if let Some((start_block, end_block)) = ScheduledRound::<T>::get() {
+				if start_block <= n && !Self::round_active() {
+					ScheduledRound::<T>::kill();
+					let result = frame_support::storage::with_storage_layer(|| {
+						Self::do_start_round(end_block)
+					});
+					if let Err(error) = result {
+						log::warn!(target: LOG_TARGET, "Scheduled round did not start: {:?}", error);
+					}
+					weight =
+						weight.saturating_add(<T as pallet::Config>::WeightInfo::start_round());
+				}
+			}
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			for auction_id in AuctionsEnding::<T>::take(n) {
+				let result = frame_support::storage::with_storage_layer(|| {
+					Self::do_settle_auction(auction_id)
+				});
+				if let Err(error) = result {
+					log::warn!(
+						target: LOG_TARGET,
+						"Auction {} could not be settled: {:?}",
+						auction_id,
+						error
+					);
+				}
+				weight = weight.saturating_add(<T as pallet::Config>::WeightInfo::settle_auction());
+			}
+			weight.saturating_add(Self::end_suspensions(n))
+		}
+
+		fn integrity_test() {
+			assert!(
+				T::MaxScoreBands::get() >= 7,
+				"MaxScoreBands must fit the 7 bands of the default scoring tables"
+			);
+		}
+
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::sweep_expired(n, remaining_weight)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
+
+		fn offchain_worker(block_number: BlockNumberFor<T>) {
+			if let Err(error) = Self::settle_games_offchain(block_number) {
+				log::debug!(target: LOG_TARGET, "Offchain worker did not settle games: {}", error);
+			}
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Creates the setup for a new game.
+		/// Creates the setup for a new game by starting a round. The properties of the games are
+		/// added in the genesis config or with `add_property` and `add_properties`, so that the
+		/// salts of their commitments never appear on chain.
 		///
-		/// The origin must be the sudo.
+		/// The origin must be the sudo or Signed by an account with the `RoundManager` role.
+		///
+		/// Like `start_round`, this fails while a round is active or scheduled.
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::setup_game())]
 		pub fn setup_game(origin: OriginFor<T>) -> DispatchResult {
-			T::GameOrigin::ensure_origin(origin)?;
-			for x in 0..8 {
-				if pallet_nfts::NextCollectionId::<T>::get().is_none() {
-					pallet_nfts::NextCollectionId::<T>::set(
-						<T as pallet_nfts::Config>::CollectionId::initial_value(),
-					);
-				};
-				let collection_id = pallet_nfts::NextCollectionId::<T>::get().unwrap();
-				let next_collection_id = collection_id.increment();
-				pallet_nfts::NextCollectionId::<T>::set(next_collection_id);
-				let collection_id: CollectionId<T> = collection_id.into();
-				let pallet_id: AccountIdOf<T> =
-					<T as pallet::Config>::PalletId::get().into_account_truncating();
-				pallet_nfts::Pallet::<T>::do_create_collection(
-					collection_id.into(),
-					pallet_id.clone(),
-					pallet_id.clone(),
-					Self::default_collection_config(),
-					T::CollectionDeposit::get(),
-					pallet_nfts::Event::Created {
-						creator: pallet_id.clone(),
-						owner: pallet_id,
-						collection: collection_id.into(),
-					},
-				)?;
-				let color = NftColor::from_index(x).ok_or(Error::<T>::InvalidIndex)?;
-				CollectionColor::<T>::insert(collection_id, color);
-			}
-			Self::create_game_properties()?;
-			let mut round = CurrentRound::<T>::get();
-			round = round.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-			CurrentRound::<T>::put(round);
-			RoundActive::<T>::put(true);
-			Ok(())
+			Self::ensure_role(origin, Role::RoundManager)?;
+			ensure!(!Self::round_active(), Error::<T>::RoundAlreadyActive);
+			ensure!(ScheduledRound::<T>::get().is_none(), Error::<T>::RoundAlreadyScheduled);
+			Self::do_start_round(None)
 		}
 
-		/// Registers a player and gives him initialy 50 points.
+		/// Registers a player and gives him initialy 50 points. The faucet tops the free balance
+		/// of the player up, as far as it is able to.
 		///
-		/// The origin must be the sudo.
+		/// The origin must be the sudo or Signed by an account with the `Registrar` role.
 		///
 		/// Parameters:
 		/// - `player`: The AccountId of the user who gets registered.
@@ -346,34 +1083,17 @@ pub mod pallet {
 		#[pallet::call_index(1)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::register_user())]
 		pub fn register_user(origin: OriginFor<T>, player: AccountIdOf<T>) -> DispatchResult {
-			let signer = ensure_signed(origin)?;
-			ensure!(Admins::<T>::get().contains(&signer), Error::<T>::NoPermission);
+			Self::ensure_role(origin, Role::Registrar)?;
 			ensure!(Users::<T>::get(player.clone()).is_none(), Error::<T>::PlayerAlreadyRegistered);
-			let current_block_number = <frame_system::Pallet<T>>::block_number();
-			let next_request =
-				current_block_number.saturating_add(<T as Config>::RequestLimit::get());
-			let user = User {
-				points: 50,
-				wins: Default::default(),
-				losses: Default::default(),
-				practise_rounds: Default::default(),
-				last_played_round: Default::default(),
-				next_token_request: next_request,
-				nfts: CollectedColors::default(),
-			};
-			<T as pallet::Config>::Currency::make_free_balance_be(
-				&player,
-				/*10000000000000 */10u64.try_into().map_err(|_| Error::<T>::ConversionError)?,
-			);
-			Users::<T>::insert(player.clone(), user);
-			frame_system::Pallet::<T>::inc_providers(&player);
-			Self::deposit_event(Event::<T>::NewPlayerRegistered { player });
-			Ok(())
+			if let Ok(amount) = Self::faucet_amount(&player) {
+				Self::drip(&player, amount)?;
+			}
+			Self::do_register(player)
 		}
 
 		/// Gives points to a user.
 		///
-		/// The origin must be the sudo.
+		/// The origin must be the sudo or Signed by an account with the `RoundManager` role.
 		///
 		/// Parameters:
 		/// - `receiver`: The AccountId of the user who gets points.
@@ -386,11 +1106,12 @@ pub mod pallet {
 			receiver: AccountIdOf<T>,
 			amount: u32,
 		) -> DispatchResult {
-			T::GameOrigin::ensure_origin(origin)?;
-			let mut user =
-				Users::<T>::get(receiver.clone()).ok_or(Error::<T>::UserNotRegistered)?;
+			Self::ensure_role(origin, Role::RoundManager)?;
+			let before = Users::<T>::get(receiver.clone()).ok_or(Error::<T>::UserNotRegistered)?;
+			let mut user = before.clone();
 			user.points = user.points.checked_add(amount).ok_or(Error::<T>::ArithmeticOverflow)?;
-			Users::<T>::insert(receiver.clone(), user);
+			Users::<T>::insert(receiver.clone(), user.clone());
+			Self::update_leaderboards(&receiver, &before, &user);
 			Self::deposit_event(Event::<T>::PointsReceived { receiver, amount: 100 });
 			Ok(())
 		}
@@ -407,33 +1128,28 @@ pub mod pallet {
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::play_game())]
 		#[pallet::feeless_if(|origin: &OriginFor<T>, game_type: &DifficultyLevel| -> bool {
 			if let Ok(signer) = ensure_signed(origin.clone()) {
-				match game_type {
-					DifficultyLevel::Pro => {
-						if let Some(user) = Users::<T>::get(&signer) {
-							if user.points >= 50 {
-								return true;
-							}
-						}
-					},
-					DifficultyLevel::Player => {
-						if let Some(user) = Users::<T>::get(&signer) {
-							if user.points >= 25 {
-								return true;
-							}
-						}
-					},
-					DifficultyLevel::Practice => {
-						return true;
-					}
+				if !Pallet::<T>::is_active(&signer) {
+					return false;
+				}
+				if *game_type == DifficultyLevel::Practice {
+					return true;
+				}
+				if let Some(user) = Users::<T>::get(&signer) {
+					let config = Pallet::<T>::scoring_config(game_type.clone());
+					return user.points >= config.entry_points;
 				}
 			}
 			false
 		})]
 		pub fn play_game(origin: OriginFor<T>, game_type: DifficultyLevel) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
+			Self::ensure_active(&signer)?;
 			Self::check_enough_points(signer.clone(), game_type.clone())?;
 			ensure!(RoundActive::<T>::get(), Error::<T>::NoActiveRound);
 			let mut user = Users::<T>::get(signer.clone()).ok_or(Error::<T>::UserNotRegistered)?;
+			if game_type != DifficultyLevel::Practice {
+				Self::pay_entry_fee(&signer)?;
+			}
 			let current_round = CurrentRound::<T>::get();
 			if current_round != user.last_played_round {
 				user.nfts = Default::default();
@@ -441,21 +1157,31 @@ pub mod pallet {
 				Users::<T>::insert(signer.clone(), user);
 			}
 			let game_id = GameId::<T>::get();
-			
-			let (hashi, _) = T::GameRandomness::random(&[(game_id % 256) as u8]);
-			let u32_value = u32::from_le_bytes(
-				hashi.as_ref()[4..8].try_into().map_err(|_| Error::<T>::ConversionError)?,
-			);
-			let mut game_properties = GameProperties::<T>::take();
-			let random_number = u32_value as usize % game_properties.len();
-			let property = game_properties[random_number].clone();
-			let game_datas =
-				GameData { difficulty: game_type, player: signer.clone(), property: property, guess: None };
-			game_properties.retain(|property| property.id as usize != random_number);
-			GameProperties::<T>::put(game_properties);
+			ActiveGames::<T>::try_append(game_id).map_err(|_| Error::<T>::TooManyGames)?;
+			PlayerGames::<T>::try_append(signer.clone(), game_id)
+				.map_err(|_| Error::<T>::TooManyPlayerGames)?;
+
+			let u32_value = Self::random_number(randomness::PROPERTY_SUBJECT, game_id, &signer)?;
+			let property = Self::take_random_property(u32_value)?;
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			let expiry_block = current_block_number.saturating_add(T::GameDuration::get());
+			GamesExpiring::<T>::try_mutate(expiry_block, |games| games.try_push(game_id))
+				.map_err(|_| Error::<T>::TooManyGames)?;
+			let game_datas = GameData {
+				difficulty: game_type,
+				player: signer.clone(),
+				property,
+				guess: None,
+				start_block: current_block_number,
+			};
 			GameInfo::<T>::insert(game_id, game_datas);
 			let next_game_id = game_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
 			GameId::<T>::put(next_game_id);
+			Rounds::<T>::mutate(current_round, |round| {
+				if let Some(round) = round {
+					round.games_played = round.games_played.saturating_add(1);
+				}
+			});
 			// Submit the encrypted property data and delete the price
 			Self::deposit_event(Event::<T>::GameStarted { player: signer, game_id });
 			Ok(())
@@ -469,13 +1195,18 @@ pub mod pallet {
 		/// - `guess`: The answer of the player.
 		/// - `game_id`: The id of the game that the player wants to answer to.
 		///
+		/// A game can only be answered once.
+		///
 		/// Emits `AnswerSubmitted` event when succesfful.
 		#[pallet::call_index(4)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::submit_answer())]
 		#[pallet::feeless_if(|origin: &OriginFor<T>, guess: &u32, game_id: &u32| -> bool {
 			if let Ok(signer) = ensure_signed(origin.clone()) {
 				if let Some(game_info) = GameInfo::<T>::get(*game_id) {
-					if signer == game_info.player {
+					if signer == game_info.player &&
+						game_info.guess.is_none() &&
+						Pallet::<T>::is_active(&signer)
+					{
 						return true;
 					}
 				}
@@ -484,130 +1215,129 @@ pub mod pallet {
 		})]
 		pub fn submit_answer(origin: OriginFor<T>, guess: u32, game_id: u32) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
+			Self::ensure_active(&signer)?;
 			let mut game_info = GameInfo::<T>::get(game_id).ok_or(Error::<T>::NoActiveGame)?;
 			ensure!(signer == game_info.player, Error::<T>::NoThePlayer);
+			ensure!(game_info.guess.is_none(), Error::<T>::AnswerAlreadySubmitted);
+			Self::remove_game_expiry(game_id, &game_info);
 			game_info.guess = Some(guess);
+			GamesExpiring::<T>::try_mutate(Self::game_expiry(&game_info), |games| {
+				games.try_push(game_id)
+			})
+			.map_err(|_| Error::<T>::TooManyGames)?;
 			GameInfo::<T>::insert(game_id, game_info);
 			Self::deposit_event(Event::<T>::AnswerSubmitted { player: signer, game_id, guess });
 			Ok(())
 		}
 
-		/// Checks the answer of the player and handles rewards accordingly.
+		/// Checks the answer that the player submitted and handles rewards accordingly. A game
+		/// without an answer is settled as not answered.
 		///
-		/// The origin must be root.
+		/// The origin must be the sudo or Signed by an account with the `Settler` role.
 		///
 		/// Parameters:
-		/// - `guess`: The answer of the player.
 		/// - `game_id`: The id of the game that the result should be compared to.
 		/// - `price`: The price of the property.
-		/// - `secret`: The secret to decrypt the price and property data.
+		/// - `secret`: The salt that was used for the commitment of the property.
+		///
+		/// The revealed price and secret have to match the commitment of the property.
 		///
 		/// Emits `ResultChecked` event when succesfful.
 		#[pallet::call_index(5)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::check_result())]
 		pub fn check_result(
 			origin: OriginFor<T>,
-			guess: u64,
 			game_id: u32,
 			price: u64,
 			secret: BoundedVec<u8, <T as Config>::StringLimit>,
 		) -> DispatchResult {
-			T::GameOrigin::ensure_origin(origin)?;
-			let game_info = GameInfo::<T>::take(game_id).ok_or(Error::<T>::NoActiveGame)?;
-			if game_info.guess.is_none() {
-				Self::no_answer_result(game_info, game_id)?;
-			} else {
-				let difference_value = ((price as i64)
-					.checked_sub(guess as i64)
-					.ok_or(Error::<T>::ArithmeticUnderflow)?)
-					.checked_mul(1000)
-					.ok_or(Error::<T>::MultiplyError)?
-					.checked_div(price as i64)
-					.ok_or(Error::<T>::DivisionError)?
-					.abs();
-				Self::do_check_result(
-					difference_value.try_into().map_err(|_| Error::<T>::ConversionError)?,
-					game_id,
-					game_info,
-					secret,
-				)?;
-			}
-			Ok(())
+			Self::ensure_role(origin, Role::Settler)?;
+			Self::do_settle_game(game_id, price, secret)
 		}
 
-		/// Lists a nft from the user.
+		/// Lists a nft from the user. A listing without a price is swapped right away if a
+		/// standing order wants the color of the nft.
 		///
 		/// The origin must be Signed and the sender must have sufficient funds free.
 		///
 		/// Parameters:
 		/// - `collection_id`: The collection id of the nft that will be listed.
 		/// - `item_id`: The item id of the nft that will be listed.
+		/// - `price`: The price for which the nft can be bought. Without a price the nft can only
+		///   be swapped against offers.
+		/// - `expiry`: The block in which the listing expires and the nft is returned.
 		///
 		/// Emits `NftListed` event when succesfful.
 		#[pallet::call_index(6)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::list_nft())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::list_nft().saturating_add(
+			<T as pallet::Config>::WeightInfo::fill_standing_order(),
+		))]
 		pub fn list_nft(
 			origin: OriginFor<T>,
 			collection_id: CollectionId<T>,
 			item_id: ItemId<T>,
+			price: Option<CurrencyBalanceOf<T>>,
+			expiry: Option<BlockNumberFor<T>>,
 		) -> DispatchResult {
-			let signer = ensure_signed(origin.clone())?;
-			let pallet_lookup = <T::Lookup as StaticLookup>::unlookup(Self::account_id());
-			ensure!(
-				pallet_nfts::Pallet::<T>::owner(collection_id.into(), item_id.into()) ==
-					Some(signer.clone()),
-				Error::<T>::NoPermission
-			);
-			let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
-			pallet_nfts::Pallet::<T>::unlock_item_transfer(
-				pallet_origin,
-				collection_id.into(),
-				item_id.into(),
-			)?;
-			pallet_nfts::Pallet::<T>::transfer(
-				origin,
-				collection_id.into(),
-				item_id.into(),
-				pallet_lookup,
-			)?;
-			let listing_info = ListingInfo { owner: signer.clone(), collection_id, item_id };
-			let mut listing_id = NextListingId::<T>::get();
+			let signer = ensure_signed(origin)?;
+			Self::ensure_active(&signer)?;
+			let listing_id = NextListingId::<T>::get();
+			if let Some(expiry) = expiry {
+				Self::ensure_valid_expiry(expiry)?;
+				ListingsExpiring::<T>::try_append(expiry, listing_id)
+					.map_err(|_| Error::<T>::TooManyExpiries)?;
+			}
+			Self::escrow_nft(&signer, collection_id, item_id)?;
+			let listing_info =
+				ListingInfo { owner: signer.clone(), collection_id, item_id, price, expiry };
 			Listings::<T>::insert(listing_id, listing_info);
-			listing_id = listing_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-			NextListingId::<T>::put(listing_id);
-			Self::deposit_event(Event::<T>::NftListed { owner: signer, collection_id, item_id });
+			NextListingId::<T>::put(
+				listing_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?,
+			);
+			Self::deposit_event(Event::<T>::NftListed {
+				owner: signer,
+				collection_id,
+				item_id,
+				price,
+			});
+			if price.is_none() {
+				Self::match_listing(listing_id)?;
+			}
 			Ok(())
 		}
 
-		/// Delists a nft from the user.
+		/// Delists a nft from the user. The nfts of all offers for the listing are returned.
 		///
-		/// The origin must be Signed and the sender must have sufficient funds free.
+		/// The origin must be Signed and the sender must be the owner of the listing or have the
+		/// `MarketplaceModerator` role. The nft is returned to the owner of the listing.
 		///
 		/// Parameters:
 		/// - `listing_id`: The listing id of the listing.
 		///
 		/// Emits `NftDelisted` event when succesfful.
 		#[pallet::call_index(7)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::delist_nft())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::delist_nft().saturating_add(
+			<T as pallet::Config>::WeightInfo::withdraw_offer()
+				.saturating_mul(T::MaxOffersPerListing::get().into()),
+		))]
 		pub fn delist_nft(origin: OriginFor<T>, listing_id: u32) -> DispatchResult {
-			let signer = ensure_signed(origin.clone())?;
+			let signer = ensure_signed(origin)?;
+			Self::ensure_active(&signer)?;
 			let listing_info =
 				Listings::<T>::take(listing_id).ok_or(Error::<T>::ListingDoesNotExist)?;
-			ensure!(listing_info.owner == signer, Error::<T>::NoPermission);
-			pallet_nfts::Pallet::<T>::do_transfer(
-				listing_info.collection_id.into(),
-				listing_info.item_id.into(),
-				signer.clone(),
-				|_, _| Ok(()),
-			)?;
-			let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
-			pallet_nfts::Pallet::<T>::lock_item_transfer(
-				pallet_origin,
-				listing_info.collection_id.into(),
-				listing_info.item_id.into(),
+			ensure!(
+				listing_info.owner == signer || Self::has_role(&signer, Role::MarketplaceModerator),
+				Error::<T>::NoPermission
+			);
+			Self::unschedule_listing_expiry(listing_id, listing_info.expiry);
+			Self::refund_offers(listing_id)?;
+			Self::release_nft(
+				&listing_info.owner,
+				listing_info.collection_id,
+				listing_info.item_id,
 			)?;
 			Self::deposit_event(Event::<T>::NftDelisted {
-				owner: signer,
+				owner: listing_info.owner,
 				collection_id: listing_info.collection_id,
 				item_id: listing_info.item_id,
 			});
@@ -622,6 +1352,7 @@ pub mod pallet {
 		/// - `listing_id`: The listing id of the listing.
 		/// - `collection_id`: The collection id of the nft that will be offered.
 		/// - `item_id`: The item id of the nft that will be offered.
+		/// - `expiry`: The block in which the offer expires and the nft is returned.
 		///
 		/// Emits `OfferMade` event when succesfful.
 		#[pallet::call_index(8)]
@@ -631,25 +1362,24 @@ pub mod pallet {
 			listing_id: u32,
 			collection_id: CollectionId<T>,
 			item_id: ItemId<T>,
+			expiry: Option<BlockNumberFor<T>>,
 		) -> DispatchResult {
-			let signer = ensure_signed(origin.clone())?;
-			ensure!(Listings::<T>::get(listing_id).is_some(), Error::<T>::ListingDoesNotExist);
-			let pallet_lookup = <T::Lookup as StaticLookup>::unlookup(Self::account_id());
-			let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
-			pallet_nfts::Pallet::<T>::unlock_item_transfer(
-				pallet_origin,
-				collection_id.into(),
-				item_id.into(),
-			)?;
-			pallet_nfts::Pallet::<T>::transfer(
-				origin,
-				collection_id.into(),
-				item_id.into(),
-				pallet_lookup,
-			)?;
-			let offer_info =
-				OfferInfo { owner: signer.clone(), listing_id, collection_id, item_id };
+			let signer = ensure_signed(origin)?;
+			Self::ensure_active(&signer)?;
+			let listing_info =
+				Listings::<T>::get(listing_id).ok_or(Error::<T>::ListingDoesNotExist)?;
+			ensure!(!Self::is_expired(listing_info.expiry), Error::<T>::ListingHasExpired);
 			let offer_id = NextOfferId::<T>::get();
+			ListingOffers::<T>::try_append(listing_id, offer_id)
+				.map_err(|_| Error::<T>::TooManyOffers)?;
+			if let Some(expiry) = expiry {
+				Self::ensure_valid_expiry(expiry)?;
+				OffersExpiring::<T>::try_append(expiry, offer_id)
+					.map_err(|_| Error::<T>::TooManyExpiries)?;
+			}
+			Self::escrow_nft(&signer, collection_id, item_id)?;
+			let offer_info =
+				OfferInfo { owner: signer.clone(), listing_id, collection_id, item_id, expiry };
 			Offers::<T>::insert(offer_id, offer_info);
 			let offer_id = offer_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
 			NextOfferId::<T>::put(offer_id);
@@ -664,36 +1394,42 @@ pub mod pallet {
 
 		/// Withdraw an offer.
 		///
-		/// The origin must be Signed and the sender must have sufficient funds free.
+		/// The origin must be Signed and the sender must be the owner of the offer or have the
+		/// `MarketplaceModerator` role. The nft is returned to the owner of the offer.
 		///
 		/// Parameters:
 		/// - `offer_id`: The id of the offer.
 		///
 		/// Emits `OfferWithdrawn` event when succesfful.
 		#[pallet::call_index(9)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::make_offer())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::withdraw_offer())]
 		pub fn withdraw_offer(origin: OriginFor<T>, offer_id: u32) -> DispatchResult {
-			let signer = ensure_signed(origin.clone())?;
-			let offer_details = Offers::<T>::get(offer_id).ok_or(Error::<T>::OfferDoesNotExist)?;
-			ensure!(offer_details.owner == signer, Error::<T>::NoPermission);
-			pallet_nfts::Pallet::<T>::do_transfer(
-				offer_details.collection_id.into(),
-				offer_details.item_id.into(),
-				signer.clone(),
-				|_, _| Ok(()),
-			)?;
-			let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
-			pallet_nfts::Pallet::<T>::lock_item_transfer(
-				pallet_origin,
-				offer_details.collection_id.into(),
-				offer_details.item_id.into(),
+			let signer = ensure_signed(origin)?;
+			Self::ensure_active(&signer)?;
+			let offer_details = Offers::<T>::take(offer_id).ok_or(Error::<T>::OfferDoesNotExist)?;
+			ensure!(
+				offer_details.owner == signer ||
+					Self::has_role(&signer, Role::MarketplaceModerator),
+				Error::<T>::NoPermission
+			);
+			ListingOffers::<T>::mutate(offer_details.listing_id, |offers| {
+				offers.retain(|id| *id != offer_id)
+			});
+			Self::unschedule_offer_expiry(offer_id, offer_details.expiry);
+			Self::release_nft(
+				&offer_details.owner,
+				offer_details.collection_id,
+				offer_details.item_id,
 			)?;
-			Offers::<T>::take(offer_id).ok_or(Error::<T>::OfferDoesNotExist)?;
-			Self::deposit_event(Event::<T>::OfferWithdrawn { owner: signer, offer_id });
+			Self::deposit_event(Event::<T>::OfferWithdrawn {
+				owner: offer_details.owner,
+				offer_id,
+			});
 			Ok(())
 		}
 
-		/// Handles an offer for a nft listing.
+		/// Handles an offer for a nft listing. If the offer is accepted, the nfts of all other
+		/// offers for the listing are returned.
 		///
 		/// The origin must be Signed and the sender must have sufficient funds free.
 		///
@@ -703,159 +1439,872 @@ pub mod pallet {
 		///
 		/// Emits `OfferHandeld` event when succesfful.
 		#[pallet::call_index(10)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::handle_offer())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::handle_offer().saturating_add(
+			<T as pallet::Config>::WeightInfo::withdraw_offer()
+				.saturating_mul(T::MaxOffersPerListing::get().into()),
+		))]
 		pub fn handle_offer(origin: OriginFor<T>, offer_id: u32, offer: Offer) -> DispatchResult {
-			let signer = ensure_signed(origin.clone())?;
+			let signer = ensure_signed(origin)?;
+			Self::ensure_active(&signer)?;
 			let offer_details = Offers::<T>::take(offer_id).ok_or(Error::<T>::OfferDoesNotExist)?;
 			let listing_details = Listings::<T>::get(offer_details.listing_id)
 				.ok_or(Error::<T>::ListingDoesNotExist)?;
 			ensure!(listing_details.owner == signer, Error::<T>::NoPermission);
-			let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
+			ListingOffers::<T>::mutate(offer_details.listing_id, |offers| {
+				offers.retain(|id| *id != offer_id)
+			});
+			Self::unschedule_offer_expiry(offer_id, offer_details.expiry);
 			if offer == Offer::Accept {
-				pallet_nfts::Pallet::<T>::do_transfer(
-					listing_details.collection_id.into(),
-					listing_details.item_id.into(),
-					offer_details.owner.clone(),
-					|_, _| Ok(()),
-				)?;
-				pallet_nfts::Pallet::<T>::lock_item_transfer(
-					pallet_origin.clone(),
-					listing_details.collection_id.into(),
-					listing_details.item_id.into(),
-				)?;
-				pallet_nfts::Pallet::<T>::do_transfer(
-					offer_details.collection_id.into(),
-					offer_details.item_id.into(),
-					listing_details.owner.clone(),
-					|_, _| Ok(()),
-				)?;
-				pallet_nfts::Pallet::<T>::lock_item_transfer(
-					pallet_origin,
-					offer_details.collection_id.into(),
-					offer_details.item_id.into(),
-				)?;
-				Listings::<T>::take(offer_details.listing_id)
-					.ok_or(Error::<T>::ListingDoesNotExist)?;
-
-				Self::swap_user_points(
-					offer_details.owner.clone(),
-					listing_details.collection_id,
-					offer_details.collection_id,
-				)?;
-				Self::swap_user_points(
-					signer.clone(),
-					offer_details.collection_id,
+				ensure!(!Self::is_expired(listing_details.expiry), Error::<T>::ListingHasExpired);
+				ensure!(!Self::is_expired(offer_details.expiry), Error::<T>::OfferHasExpired);
+				Listings::<T>::remove(offer_details.listing_id);
+				Self::unschedule_listing_expiry(offer_details.listing_id, listing_details.expiry);
+				Self::release_nft(
+					&offer_details.owner,
 					listing_details.collection_id,
+					listing_details.item_id,
 				)?;
+				Self::release_nft(&signer, offer_details.collection_id, offer_details.item_id)?;
+				Self::refund_offers(offer_details.listing_id)?;
 			} else {
-				pallet_nfts::Pallet::<T>::do_transfer(
-					offer_details.collection_id.into(),
-					offer_details.item_id.into(),
-					offer_details.owner,
-					|_, _| Ok(()),
-				)?;
-				pallet_nfts::Pallet::<T>::lock_item_transfer(
-					pallet_origin,
-					offer_details.collection_id.into(),
-					offer_details.item_id.into(),
+				Self::release_nft(
+					&offer_details.owner,
+					offer_details.collection_id,
+					offer_details.item_id,
 				)?;
 			}
 			Self::deposit_event(Event::<T>::OfferHandeld { offer_id, offer });
 			Ok(())
 		}
 
-		/// Add a new property and the price.
+		/// Add a new property together with the commitment of its price.
 		///
-		/// The origin must be the sudo.
+		/// The origin must be the sudo or Signed by an account with the `PropertyCurator` role.
 		///
 		/// Parameters:
-		/// - `property`: The new property that will be added.
-		/// - `price`: The price of the property that will be added.
+		/// - `property`: The new property that will be added. The commitment is the hash of the
+		///   SCALE encoded price, salt and data of the property.
+		///
+		/// Emits `PropertyAdded` event when succesfful.
 		#[pallet::call_index(11)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_property())]
 		pub fn add_property(origin: OriginFor<T>, property: PropertyInfoData<T>) -> DispatchResult {
-			T::GameOrigin::ensure_origin(origin)?;
-			GameProperties::<T>::try_append(property.clone())
-				.map_err(|_| Error::<T>::TooManyTest)?;
-			Ok(())
+			Self::ensure_role(origin, Role::PropertyCurator)?;
+			Self::do_add_property(property)
 		}
 
-		/// Remove a new property and the price.
+		/// Retires a property, so that it is not used for new games anymore.
 		///
-		/// The origin must be the sudo.
+		/// The origin must be the sudo or Signed by an account with the `PropertyCurator` role.
 		///
 		/// Parameters:
-		/// - `id`: The id of the property that should be removed.
+		/// - `id`: The id of the property that should be retired.
+		///
+		/// Emits `PropertyRetired` event when succesfful.
 		#[pallet::call_index(12)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_property())]
 		pub fn remove_property(origin: OriginFor<T>, id: u32) -> DispatchResult {
-			T::GameOrigin::ensure_origin(origin)?;
-			let mut properties = GameProperties::<T>::take();
-			properties.retain(|property| property.id != id);
-			GameProperties::<T>::put(properties);
+			Self::ensure_role(origin, Role::PropertyCurator)?;
+			let mut record = GameProperties::<T>::get(id).ok_or(Error::<T>::NoProperty)?;
+			ensure!(
+				matches!(record.status, PropertyStatus::Available | PropertyStatus::InPlay),
+				Error::<T>::PropertyAlreadyRetired
+			);
+			if record.status == PropertyStatus::Available {
+				Self::remove_available_property(id)?;
+			}
+			record.status = PropertyStatus::Retired;
+			GameProperties::<T>::insert(id, record);
+			Self::deposit_event(Event::<T>::PropertyRetired { property_id: id });
 			Ok(())
 		}
 
-		/// Adds an account to the admins.
+		/// Lets the player request token to play. The faucet drips token from the pallet account
+		/// until the free balance of the player reaches the faucet target.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// Emits `FaucetDrip` and `TokenReceived` event when succesfful.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::request_token())]
+		pub fn request_token(origin: OriginFor<T>) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			Self::ensure_active(&signer)?;
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			let mut user = Users::<T>::get(signer.clone()).ok_or(Error::<T>::UserNotRegistered)?;
+			ensure!(user.next_token_request < current_block_number, Error::<T>::CantRequestToken);
+			let next_request =
+				current_block_number.saturating_add(<T as Config>::RequestLimit::get());
+			user.next_token_request = next_request;
+			let amount = Self::faucet_amount(&signer)?;
+			Self::drip(&signer, amount)?;
+			Users::<T>::insert(signer.clone(), user);
+			Self::deposit_event(Event::<T>::TokenReceived { player: signer });
+			Ok(())
+		}
+
+		/// Settles a game with the guess that the player submitted.
+		///
+		/// The origin must be Signed and the sender must have the `Settler` role. This call is
+		/// submitted by the offchain worker with the price of the local price feed. A game can only
+		/// be settled once the player can no longer answer it.
+		///
+		/// Parameters:
+		/// - `game_id`: The id of the game that should be settled.
+		/// - `price`: The price of the property.
+		/// - `secret`: The salt that was used for the commitment of the property.
+		///
+		/// Emits `ResultChecked` event when succesfful.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::submit_result())]
+		#[pallet::feeless_if(|origin: &OriginFor<T>, _game_id: &u32, _price: &u64, _secret: &BoundedVec<u8, <T as Config>::StringLimit>| -> bool {
+			if let Ok(signer) = ensure_signed(origin.clone()) {
+				return Pallet::<T>::has_role(&signer, Role::Settler) &&
+					Pallet::<T>::is_active(&signer);
+			}
+			false
+		})]
+		pub fn submit_result(
+			origin: OriginFor<T>,
+			game_id: u32,
+			price: u64,
+			secret: BoundedVec<u8, <T as Config>::StringLimit>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			Self::ensure_active(&signer)?;
+			ensure!(Self::has_role(&signer, Role::Settler), Error::<T>::NoPermission);
+			let game_info = GameInfo::<T>::get(game_id).ok_or(Error::<T>::NoActiveGame)?;
+			ensure!(game_info.guess.is_some(), Error::<T>::NoGuess);
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >= Self::answer_deadline(&game_info),
+				Error::<T>::AnswerWindowOpen
+			);
+			Self::do_settle_game(game_id, price, secret)
+		}
+
+		/// Sets the scoring table of a difficulty level.
+		///
+		/// The origin must be the sudo or Signed by an account with the `RoundManager` role.
+		///
+		/// Parameters:
+		/// - `difficulty`: The difficulty level that the scoring table is used for.
+		/// - `config`: The new scoring table. The nft threshold and the max differences of the
+		///   bands have to be sorted ascending. A practice table can not have an nft threshold or
+		///   entry points.
+		///
+		/// Emits `ScoringConfigUpdated` event when succesfful.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_scoring_config())]
+		pub fn set_scoring_config(
+			origin: OriginFor<T>,
+			difficulty: DifficultyLevel,
+			config: ScoringConfig<T>,
+		) -> DispatchResult {
+			Self::ensure_role(origin, Role::RoundManager)?;
+			ensure!(config.is_valid(&difficulty), Error::<T>::InvalidScoringConfig);
+			ScoringConfigs::<T>::insert(difficulty.clone(), config.clone());
+			Self::deposit_event(Event::<T>::ScoringConfigUpdated { difficulty, config });
+			Ok(())
+		}
+
+		/// Adds multiple new properties together with the commitments of their prices.
+		///
+		/// The origin must be the sudo or Signed by an account with the `PropertyCurator` role.
+		///
+		/// Parameters:
+		/// - `properties`: The new properties that will be added. The commitment is the hash of the
+		///   SCALE encoded price, salt and data of the property.
+		///
+		/// Emits `PropertyAdded` event for every property when succesfful.
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_properties(properties.len() as u32))]
+		pub fn add_properties(
+			origin: OriginFor<T>,
+			properties: BoundedVec<PropertyInfoData<T>, T::MaxPropertiesPerBatch>,
+		) -> DispatchResult {
+			Self::ensure_role(origin, Role::PropertyCurator)?;
+			for property in properties {
+				Self::do_add_property(property)?;
+			}
+			Ok(())
+		}
+
+		/// Starts a new round now or schedules it for a later block.
+		///
+		/// The origin must be the sudo or Signed by an account with the `RoundManager` role.
+		///
+		/// Parameters:
+		/// - `start_block`: The block in which the round starts. The round starts immediately if
+		///   this is `None` or not in the future.
+		/// - `end_block`: The block in which the round is closed if nobody has collected four nfts
+		///   of every color before.
+		///
+		/// Emits `RoundStarted` or `RoundScheduled` event when succesfful.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::start_round())]
+		pub fn start_round(
+			origin: OriginFor<T>,
+			start_block: Option<BlockNumberFor<T>>,
+			end_block: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			Self::ensure_role(origin, Role::RoundManager)?;
+			ensure!(!Self::round_active(), Error::<T>::RoundAlreadyActive);
+			ensure!(ScheduledRound::<T>::get().is_none(), Error::<T>::RoundAlreadyScheduled);
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			let start_block = start_block.unwrap_or(current_block_number).max(current_block_number);
+			ensure!(
+				end_block.map_or(true, |end_block| end_block > start_block),
+				Error::<T>::InvalidRoundSchedule
+			);
+			if start_block > current_block_number {
+				ScheduledRound::<T>::put((start_block, end_block));
+				Self::deposit_event(Event::<T>::RoundScheduled { start_block, end_block });
+				Ok(())
+			} else {
+				Self::do_start_round(end_block)
+			}
+		}
+
+		/// Ends the current round without a champion.
+		///
+		/// The origin must be the sudo or Signed by an account with the `RoundManager` role.
+		///
+		/// Emits `RoundEnded` event when succesfful.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::end_round())]
+		pub fn end_round(origin: OriginFor<T>) -> DispatchResult {
+			Self::ensure_role(origin, Role::RoundManager)?;
+			ensure!(Self::round_active(), Error::<T>::NoActiveRound);
+			Self::do_end_round(None);
+			Ok(())
+		}
+
+		/// Buys a listed nft for its price. The marketplace fee is paid to the pallet account and
+		/// the rest of the price to the seller. The nfts of all offers for the listing are
+		/// returned.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// Parameters:
+		/// - `listing_id`: The listing id of the listing.
+		///
+		/// Emits `NftSold` event when succesfful.
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::buy_nft().saturating_add(
+			<T as pallet::Config>::WeightInfo::withdraw_offer()
+				.saturating_mul(T::MaxOffersPerListing::get().into()),
+		))]
+		pub fn buy_nft(origin: OriginFor<T>, listing_id: u32) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			Self::ensure_active(&buyer)?;
+			ensure!(Users::<T>::contains_key(&buyer), Error::<T>::UserNotRegistered);
+			let listing_info =
+				Listings::<T>::take(listing_id).ok_or(Error::<T>::ListingDoesNotExist)?;
+			ensure!(listing_info.owner != buyer, Error::<T>::NoPermission);
+			ensure!(!Self::is_expired(listing_info.expiry), Error::<T>::ListingHasExpired);
+			let price = listing_info.price.ok_or(Error::<T>::ListingNotForSale)?;
+			let fee = T::MarketplaceFee::get() * price;
+			<T as pallet::Config>::Currency::transfer(
+				&buyer,
+				&Self::account_id(),
+				fee,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			<T as pallet::Config>::Currency::transfer(
+				&buyer,
+				&listing_info.owner,
+				price.saturating_sub(fee),
+				ExistenceRequirement::KeepAlive,
+			)?;
+			Self::unschedule_listing_expiry(listing_id, listing_info.expiry);
+			Self::refund_offers(listing_id)?;
+			Self::release_nft(&buyer, listing_info.collection_id, listing_info.item_id)?;
+			Self::deposit_event(Event::<T>::NftSold {
+				listing_id,
+				seller: listing_info.owner,
+				buyer,
+				price,
+				fee,
+			});
+			Ok(())
+		}
+
+		/// Puts a nft up for auction. The auction ends after `AuctionDuration` blocks and is
+		/// settled automatically.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// Parameters:
+		/// - `collection_id`: The collection id of the nft that will be auctioned.
+		/// - `item_id`: The item id of the nft that will be auctioned.
+		/// - `min_bid`: The lowest bid that is accepted.
+		///
+		/// Emits `AuctionCreated` event when succesfful.
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_auction())]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			collection_id: CollectionId<T>,
+			item_id: ItemId<T>,
+			min_bid: CurrencyBalanceOf<T>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			Self::ensure_active(&signer)?;
+			Self::escrow_nft(&signer, collection_id, item_id)?;
+			let auction_id = NextAuctionId::<T>::get();
+			let end_block =
+				<frame_system::Pallet<T>>::block_number().saturating_add(T::AuctionDuration::get());
+			Self::schedule_auction_end(auction_id, end_block)?;
+			let auction_info = AuctionInfo {
+				owner: signer.clone(),
+				collection_id,
+				item_id,
+				min_bid,
+				highest_bid: None,
+				end_block,
+			};
+			Auctions::<T>::insert(auction_id, auction_info);
+			NextAuctionId::<T>::put(
+				auction_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?,
+			);
+			Self::deposit_event(Event::<T>::AuctionCreated {
+				auction_id,
+				owner: signer,
+				collection_id,
+				item_id,
+				min_bid,
+				end_block,
+			});
+			Ok(())
+		}
+
+		/// Bids on an auction. The amount is reserved until the bid is outbid or the auction is
+		/// settled. A bid in the last `AuctionExtension` blocks extends the auction, to the next
+		/// block that has room for it if too many auctions end in the extended end.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// Parameters:
+		/// - `auction_id`: The id of the auction.
+		/// - `amount`: The amount of the bid.
+		///
+		/// Emits `BidPlaced` event when succesfful.
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::bid())]
+		pub fn bid(
+			origin: OriginFor<T>,
+			auction_id: u32,
+			amount: CurrencyBalanceOf<T>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			Self::ensure_active(&signer)?;
+			ensure!(Users::<T>::contains_key(&signer), Error::<T>::UserNotRegistered);
+			let mut auction_info =
+				Auctions::<T>::get(auction_id).ok_or(Error::<T>::AuctionDoesNotExist)?;
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			ensure!(current_block_number < auction_info.end_block, Error::<T>::AuctionEnded);
+			ensure!(auction_info.owner != signer, Error::<T>::NoPermission);
+			ensure!(amount >= auction_info.min_bid, Error::<T>::BidTooLow);
+			if let Some((_, highest_amount)) = &auction_info.highest_bid {
+				ensure!(amount > *highest_amount, Error::<T>::BidTooLow);
+			}
+			<T as pallet::Config>::Currency::reserve(&signer, amount)?;
+			if let Some((bidder, highest_amount)) = auction_info.highest_bid.take() {
+				<T as pallet::Config>::Currency::unreserve(&bidder, highest_amount);
+				HighestBidCount::<T>::mutate(&bidder, |count| *count = count.saturating_sub(1));
+			}
+			HighestBidCount::<T>::mutate(&signer, |count| *count = count.saturating_add(1));
+			auction_info.highest_bid = Some((signer.clone(), amount));
+			let extended_end = current_block_number.saturating_add(T::AuctionExtension::get());
+			if extended_end > auction_info.end_block {
+				Self::unschedule_auction_end(auction_id, auction_info.end_block);
+				auction_info.end_block =
+					Self::schedule_auction_extension(auction_id, extended_end)?;
+			}
+			let end_block = auction_info.end_block;
+			Auctions::<T>::insert(auction_id, auction_info);
+			Self::deposit_event(Event::<T>::BidPlaced {
+				auction_id,
+				bidder: signer,
+				amount,
+				end_block,
+			});
+			Ok(())
+		}
+
+		/// Settles an auction that has ended but could not be settled automatically.
+		///
+		/// The origin must be Signed.
+		///
+		/// Parameters:
+		/// - `auction_id`: The id of the auction.
+		///
+		/// Emits `AuctionSettled` event when succesfful.
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::settle_auction())]
+		pub fn settle_auction(origin: OriginFor<T>, auction_id: u32) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			Self::ensure_active(&signer)?;
+			let auction_info =
+				Auctions::<T>::get(auction_id).ok_or(Error::<T>::AuctionDoesNotExist)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >= auction_info.end_block,
+				Error::<T>::AuctionNotEnded
+			);
+			Self::do_settle_auction(auction_id)
+		}
+
+		/// Places a standing order that swaps a nft for any nft of the wanted color. The order is
+		/// matched right away with the oldest standing order that offers the wanted color for the
+		/// color of the nft, otherwise it waits for a matching standing order or listing.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// Parameters:
+		/// - `collection_id`: The collection id of the nft that will be offered.
+		/// - `item_id`: The item id of the nft that will be offered.
+		/// - `want`: The color of the wanted nft.
+		///
+		/// Emits `StandingOrderPlaced` event when succesfful.
+		#[pallet::call_index(27)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::place_standing_order())]
+		pub fn place_standing_order(
+			origin: OriginFor<T>,
+			collection_id: CollectionId<T>,
+			item_id: ItemId<T>,
+			want: NftColor,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			Self::ensure_active(&signer)?;
+			let give =
+				Self::collection_color(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+			ensure!(give != want, Error::<T>::SameColor);
+			Self::escrow_nft(&signer, collection_id, item_id)?;
+			let order_id = NextStandingOrderId::<T>::get();
+			NextStandingOrderId::<T>::put(
+				order_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?,
+			);
+			Self::deposit_event(Event::<T>::StandingOrderPlaced {
+				order_id,
+				owner: signer.clone(),
+				collection_id,
+				item_id,
+				give: give.clone(),
+				want: want.clone(),
+			});
+			if let Some(matched_id) = Self::oldest_standing_order(&want, &give, &signer) {
+				return Self::fill_standing_order(matched_id, &signer, collection_id, item_id, None);
+			}
+			StandingOrdersByColor::<T>::try_append(&give, &want, order_id)
+				.map_err(|_| Error::<T>::TooManyStandingOrders)?;
+			let order_info =
+				StandingOrderInfo { owner: signer, collection_id, item_id, give, want };
+			StandingOrders::<T>::insert(order_id, order_info);
+			Ok(())
+		}
+
+		/// Cancels a standing order and returns the nft.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// Parameters:
+		/// - `order_id`: The id of the standing order.
+		///
+		/// Emits `StandingOrderCancelled` event when succesfful.
+		#[pallet::call_index(28)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_standing_order())]
+		pub fn cancel_standing_order(origin: OriginFor<T>, order_id: u32) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			Self::ensure_active(&signer)?;
+			let order_info =
+				StandingOrders::<T>::take(order_id).ok_or(Error::<T>::StandingOrderDoesNotExist)?;
+			ensure!(order_info.owner == signer, Error::<T>::NoPermission);
+			StandingOrdersByColor::<T>::mutate(&order_info.give, &order_info.want, |orders| {
+				orders.retain(|id| *id != order_id)
+			});
+			Self::release_nft(&signer, order_info.collection_id, order_info.item_id)?;
+			Self::deposit_event(Event::<T>::StandingOrderCancelled { order_id, owner: signer });
+			Ok(())
+		}
+
+		/// Adds funds to the prize pool. A Signed origin pays the funds into the pallet account,
+		/// the sudo adds free funds of the pallet account, like the collected marketplace fees.
+		/// The funds of the prize pool and the prizes that have not been claimed yet are not free.
+		///
+		/// The origin must be Signed or the sudo.
+		///
+		/// Parameters:
+		/// - `amount`: The amount that is added to the prize pool.
+		///
+		/// Emits `PrizePoolFunded` event when succesfful.
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::fund_prize_pool())]
+		pub fn fund_prize_pool(
+			origin: OriginFor<T>,
+			amount: CurrencyBalanceOf<T>,
+		) -> DispatchResult {
+			let who = match T::GameOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			if let Some(who) = &who {
+				Self::ensure_active(who)?;
+			}
+			let pool = Self::prize_pool().saturating_add(amount);
+			match &who {
+				Some(who) => <T as pallet::Config>::Currency::transfer(
+					who,
+					&Self::account_id(),
+					amount,
+					ExistenceRequirement::KeepAlive,
+				)?,
+				None => ensure!(
+					<T as pallet::Config>::Currency::free_balance(&Self::account_id())
+						.saturating_sub(<T as pallet::Config>::Currency::minimum_balance()) >=
+						pool.saturating_add(Self::unclaimed_prizes()),
+					Error::<T>::InsufficientPalletFunds
+				),
+			}
+			PrizePool::<T>::put(pool);
+			Self::deposit_event(Event::<T>::PrizePoolFunded { who, amount });
+			Ok(())
+		}
+
+		/// Sets the shares of the prize pool that the places of the round leaderboard receive
+		/// when a round closes.
+		///
+		/// The origin must be the sudo or Signed by an account with the `RoundManager` role.
+		///
+		/// Parameters:
+		/// - `shares`: The shares of the places, starting with the first place. The shares can not
+		///   add up to more than the whole prize pool.
+		///
+		/// Emits `PrizeDistributionUpdated` event when succesfful.
+		#[pallet::call_index(30)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_prize_distribution())]
+		pub fn set_prize_distribution(
+			origin: OriginFor<T>,
+			shares: BoundedVec<Permill, T::LeaderboardLimit>,
+		) -> DispatchResult {
+			Self::ensure_role(origin, Role::RoundManager)?;
+			ensure!(Self::is_valid_distribution(&shares), Error::<T>::InvalidPrizeDistribution);
+			PrizeDistribution::<T>::put(shares.clone());
+			Self::deposit_event(Event::<T>::PrizeDistributionUpdated { shares });
+			Ok(())
+		}
+
+		/// Pays out the prize that the caller has been awarded in a round.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free.
+		///
+		/// Parameters:
+		/// - `round`: The round in which the prize has been awarded.
+		///
+		/// Emits `PrizeClaimed` event when succesfful.
+		#[pallet::call_index(31)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::claim_prize())]
+		pub fn claim_prize(origin: OriginFor<T>, round: u32) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			Self::ensure_active(&signer)?;
+			let amount = RoundPrizes::<T>::take(round, &signer).ok_or(Error::<T>::NoPrize)?;
+			<T as pallet::Config>::Currency::transfer(
+				&Self::account_id(),
+				&signer,
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			UnclaimedPrizes::<T>::mutate(|unclaimed| *unclaimed = unclaimed.saturating_sub(amount));
+			Self::deposit_event(Event::<T>::PrizeClaimed { round, player: signer, amount });
+			Ok(())
+		}
+
+		/// Grants a role to an account.
 		///
 		/// The origin must be the sudo.
 		///
 		/// Parameters:
-		/// - `new_admin`: The address of the new account added to the list.
+		/// - `role`: The role that is granted.
+		/// - `account`: The account that is granted the role.
 		///
-		/// Emits `NewAdminAdded` event when succesfful
-		#[pallet::call_index(13)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_to_admins())]
-		pub fn add_to_admins(origin: OriginFor<T>, new_admin: AccountIdOf<T>) -> DispatchResult {
+		/// Emits `RoleGranted` event when succesfful.
+		#[pallet::call_index(32)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::grant_role())]
+		pub fn grant_role(
+			origin: OriginFor<T>,
+			role: Role,
+			account: AccountIdOf<T>,
+		) -> DispatchResult {
 			T::GameOrigin::ensure_origin(origin)?;
-			ensure!(!Admins::<T>::get().contains(&new_admin), Error::<T>::AccountAlreadyAdmin,);
-			Admins::<T>::try_append(new_admin.clone()).map_err(|_| Error::<T>::TooManyAdmins)?;
-			Self::deposit_event(Event::<T>::NewAdminAdded { new_admin });
-			Ok(())
+			Self::do_grant_role(role, account)
 		}
 
-		/// Removes an account from the admins.
+		/// Revokes a role from an account.
 		///
 		/// The origin must be the sudo.
 		///
 		/// Parameters:
-		/// - `admin`: The address of the admin removed from the admins.
+		/// - `role`: The role that is revoked.
+		/// - `account`: The account that the role is revoked from.
 		///
-		/// Emits `UserRemoved` event when succesfful
-		#[pallet::call_index(14)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_from_admins())]
-		pub fn remove_from_admins(origin: OriginFor<T>, admin: AccountIdOf<T>) -> DispatchResult {
+		/// Emits `RoleRevoked` event when succesfful.
+		#[pallet::call_index(33)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_role())]
+		pub fn revoke_role(
+			origin: OriginFor<T>,
+			role: Role,
+			account: AccountIdOf<T>,
+		) -> DispatchResult {
 			T::GameOrigin::ensure_origin(origin)?;
-			ensure!(Admins::<T>::get().contains(&admin), Error::<T>::NotAdmin);
-			let mut admins = Admins::<T>::get();
-			let index = admins.iter().position(|x| *x == admin).ok_or(Error::<T>::InvalidIndex)?;
-			admins.remove(index);
-			Admins::<T>::put(admins);
-			Self::deposit_event(Event::<T>::AdminRemoved { admin });
+			RoleMembers::<T>::try_mutate(&role, |members| {
+				let index = members
+					.iter()
+					.position(|member| *member == account)
+					.ok_or(Error::<T>::RoleNotGranted)?;
+				members.remove(index);
+				Ok::<(), DispatchError>(())
+			})?;
+			Self::deposit_event(Event::<T>::RoleRevoked { role, account });
 			Ok(())
 		}
 
-		/// Lets the player request token to play.
+		/// Registers the caller as a player and gives him initialy 50 points. Without an invite
+		/// code the registration deposit is reserved from the caller.
 		///
 		/// The origin must be Signed and the sender must have sufficient funds free.
 		///
-		/// Emits `TokenReceived` event when succesfful.
-		#[pallet::call_index(15)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::request_token())]
-		pub fn request_token(origin: OriginFor<T>) -> DispatchResult {
+		/// Parameters:
+		/// - `invite_code`: A one-time invite code of the caller that is redeemed instead of
+		///   reserving the deposit.
+		///
+		/// Emits `NewPlayerRegistered` event when succesfful.
+		#[pallet::call_index(34)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::register())]
+		pub fn register(
+			origin: OriginFor<T>,
+			invite_code: Option<BoundedVec<u8, <T as Config>::StringLimit>>,
+		) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
-			let current_block_number = <frame_system::Pallet<T>>::block_number();
-			let mut user = Users::<T>::get(signer.clone()).ok_or(Error::<T>::UserNotRegistered)?;
-			ensure!(user.next_token_request < current_block_number, Error::<T>::CantRequestToken);
-			let next_request =
-				current_block_number.saturating_add(<T as Config>::RequestLimit::get());
-			user.next_token_request = next_request;
-			<T as pallet::Config>::Currency::make_free_balance_be(
-				&signer,
-				/* 10000000000000 */10u64.try_into().map_err(|_| Error::<T>::ConversionError)?,
+			ensure!(!Users::<T>::contains_key(&signer), Error::<T>::PlayerAlreadyRegistered);
+			match invite_code {
+				Some(code) => {
+					let code_hash = Self::invite_code_hash(&code, &signer);
+					InviteCodes::<T>::take(code_hash).ok_or(Error::<T>::InvalidInviteCode)?;
+					Self::deposit_event(Event::<T>::InviteCodeRedeemed {
+						player: signer.clone(),
+						code_hash,
+					});
+				},
+				None => {
+					let deposit = T::RegistrationDeposit::get();
+					<T as pallet::Config>::Currency::reserve(&signer, deposit)?;
+					RegistrationDeposits::<T>::insert(&signer, deposit);
+				},
+			}
+			Self::do_register(signer)
+		}
+
+		/// Unregisters the caller and returns the registration deposit. The game nfts of the
+		/// caller are kept.
+		///
+		/// The origin must be Signed and the sender must be a registered player without ongoing
+		/// games, nfts on the marketplace or highest bids.
+		///
+		/// Parameters:
+		/// - `max_rounds`: The maximum amount of rounds of the caller that are cleaned up.
+		///
+		/// Emits `PlayerUnregistered` event when succesfful.
+		#[pallet::call_index(35)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::unregister(*max_rounds))]
+		pub fn unregister(origin: OriginFor<T>, max_rounds: u32) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			Self::ensure_active(&signer)?;
+			Self::do_unregister(signer, max_rounds)
+		}
+
+		/// Deregisters a player. The data of the player is removed, the game nfts of the player
+		/// are burned and the registration deposit is returned.
+		///
+		/// The origin must be Signed by the player, or be the sudo or Signed by an account with
+		/// the `Registrar` role. The player can not have ongoing games, nfts on the marketplace or
+		/// highest bids.
+		///
+		/// Parameters:
+		/// - `player`: The player that is deregistered.
+		/// - `max_nfts`: The maximum amount of game nfts of the player that are burned.
+		/// - `max_rounds`: The maximum amount of rounds of the player that are cleaned up.
+		///
+		/// Emits `PlayerDeregistered` event when succesfful.
+		#[pallet::call_index(42)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::deregister(*max_nfts, *max_rounds))]
+		pub fn deregister(
+			origin: OriginFor<T>,
+			player: AccountIdOf<T>,
+			max_nfts: u32,
+			max_rounds: u32,
+		) -> DispatchResult {
+			if ensure_signed(origin.clone()).ok() == Some(player.clone()) {
+				Self::ensure_active(&player)?;
+			} else {
+				Self::ensure_role(origin, Role::Registrar)?;
+			}
+			Self::do_deregister(player, max_nfts, max_rounds)
+		}
+
+		/// Creates one-time invite codes with which players can register without a deposit. Every
+		/// code can only be redeemed by the account that it has been created for.
+		///
+		/// The origin must be the sudo or Signed by an account with the `Registrar` role.
+		///
+		/// Parameters:
+		/// - `code_hashes`: The hashes of the new invite codes and their accounts, as returned by
+		///   `invite_code_hash`.
+		///
+		/// Emits `InviteCodesCreated` event when succesfful.
+		#[pallet::call_index(36)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_invite_codes(code_hashes.len() as u32))]
+		pub fn create_invite_codes(
+			origin: OriginFor<T>,
+			code_hashes: BoundedVec<T::Hash, T::MaxInviteCodesPerCall>,
+		) -> DispatchResult {
+			Self::ensure_role(origin, Role::Registrar)?;
+			for code_hash in &code_hashes {
+				ensure!(
+					!InviteCodes::<T>::contains_key(code_hash),
+					Error::<T>::InviteCodeAlreadyExists
+				);
+				InviteCodes::<T>::insert(code_hash, ());
+			}
+			Self::deposit_event(Event::<T>::InviteCodesCreated {
+				amount: code_hashes.len() as u32,
+			});
+			Ok(())
+		}
+
+		/// Suspends a player until a block. A new suspension replaces the running one.
+		///
+		/// The origin must be the sudo or Signed by an account with the `PlayerModerator` role.
+		///
+		/// Parameters:
+		/// - `player`: The player that gets suspended.
+		/// - `until`: The block in which the suspension ends.
+		/// - `reason`: The hash of the reason for the suspension.
+		///
+		/// Emits `PlayerSuspended` event when succesfful.
+		#[pallet::call_index(37)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::suspend_player())]
+		pub fn suspend_player(
+			origin: OriginFor<T>,
+			player: AccountIdOf<T>,
+			until: BlockNumberFor<T>,
+			reason: T::Hash,
+		) -> DispatchResult {
+			Self::ensure_role(origin, Role::PlayerModerator)?;
+			ensure!(
+				until > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidSuspension
 			);
-			Users::<T>::insert(signer.clone(), user);
-			Self::deposit_event(Event::<T>::TokenReceived { player: signer });
+			let user = Users::<T>::get(&player).ok_or(Error::<T>::UserNotRegistered)?;
+			ensure!(user.moderation != ModerationState::Banned, Error::<T>::PlayerIsBanned);
+			Self::set_moderation(&player, ModerationState::Suspended(until))?;
+			Self::deposit_event(Event::<T>::PlayerSuspended { player, until, reason });
+			Ok(())
+		}
+
+		/// Bans a player until a moderator reinstates the player.
+		///
+		/// The origin must be the sudo or Signed by an account with the `PlayerModerator` role.
+		///
+		/// Parameters:
+		/// - `player`: The player that gets banned.
+		/// - `reason`: The hash of the reason for the ban.
+		///
+		/// Emits `PlayerBanned` event when succesfful.
+		#[pallet::call_index(38)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::ban_player())]
+		pub fn ban_player(
+			origin: OriginFor<T>,
+			player: AccountIdOf<T>,
+			reason: T::Hash,
+		) -> DispatchResult {
+			Self::ensure_role(origin, Role::PlayerModerator)?;
+			let user = Users::<T>::get(&player).ok_or(Error::<T>::UserNotRegistered)?;
+			ensure!(user.moderation != ModerationState::Banned, Error::<T>::PlayerIsBanned);
+			Self::set_moderation(&player, ModerationState::Banned)?;
+			BannedAccounts::<T>::insert(&player, ());
+			Self::deposit_event(Event::<T>::PlayerBanned { player, reason });
+			Ok(())
+		}
+
+		/// Lifts the suspension or the ban of a player. Accepts the open appeal of the player. The
+		/// suspension or the ban of an account that is not registered anymore is lifted as well.
+		///
+		/// The origin must be the sudo or Signed by an account with the `PlayerModerator` role.
+		///
+		/// Parameters:
+		/// - `player`: The player that gets reinstated.
+		/// - `reason`: The hash of the reason for the reinstatement.
+		///
+		/// Emits `PlayerReinstated` event when succesfful.
+		#[pallet::call_index(39)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reinstate_player())]
+		pub fn reinstate_player(
+			origin: OriginFor<T>,
+			player: AccountIdOf<T>,
+			reason: T::Hash,
+		) -> DispatchResult {
+			Self::ensure_role(origin, Role::PlayerModerator)?;
+			if Users::<T>::contains_key(&player) {
+				ensure!(!Self::is_active(&player), Error::<T>::PlayerNotRestricted);
+				Self::set_moderation(&player, ModerationState::Active)?;
+			} else if let Some(until) = SuspendedAccounts::<T>::take(&player) {
+				SuspensionsEnding::<T>::mutate(until, |players| players.retain(|p| *p != player));
+			} else {
+				ensure!(BannedAccounts::<T>::contains_key(&player), Error::<T>::UserNotRegistered);
+			}
+			BannedAccounts::<T>::remove(&player);
+			Self::deposit_event(Event::<T>::PlayerReinstated { player, reason });
+			Ok(())
+		}
+
+		/// Lets a suspended or banned player appeal against the suspension or the ban.
+		///
+		/// The origin must be Signed and the sender must be suspended or banned.
+		///
+		/// Parameters:
+		/// - `statement`: The hash of the statement of the player.
+		///
+		/// Emits `AppealFiled` event when succesfful.
+		#[pallet::call_index(40)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::file_appeal())]
+		pub fn file_appeal(origin: OriginFor<T>, statement: T::Hash) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			ensure!(Users::<T>::contains_key(&signer), Error::<T>::UserNotRegistered);
+			ensure!(!Self::is_active(&signer), Error::<T>::PlayerNotRestricted);
+			ensure!(!Appeals::<T>::contains_key(&signer), Error::<T>::AppealAlreadyFiled);
+			Appeals::<T>::insert(&signer, statement);
+			Self::deposit_event(Event::<T>::AppealFiled { player: signer, statement });
+			Ok(())
+		}
+
+		/// Rejects the open appeal of a player.
+		///
+		/// The origin must be the sudo or Signed by an account with the `PlayerModerator` role.
+		///
+		/// Parameters:
+		/// - `player`: The player whose appeal gets rejected.
+		/// - `reason`: The hash of the reason for the rejection.
+		///
+		/// Emits `AppealRejected` event when succesfful.
+		#[pallet::call_index(41)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reject_appeal())]
+		pub fn reject_appeal(
+			origin: OriginFor<T>,
+			player: AccountIdOf<T>,
+			reason: T::Hash,
+		) -> DispatchResult {
+			Self::ensure_role(origin, Role::PlayerModerator)?;
+			Appeals::<T>::take(&player).ok_or(Error::<T>::NoAppeal)?;
+			Self::deposit_event(Event::<T>::AppealRejected { player, reason });
 			Ok(())
 		}
 	}
@@ -0,0 +1,329 @@
+//! Storage migrations of the pallet.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, sp_runtime::Saturating, traits::OnRuntimeUpgrade};
+use frame_system::pallet_prelude::*;
+use sp_std::vec::Vec;
+
+/// Migration from the storage version 0 to 1.
+///
+/// Version 1 stores the properties in a map of property records and keeps the escrowed nfts, the
+/// round collections and the leaderboard scores in their own storage items.
+pub mod v1 {
+	use super::*;
+
+	/// The storage items of version 0 whose encoding changed in version 1.
+	pub mod v0 {
+		use super::*;
+
+		#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo)]
+		#[scale_info(skip_type_params(T))]
+		pub struct PropertyInfoData<T: Config> {
+			pub id: u32,
+			pub data: BoundedVec<u8, <T as Config>::StringLimit>,
+		}
+
+		#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo)]
+		#[scale_info(skip_type_params(T))]
+		pub struct GameData<T: Config> {
+			pub difficulty: DifficultyLevel,
+			pub player: AccountIdOf<T>,
+			pub property: PropertyInfoData<T>,
+			pub guess: Option<u32>,
+		}
+
+		#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo)]
+		#[scale_info(skip_type_params(T))]
+		pub struct ListingInfo<T: Config> {
+			pub owner: AccountIdOf<T>,
+			pub collection_id: CollectionId<T>,
+			pub item_id: ItemId<T>,
+		}
+
+		#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo)]
+		#[scale_info(skip_type_params(T))]
+		pub struct OfferInfo<T: Config> {
+			pub owner: AccountIdOf<T>,
+			pub listing_id: u32,
+			pub collection_id: CollectionId<T>,
+			pub item_id: ItemId<T>,
+		}
+
+		#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo)]
+		#[scale_info(skip_type_params(T))]
+		pub struct User<T: Config> {
+			pub points: u32,
+			pub wins: u32,
+			pub losses: u32,
+			pub practise_rounds: u8,
+			pub last_played_round: u32,
+			pub next_token_request: BlockNumberFor<T>,
+			pub nfts: CollectedColors,
+		}
+
+		#[frame_support::storage_alias]
+		pub type GameProperties<T: Config> = StorageValue<
+			Pallet<T>,
+			BoundedVec<PropertyInfoData<T>, <T as Config>::MaxProperty>,
+			ValueQuery,
+		>;
+
+		#[frame_support::storage_alias]
+		pub type GameInfo<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, u32, GameData<T>, OptionQuery>;
+
+		#[frame_support::storage_alias]
+		pub type Admins<T: Config> = StorageValue<Pallet<T>, Vec<AccountIdOf<T>>, ValueQuery>;
+	}
+
+	/// Migrates the storage of the pallet from version 0 to 1.
+	///
+	/// The properties and the ongoing games of version 0 are removed, because their prices were
+	/// never committed to. The property curators add the properties again with `add_properties`.
+	/// Listings and offers are kept without a price and an expiry and their nfts move into the
+	/// escrow. The collections of the past rounds are assigned to their rounds and the
+	/// leaderboard is ranked again from the points of the users. The admins are granted the
+	/// `Registrar` role.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T>::on_chain_storage_version();
+			if on_chain_version != 0 {
+				log::info!(
+					target: LOG_TARGET,
+					"Skipping the migration to v1, the storage version is {:?}",
+					on_chain_version
+				);
+				return T::DbWeight::get().reads(1);
+			}
+			let mut reads = 1u64;
+			let mut writes = 0u64;
+
+			let properties = v0::GameProperties::<T>::get().len();
+			v0::GameProperties::<T>::kill();
+			let games = v0::GameInfo::<T>::iter_keys().count();
+			let _ = v0::GameInfo::<T>::clear(u32::MAX, None);
+			reads = reads.saturating_add(1).saturating_add(games as u64);
+			writes = writes.saturating_add(1).saturating_add(games as u64);
+			log::warn!(
+				target: LOG_TARGET,
+				"Removed {} properties and {} ongoing games without a price commitment",
+				properties,
+				games
+			);
+
+			let admins = v0::Admins::<T>::take();
+			reads.saturating_accrue(2);
+			writes.saturating_accrue(2);
+			RoleMembers::<T>::mutate(Role::Registrar, |members| {
+				for account in admins {
+					if members.contains(&account) {
+						continue;
+					}
+					if members.try_push(account.clone()).is_err() {
+						log::warn!(
+							target: LOG_TARGET,
+							"The role {:?} could not be granted to {:?}",
+							Role::Registrar,
+							account
+						);
+					}
+				}
+			});
+
+			Users::<T>::translate::<v0::User<T>, _>(|_, user| {
+				reads.saturating_inc();
+				writes.saturating_inc();
+				Some(User {
+					points: user.points,
+					wins: user.wins,
+					losses: user.losses,
+					practise_rounds: user.practise_rounds,
+					last_played_round: user.last_played_round,
+					next_token_request: user.next_token_request,
+					nfts: user.nfts,
+					moderation: ModerationState::Active,
+				})
+			});
+
+			let current_round = CurrentRound::<T>::get();
+			reads.saturating_inc();
+			for round in 1..=current_round {
+				let mut collections = BoundedVec::<CollectionId<T>, ConstU32<8>>::new();
+				for index in 0..8u32 {
+					let collection_id: CollectionId<T> =
+						round.saturating_sub(1).saturating_mul(8).saturating_add(index).into();
+					reads.saturating_inc();
+					if CollectionColor::<T>::get(collection_id) ==
+						NftColor::from_index(index as usize)
+					{
+						let _ = collections.try_push(collection_id);
+					}
+				}
+				if collections.len() < 8 {
+					log::warn!(
+						target: LOG_TARGET,
+						"Round {} has only {} collections of its colors",
+						round,
+						collections.len()
+					);
+				}
+				RoundCollections::<T>::insert(round, collections);
+				writes.saturating_inc();
+			}
+			reads.saturating_inc();
+			if RoundActive::<T>::get() && !Rounds::<T>::contains_key(current_round) {
+				Rounds::<T>::insert(
+					current_round,
+					RoundInfo {
+						start_block: <frame_system::Pallet<T>>::block_number(),
+						end_block: None,
+						closed_block: None,
+						champion: None,
+						games_played: 0,
+						nfts_minted: 0,
+						leaderboard: Default::default(),
+					},
+				);
+				writes.saturating_inc();
+			}
+
+			Listings::<T>::translate::<v0::ListingInfo<T>, _>(|_, listing| {
+				reads.saturating_accrue(3);
+				writes.saturating_accrue(3);
+				Self::escrow(&listing.owner, listing.collection_id, listing.item_id);
+				Some(ListingInfo {
+					owner: listing.owner,
+					collection_id: listing.collection_id,
+					item_id: listing.item_id,
+					price: None,
+					expiry: None,
+				})
+			});
+
+			Offers::<T>::translate::<v0::OfferInfo<T>, _>(|offer_id, offer| {
+				reads.saturating_accrue(4);
+				writes.saturating_accrue(4);
+				let indexed = Listings::<T>::contains_key(offer.listing_id) &&
+					ListingOffers::<T>::try_mutate(offer.listing_id, |offer_ids| {
+						offer_ids.try_push(offer_id)
+					})
+					.is_ok();
+				if !indexed {
+					if let Err(error) =
+						Self::return_nft(&offer.owner, offer.collection_id, offer.item_id)
+					{
+						log::warn!(
+							target: LOG_TARGET,
+							"Nft of offer {} could not be returned: {:?}",
+							offer_id,
+							error
+						);
+					}
+					return None;
+				}
+				Self::escrow(&offer.owner, offer.collection_id, offer.item_id);
+				Some(OfferInfo {
+					owner: offer.owner,
+					listing_id: offer.listing_id,
+					collection_id: offer.collection_id,
+					item_id: offer.item_id,
+					expiry: None,
+				})
+			});
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			for (account, user) in Users::<T>::iter() {
+				reads.saturating_inc();
+				writes.saturating_inc();
+				Scores::<T>::insert(
+					account,
+					Score { points: user.points, wins: user.wins, achieved: now },
+				);
+			}
+			let (leaderboard, runners_up) = Pallet::<T>::rank_scores(Scores::<T>::iter());
+			Leaderboard::<T>::put(leaderboard);
+			LeaderboardRunnersUp::<T>::put(runners_up);
+			writes.saturating_accrue(2);
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+			writes.saturating_inc();
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 0,
+				"The migration to v1 requires the storage version 0"
+			);
+			let users = Users::<T>::iter_keys().count() as u32;
+			let listings = Listings::<T>::iter_keys().count() as u32;
+			let offers = Offers::<T>::iter_keys().count() as u32;
+			Ok((users, listings, offers).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let (users, listings, offers): (u32, u32, u32) = Decode::decode(&mut &state[..])
+				.map_err(|_| "The state of the pre upgrade can not be decoded")?;
+			ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"The storage version is not 1 after the migration"
+			);
+			ensure!(!v0::GameProperties::<T>::exists(), "The properties of v0 still exist");
+			ensure!(!v0::Admins::<T>::exists(), "The admins of v0 still exist");
+			ensure!(GameInfo::<T>::iter_keys().next().is_none(), "The games of v0 still exist");
+			ensure!(Users::<T>::iter().count() as u32 == users, "Users have been lost");
+			ensure!(Listings::<T>::iter().count() as u32 == listings, "Listings have been lost");
+			ensure!(Offers::<T>::iter().count() as u32 <= offers, "Offers have been added");
+			Pallet::<T>::do_try_state()
+		}
+	}
+
+	impl<T: Config> MigrateToV1<T> {
+		/// Records the owner of a nft that the pallet account holds for a listing or an offer and
+		/// removes the nft from the collected colors of the owner.
+		fn escrow(owner: &AccountIdOf<T>, collection_id: CollectionId<T>, item_id: ItemId<T>) {
+			let points = Users::<T>::mutate(owner, |user| {
+				let Some(user) = user else { return 0 };
+				if !RoundCollections::<T>::get(user.last_played_round).contains(&collection_id) {
+					return 0;
+				}
+				let Some(color) = CollectionColor::<T>::get(collection_id) else { return 0 };
+				if user.sub_nft_color(color.clone()).is_err() {
+					return 0;
+				}
+				let points = user.subtracting_calculate_points(color);
+				user.deduct_points(points, T::PointsFloor::get())
+			});
+			EscrowDepositors::<T>::insert(
+				collection_id,
+				item_id,
+				EscrowDeposit { depositor: owner.clone(), points },
+			);
+			EscrowedNftCount::<T>::mutate(owner, |count| *count = count.saturating_add(1));
+		}
+
+		/// Returns a nft that the pallet account holds for an offer to the owner of the offer.
+		fn return_nft(
+			owner: &AccountIdOf<T>,
+			collection_id: CollectionId<T>,
+			item_id: ItemId<T>,
+		) -> DispatchResult {
+			pallet_nfts::Pallet::<T>::do_transfer(
+				collection_id.into(),
+				item_id.into(),
+				owner.clone(),
+				|_, _| Ok(()),
+			)?;
+			let pallet_origin: OriginFor<T> = RawOrigin::Signed(Pallet::<T>::account_id()).into();
+			pallet_nfts::Pallet::<T>::lock_item_transfer(
+				pallet_origin,
+				collection_id.into(),
+				item_id.into(),
+			)
+		}
+	}
+}
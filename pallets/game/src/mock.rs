@@ -3,8 +3,9 @@ use frame_support::{parameter_types, traits::AsEnsureOriginWithArg, PalletId};
 use pallet_nfts::PalletFeatures;
 use sp_core::ConstU32;
 use sp_runtime::{
-	traits::{AccountIdLookup, BlakeTwo256, IdentifyAccount, Verify},
-	BuildStorage, MultiSignature,
+	testing::TestXt,
+	traits::{AccountIdLookup, BlakeTwo256, Extrinsic as ExtrinsicT, IdentifyAccount, Verify},
+	BuildStorage, MultiSignature, Permill,
 };
 pub type BlockNumber = u64;
 pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
@@ -118,6 +119,35 @@ impl pallet_nfts::Config for Test {
 
 impl pallet_insecure_randomness_collective_flip::Config for Test {}
 
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = AccountPublic;
+	type Signature = Signature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		_public: AccountPublic,
+		_account: AccountId,
+		nonce: u32,
+	) -> Option<(RuntimeCall, <Extrinsic as ExtrinsicT>::SignaturePayload)> {
+		Some((call, (nonce.into(), ())))
+	}
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub struct MaxProperties;
 
@@ -129,12 +159,46 @@ impl sp_core::Get<u32> for MaxProperties {
 
 parameter_types! {
 	pub const GamePalletId: PalletId = PalletId(*b"py/rlxdl");
-	pub const MaxOngoingGame: u32 = 200;
-	pub const LeaderLimit: u32 = 10;
-	pub const MaxAdmin: u32 = 10;
+	pub const MaxOngoingGame: u32 = 5;
+	pub const MaxOngoingGamesPerPlayer: u32 = 3;
+	pub const LeaderLimit: u32 = 3;
+	pub const MaxRoleMembers: u32 = 10;
 	pub const RequestLimits: BlockNumber = 180;
+	pub const GameDuration: BlockNumber = 10;
+	pub const SettlementDuration: BlockNumber = 10;
+	pub const MaxScoreBands: u32 = 10;
+	pub const MaxPropertiesPerBatch: u32 = 10;
+	pub const MaxOffersPerListing: u32 = 3;
+	pub const MarketplaceFee: Permill = Permill::from_percent(5);
+	pub const AuctionDuration: BlockNumber = 10;
+	pub const AuctionExtension: BlockNumber = 3;
+	pub const MaxAuctionsPerBlock: u32 = 2;
+	pub const MaxExpiriesPerBlock: u32 = 2;
+	pub const MaxStandingOrdersPerPair: u32 = 2;
+	pub static PointsFloor: u32 = 0;
+	pub static EntryFee: u32 = 0;
+	pub const RegistrationDeposit: u32 = 5;
+	pub const MaxInviteCodesPerCall: u32 = 5;
+	pub const FaucetDripAmount: u32 = 10;
+	pub const FaucetTarget: u32 = 10;
+	pub static FaucetCap: u32 = 1_000;
+	pub const FaucetCapPeriod: BlockNumber = DAYS;
+	pub const MaxSuspensionsPerBlock: u32 = 2;
+	pub static VrfOutput: Option<sp_core::H256> = None;
+}
+
+/// VRF source whose output can be set by the tests.
+pub struct MockVrf;
+
+impl frame_support::traits::Randomness<Option<sp_core::H256>, BlockNumber> for MockVrf {
+	fn random(_subject: &[u8]) -> (Option<sp_core::H256>, BlockNumber) {
+		(VrfOutput::get(), System::block_number())
+	}
 }
 
+pub type GameVrfRandomness =
+	pallet_game::randomness::VrfRandomness<Test, MockVrf, RandomnessCollectiveFlip>;
+
 /// Configure the pallet-game in pallets/game.
 impl pallet_game::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
@@ -146,11 +210,33 @@ impl pallet_game::Config for Test {
 	type MaxProperty = MaxProperties;
 	type PalletId = GamePalletId;
 	type MaxOngoingGames = MaxOngoingGame;
+	type MaxOngoingGamesPerPlayer = MaxOngoingGamesPerPlayer;
 	type GameRandomness = RandomnessCollectiveFlip;
 	type StringLimit = ConstU32<5000>;
 	type LeaderboardLimit = LeaderLimit;
-	type MaxAdmins = MaxAdmin;
+	type MaxRoleMembers = MaxRoleMembers;
 	type RequestLimit = RequestLimits;
+	type GameDuration = GameDuration;
+	type SettlementDuration = SettlementDuration;
+	type AuthorityId = pallet_game::crypto::OracleAuthId;
+	type MaxScoreBands = MaxScoreBands;
+	type PointsFloor = PointsFloor;
+	type MaxPropertiesPerBatch = MaxPropertiesPerBatch;
+	type MaxOffersPerListing = MaxOffersPerListing;
+	type MarketplaceFee = MarketplaceFee;
+	type AuctionDuration = AuctionDuration;
+	type AuctionExtension = AuctionExtension;
+	type MaxAuctionsPerBlock = MaxAuctionsPerBlock;
+	type MaxExpiriesPerBlock = MaxExpiriesPerBlock;
+	type MaxStandingOrdersPerPair = MaxStandingOrdersPerPair;
+	type EntryFee = EntryFee;
+	type RegistrationDeposit = RegistrationDeposit;
+	type MaxInviteCodesPerCall = MaxInviteCodesPerCall;
+	type FaucetDripAmount = FaucetDripAmount;
+	type FaucetTarget = FaucetTarget;
+	type FaucetCap = FaucetCap;
+	type FaucetCapPeriod = FaucetCapPeriod;
+	type MaxSuspensionsPerBlock = MaxSuspensionsPerBlock;
 }
 
 // Build genesis storage according to the mock runtime.
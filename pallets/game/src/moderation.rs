@@ -0,0 +1,66 @@
+//! Moderation of the players.
+//!
+//! Accounts with the `PlayerModerator` role suspend players until a block or ban them. A player
+//! that is suspended or banned can not use the game until the suspension ends or a moderator
+//! reinstates the player, but can file an appeal for the moderators. Suspensions end in
+//! `on_initialize` of their last block. Bans and running suspensions are also kept per account, so
+//! that a removed player can not register again before it is reinstated or its suspension ends.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Returns true if the account is not a suspended or banned player.
+	pub fn is_active(account: &AccountIdOf<T>) -> bool {
+		Self::ensure_active(account).is_ok()
+	}
+
+	/// Checks that the account is not a suspended or banned player.
+	pub(crate) fn ensure_active(account: &AccountIdOf<T>) -> DispatchResult {
+		match Users::<T>::get(account).map(|user| user.moderation) {
+			Some(ModerationState::Suspended(until))
+				if until > <frame_system::Pallet<T>>::block_number() =>
+				Err(Error::<T>::PlayerIsSuspended.into()),
+			Some(ModerationState::Banned) => Err(Error::<T>::PlayerIsBanned.into()),
+			_ => Ok(()),
+		}
+	}
+
+	/// Sets the moderation state of a registered player and closes the open appeal of the
+	/// player.
+	pub(crate) fn set_moderation(
+		player: &AccountIdOf<T>,
+		moderation: ModerationState<BlockNumberFor<T>>,
+	) -> DispatchResult {
+		let mut user = Users::<T>::get(player).ok_or(Error::<T>::UserNotRegistered)?;
+		if let ModerationState::Suspended(until) = user.moderation {
+			SuspensionsEnding::<T>::mutate(until, |players| players.retain(|p| p != player));
+		}
+		if let ModerationState::Suspended(until) = moderation {
+			SuspensionsEnding::<T>::try_mutate(until, |players| players.try_push(player.clone()))
+				.map_err(|_| Error::<T>::TooManySuspensions)?;
+		}
+		Appeals::<T>::remove(player);
+		user.moderation = moderation;
+		Users::<T>::insert(player, user);
+		Ok(())
+	}
+
+	/// Ends the suspensions that run until the block.
+	pub(crate) fn end_suspensions(n: BlockNumberFor<T>) -> Weight {
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+		for player in SuspensionsEnding::<T>::take(n) {
+			Users::<T>::mutate(&player, |user| {
+				if let Some(user) = user {
+					user.moderation = ModerationState::Active;
+				}
+			});
+			Appeals::<T>::remove(&player);
+			SuspendedAccounts::<T>::remove(&player);
+			Self::deposit_event(Event::<T>::SuspensionEnded { player });
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 3));
+		}
+		weight
+	}
+}
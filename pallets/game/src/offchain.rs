@@ -0,0 +1,124 @@
+//! The offchain worker settles answered games with the prices of a local price feed.
+//!
+//! The price feed lives in the persistent offchain local storage of the node. For every property
+//! the feed stores a SCALE encoded [`PriceFeedEntry`] under the key returned by
+//! [`Pallet::price_feed_key`]. The entries can be written by an operator-run process through the
+//! `offchain_localStorageSet` rpc with the `PERSISTENT` storage kind.
+//!
+//! The settlement transactions are signed with an oracle key of the type [`crate::KEY_TYPE`]. The
+//! account of that key has to be granted the `Settler` role with `grant_role`.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+use frame_system::{
+	offchain::{SendSignedTransaction, Signer},
+	pallet_prelude::*,
+};
+use sp_runtime::offchain::storage::{StorageRetrievalError, StorageValueRef};
+use sp_std::vec::Vec;
+
+/// Prefix of the offchain storage keys of the price feed.
+pub const PRICE_FEED_PREFIX: &[u8] = b"pallet_game::price::";
+
+/// Prefix of the offchain storage keys that store when the settlement of a game was submitted.
+pub const SUBMITTED_PREFIX: &[u8] = b"pallet_game::submitted::";
+
+/// The price of a property in the price feed together with the salt of its commitment.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct PriceFeedEntry {
+	pub price: u64,
+	pub secret: Vec<u8>,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns the offchain storage key under which the price of a property is stored.
+	pub fn price_feed_key(property_id: u32) -> Vec<u8> {
+		let mut key = PRICE_FEED_PREFIX.to_vec();
+		key.extend(property_id.encode());
+		key
+	}
+
+	/// Returns the offchain storage key which stores when the settlement of a game was submitted.
+	pub fn submitted_key(game_id: u32) -> Vec<u8> {
+		let mut key = SUBMITTED_PREFIX.to_vec();
+		key.extend(game_id.encode());
+		key
+	}
+
+	/// Submits a signed `submit_result` transaction for every answered game whose property price
+	/// is available in the local price feed. A game is only settled once the player can no longer
+	/// answer it, as the transaction reveals the price.
+	///
+	/// A game is only submitted again once `GameDuration` blocks have passed since the last
+	/// submission, in case the previous transaction did not make it into a block.
+	pub fn settle_games_offchain(block_number: BlockNumberFor<T>) -> Result<(), &'static str> {
+		let signer = Signer::<T, T::AuthorityId>::any_account();
+		if !signer.can_sign() {
+			return Err("No oracle key available in the keystore");
+		}
+		for game_id in ActiveGames::<T>::get() {
+			let game_info = match GameInfo::<T>::get(game_id) {
+				Some(game_info)
+					if game_info.guess.is_some() &&
+						block_number >= Self::answer_deadline(&game_info) =>
+					game_info,
+				_ => continue,
+			};
+			let feed = StorageValueRef::persistent(&Self::price_feed_key(game_info.property.id));
+			let entry = match feed.get::<PriceFeedEntry>() {
+				Ok(Some(entry)) => entry,
+				Ok(None) => continue,
+				Err(_) => {
+					log::warn!(
+						target: LOG_TARGET,
+						"Price feed entry of property {} could not be decoded",
+						game_info.property.id
+					);
+					continue;
+				},
+			};
+			let secret: BoundedVec<u8, <T as Config>::StringLimit> = match entry.secret.try_into() {
+				Ok(secret) => secret,
+				Err(_) => continue,
+			};
+			if Self::property_commitment(entry.price, &secret, &game_info.property.data) !=
+				game_info.property.commitment
+			{
+				log::warn!(
+					target: LOG_TARGET,
+					"Price feed entry of property {} does not match the commitment",
+					game_info.property.id
+				);
+				continue;
+			}
+			let submitted = StorageValueRef::persistent(&Self::submitted_key(game_id));
+			let lock = submitted.mutate(
+				|last: Result<Option<BlockNumberFor<T>>, StorageRetrievalError>| match last {
+					Ok(Some(block))
+						if block_number < block.saturating_add(T::GameDuration::get()) =>
+						Err(()),
+					_ => Ok(block_number),
+				},
+			);
+			if lock.is_err() {
+				continue;
+			}
+			let price = entry.price;
+			match signer.send_signed_transaction(|_| Call::submit_result {
+				game_id,
+				price,
+				secret: secret.clone(),
+			}) {
+				Some((_, Ok(()))) =>
+					log::info!(target: LOG_TARGET, "Submitted the settlement of game {}", game_id),
+				Some((_, Err(()))) => log::error!(
+					target: LOG_TARGET,
+					"Failed to submit the settlement of game {}",
+					game_id
+				),
+				None => return Err("No oracle key available in the keystore"),
+			}
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,73 @@
+//! Standing orders of the marketplace.
+//!
+//! A standing order offers an escrowed nft for any nft of the wanted color. Orders are matched
+//! against new standing orders with the opposite pair of colors and against new listings without
+//! a price. The oldest order of a pair of colors that does not belong to the counterparty is
+//! matched first.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Returns the oldest standing order that offers `give` for `want` and does not belong to the
+	/// counterparty.
+	pub(crate) fn oldest_standing_order(
+		give: &NftColor,
+		want: &NftColor,
+		counterparty: &AccountIdOf<T>,
+	) -> Option<u32> {
+		StandingOrdersByColor::<T>::get(give, want).into_iter().find(|order_id| {
+			StandingOrders::<T>::get(order_id)
+				.map_or(false, |order_info| order_info.owner != *counterparty)
+		})
+	}
+
+	/// Swaps a new listing without a price with the oldest standing order that wants the color
+	/// of the listed nft.
+	pub(crate) fn match_listing(listing_id: u32) -> DispatchResult {
+		let listing_info = Listings::<T>::get(listing_id).ok_or(Error::<T>::ListingDoesNotExist)?;
+		let want = Self::collection_color(listing_info.collection_id)
+			.ok_or(Error::<T>::CollectionUnknown)?;
+		let matched_id = (0..8)
+			.filter_map(NftColor::from_index)
+			.filter(|give| *give != want)
+			.filter_map(|give| Self::oldest_standing_order(&give, &want, &listing_info.owner))
+			.min();
+		if let Some(order_id) = matched_id {
+			Listings::<T>::remove(listing_id);
+			Self::unschedule_listing_expiry(listing_id, listing_info.expiry);
+			Self::fill_standing_order(
+				order_id,
+				&listing_info.owner,
+				listing_info.collection_id,
+				listing_info.item_id,
+				Some(listing_id),
+			)?;
+		}
+		Ok(())
+	}
+
+	/// Closes a standing order by swapping its nft with the escrowed nft of the counterparty.
+	pub(crate) fn fill_standing_order(
+		order_id: u32,
+		counterparty: &AccountIdOf<T>,
+		collection_id: CollectionId<T>,
+		item_id: ItemId<T>,
+		listing_id: Option<u32>,
+	) -> DispatchResult {
+		let order_info =
+			StandingOrders::<T>::take(order_id).ok_or(Error::<T>::StandingOrderDoesNotExist)?;
+		StandingOrdersByColor::<T>::mutate(&order_info.give, &order_info.want, |orders| {
+			orders.retain(|id| *id != order_id)
+		});
+		Self::release_nft(counterparty, order_info.collection_id, order_info.item_id)?;
+		Self::release_nft(&order_info.owner, collection_id, item_id)?;
+		Self::deposit_event(Event::<T>::StandingOrderMatched {
+			order_id,
+			owner: order_info.owner,
+			counterparty: counterparty.clone(),
+			listing_id,
+		});
+		Ok(())
+	}
+}
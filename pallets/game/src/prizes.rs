@@ -0,0 +1,52 @@
+//! Prize pool of the game.
+//!
+//! The prize pool is held by the pallet account and is funded by the entry fees of the games and
+//! by donations. When a round closes, the places of the round leaderboard are awarded their share
+//! of the pool. The prizes are kept per round until the players claim them and their total is
+//! tracked, so that the pallet account does not hand out the funds of unclaimed prizes.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, sp_runtime::traits::Zero};
+
+impl<T: Config> Pallet<T> {
+	/// Checks that the shares of a prize distribution add up to at most the whole prize pool.
+	pub(crate) fn is_valid_distribution(shares: &[Permill]) -> bool {
+		shares
+			.iter()
+			.fold(0u32, |total, share| total.saturating_add(share.deconstruct())) <=
+			Permill::one().deconstruct()
+	}
+
+	/// Pays the entry fee of a game into the prize pool.
+	pub(crate) fn pay_entry_fee(player: &AccountIdOf<T>) -> DispatchResult {
+		let fee = T::EntryFee::get();
+		if fee.is_zero() {
+			return Ok(());
+		}
+		<T as pallet::Config>::Currency::transfer(
+			player,
+			&Self::account_id(),
+			fee,
+			ExistenceRequirement::KeepAlive,
+		)?;
+		PrizePool::<T>::mutate(|pool| *pool = pool.saturating_add(fee));
+		Ok(())
+	}
+
+	/// Awards the places of the leaderboard of a round their share of the prize pool.
+	pub(crate) fn award_prizes(round: u32, leaderboard: &LeaderboardOf<T>) {
+		let pool = Self::prize_pool();
+		let mut awarded: CurrencyBalanceOf<T> = Zero::zero();
+		for ((player, _), share) in leaderboard.iter().zip(Self::prize_distribution()) {
+			let amount = share * pool;
+			if amount.is_zero() {
+				continue;
+			}
+			RoundPrizes::<T>::insert(round, player, amount);
+			awarded = awarded.saturating_add(amount);
+			Self::deposit_event(Event::<T>::PrizeAwarded { round, player: player.clone(), amount });
+		}
+		PrizePool::<T>::put(pool.saturating_sub(awarded));
+		UnclaimedPrizes::<T>::mutate(|unclaimed| *unclaimed = unclaimed.saturating_add(awarded));
+	}
+}
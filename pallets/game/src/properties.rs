@@ -2,31 +2,77 @@ use crate::*;
 use frame_support::pallet_prelude::*;
 
 impl<T: Config> Pallet<T> {
-	pub(crate) fn create_game_properties() -> DispatchResult {
-		let new_property = PropertyInfoData {
-			id: 147229391,
-			data: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
-		};
-		GameProperties::<T>::try_append(new_property.clone())
-			.map_err(|_| Error::<T>::TooManyTest)?;
-		let new_property = PropertyInfoData {
-			id: 146480642,
-			data: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
-		};
-		GameProperties::<T>::try_append(new_property.clone())
-			.map_err(|_| Error::<T>::TooManyTest)?;
-		let new_property = PropertyInfoData {
-			id: 147031382,
-			data: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
-		};
-		GameProperties::<T>::try_append(new_property.clone())
-			.map_err(|_| Error::<T>::TooManyTest)?;
-		let new_property = PropertyInfoData {
-			id: 147031382,
-			data: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
-		};
-		GameProperties::<T>::try_append(new_property.clone())
-			.map_err(|_| Error::<T>::TooManyTest)?;
+	/// Adds a new property to the registry and makes it available for games.
+	pub fn do_add_property(property: PropertyInfoData<T>) -> DispatchResult {
+		let property_id = property.id;
+		ensure!(!GameProperties::<T>::contains_key(property_id), Error::<T>::PropertyAlreadyExists);
+		ensure!(
+			AvailablePropertyCount::<T>::get() < T::MaxProperty::get(),
+			Error::<T>::TooManyTest
+		);
+		Self::make_property_available(property_id);
+		GameProperties::<T>::insert(
+			property_id,
+			PropertyRecord { property, status: PropertyStatus::Available },
+		);
+		Self::deposit_event(Event::<T>::PropertyAdded { property_id });
+		Ok(())
+	}
+
+	/// Takes a random available property for a new game and marks it as in play.
+	pub fn take_random_property(random: u32) -> Result<PropertyInfoData<T>, DispatchError> {
+		let count = AvailablePropertyCount::<T>::get();
+		ensure!(count > 0, Error::<T>::NoProperty);
+		let property_id =
+			AvailableProperties::<T>::get(random % count).ok_or(Error::<T>::NoProperty)?;
+		Self::remove_available_property(property_id)?;
+		GameProperties::<T>::try_mutate(property_id, |record| {
+			let record = record.as_mut().ok_or(Error::<T>::NoProperty)?;
+			record.status = PropertyStatus::InPlay;
+			Ok(record.property.clone())
+		})
+	}
+
+	/// Makes a property that was in play available again. Retired properties stay retired.
+	pub fn release_property(property_id: u32) {
+		GameProperties::<T>::mutate(property_id, |record| {
+			if let Some(record) = record {
+				if record.status == PropertyStatus::InPlay {
+					Self::make_property_available(property_id);
+					record.status = PropertyStatus::Available;
+				}
+			}
+		});
+	}
+
+	/// Marks a property whose price has been revealed, so that it is never used for a game again.
+	pub fn reveal_property(property_id: u32) {
+		GameProperties::<T>::mutate(property_id, |record| {
+			if let Some(record) = record {
+				record.status = PropertyStatus::Revealed;
+			}
+		});
+	}
+
+	/// Appends a property to the availability index.
+	fn make_property_available(property_id: u32) {
+		let count = AvailablePropertyCount::<T>::get();
+		AvailableProperties::<T>::insert(count, property_id);
+		AvailablePropertySlot::<T>::insert(property_id, count);
+		AvailablePropertyCount::<T>::put(count.saturating_add(1));
+	}
+
+	/// Removes a property from the availability index by moving the last property into its slot.
+	pub(crate) fn remove_available_property(property_id: u32) -> DispatchResult {
+		let slot = AvailablePropertySlot::<T>::take(property_id).ok_or(Error::<T>::NoProperty)?;
+		let last_slot = AvailablePropertyCount::<T>::get().saturating_sub(1);
+		let last_property =
+			AvailableProperties::<T>::take(last_slot).ok_or(Error::<T>::NoProperty)?;
+		if slot != last_slot {
+			AvailableProperties::<T>::insert(slot, last_property);
+			AvailablePropertySlot::<T>::insert(last_property, slot);
+		}
+		AvailablePropertyCount::<T>::put(last_slot);
 		Ok(())
 	}
 }
@@ -0,0 +1,36 @@
+//! Randomness sources for choosing the property of a game and the color of a nft.
+
+use frame_support::traits::Randomness;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::Hash;
+use sp_std::marker::PhantomData;
+
+/// Subject prefix of the draw that chooses the property of a game.
+pub const PROPERTY_SUBJECT: &[u8] = b"realxdeal/property";
+
+/// Subject prefix of the draw that chooses the color of a won nft.
+pub const NFT_SUBJECT: &[u8] = b"realxdeal/nft";
+
+/// Randomness backed by a VRF output, e.g. `pallet_babe::ParentBlockRandomness`.
+///
+/// The output of `Source` is hashed together with the subject, so that every subject of a block
+/// gets its own value. As long as `Source` has no output yet, e.g. during the first epochs of
+/// BABE, the randomness of `Fallback` is used instead. The draws are only as secure as
+/// `Fallback` during that time, so an insecure source like `RandomnessCollectiveFlip` must only
+/// be used as the fallback on development chains.
+pub struct VrfRandomness<T, Source, Fallback>(PhantomData<(T, Source, Fallback)>);
+
+impl<T, Source, Fallback> Randomness<T::Hash, BlockNumberFor<T>>
+	for VrfRandomness<T, Source, Fallback>
+where
+	T: frame_system::Config,
+	Source: Randomness<Option<T::Hash>, BlockNumberFor<T>>,
+	Fallback: Randomness<T::Hash, BlockNumberFor<T>>,
+{
+	fn random(subject: &[u8]) -> (T::Hash, BlockNumberFor<T>) {
+		match Source::random(subject) {
+			(Some(output), block_number) => (T::Hashing::hash_of(&(output, subject)), block_number),
+			(None, _) => Fallback::random(subject),
+		}
+	}
+}
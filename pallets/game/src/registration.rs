@@ -0,0 +1,158 @@
+//! Registration of the players.
+//!
+//! Players are registered by an account with the `Registrar` role or register themselves. A
+//! player that registers itself either reserves the registration deposit or redeems a one-time
+//! invite code. Invite codes are created in bulk by the registrars as hashes of the code and the
+//! invited account, so that the codes are only revealed when they are redeemed and a revealed code
+//! can not be redeemed by another account.
+//!
+//! A player unregisters itself, which keeps its game nfts, or is deregistered by itself or by a
+//! registrar, which burns its game nfts. Both remove the data of the player, including its places
+//! and scores in the rounds it scored in, and return the deposit. A suspended or banned player
+//! stays suspended or banned after its removal and can not register again until the suspension
+//! ends or a moderator reinstates it.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*, sp_runtime::traits::Hash, traits::tokens::nonfungibles_v2::InspectEnumerable,
+};
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+	/// Returns the hash under which the invite code of an account is stored.
+	pub fn invite_code_hash(code: &[u8], account: &AccountIdOf<T>) -> T::Hash {
+		T::Hashing::hash_of(&(code, account))
+	}
+
+	/// Adds a new player with the initial 50 points. Banned accounts and suspended accounts can
+	/// not register again.
+	pub(crate) fn do_register(player: AccountIdOf<T>) -> DispatchResult {
+		ensure!(!Users::<T>::contains_key(&player), Error::<T>::PlayerAlreadyRegistered);
+		ensure!(!BannedAccounts::<T>::contains_key(&player), Error::<T>::PlayerIsBanned);
+		ensure!(!SuspendedAccounts::<T>::contains_key(&player), Error::<T>::PlayerIsSuspended);
+		let current_block_number = <frame_system::Pallet<T>>::block_number();
+		let next_request = current_block_number.saturating_add(<T as Config>::RequestLimit::get());
+		let user = User {
+			points: 50,
+			wins: Default::default(),
+			losses: Default::default(),
+			practise_rounds: Default::default(),
+			last_played_round: Default::default(),
+			next_token_request: next_request,
+			nfts: CollectedColors::default(),
+			moderation: ModerationState::Active,
+		};
+		Users::<T>::insert(player.clone(), user);
+		frame_system::Pallet::<T>::inc_providers(&player);
+		Self::deposit_event(Event::<T>::NewPlayerRegistered { player });
+		Ok(())
+	}
+
+	/// Returns the nfts of the game collections that the account owns, but not more than
+	/// `limit`.
+	pub fn game_nfts_of(
+		account: &AccountIdOf<T>,
+		limit: usize,
+	) -> Vec<(CollectionId<T>, ItemId<T>)> {
+		pallet_nfts::Pallet::<T>::owned(account)
+			.map(|(collection_id, item_id)| {
+				(CollectionId::<T>::from(collection_id), ItemId::<T>::from(item_id))
+			})
+			.filter(|(collection_id, _)| Self::collection_color(*collection_id).is_some())
+			.take(limit)
+			.collect()
+	}
+
+	/// Removes a player together with its scores and returns its registration deposit. The game
+	/// nfts of the player are kept.
+	pub(crate) fn do_unregister(player: AccountIdOf<T>, max_rounds: u32) -> DispatchResult {
+		Self::ensure_removable(&player)?;
+		let rounds = Self::rounds_of(&player, max_rounds)?;
+		let deposit = Self::remove_player(&player, rounds)?;
+		Self::deposit_event(Event::<T>::PlayerUnregistered { player, deposit });
+		Ok(())
+	}
+
+	/// Removes a player together with its scores, burns its game nfts and returns its
+	/// registration deposit.
+	pub(crate) fn do_deregister(
+		player: AccountIdOf<T>,
+		max_nfts: u32,
+		max_rounds: u32,
+	) -> DispatchResult {
+		Self::ensure_removable(&player)?;
+		let rounds = Self::rounds_of(&player, max_rounds)?;
+		let nfts = Self::game_nfts_of(&player, (max_nfts as usize).saturating_add(1));
+		ensure!(nfts.len() <= max_nfts as usize, Error::<T>::TooManyNfts);
+		for (collection_id, item_id) in &nfts {
+			pallet_nfts::Pallet::<T>::do_burn((*collection_id).into(), (*item_id).into(), |_| {
+				Ok(())
+			})?;
+		}
+		let deposit = Self::remove_player(&player, rounds)?;
+		Self::deposit_event(Event::<T>::PlayerDeregistered {
+			player,
+			deposit,
+			burned_nfts: nfts.len() as u32,
+		});
+		Ok(())
+	}
+
+	/// Checks that a player is registered and has no ongoing games, no nfts on the marketplace
+	/// and no highest bids.
+	fn ensure_removable(player: &AccountIdOf<T>) -> DispatchResult {
+		ensure!(Users::<T>::contains_key(player), Error::<T>::UserNotRegistered);
+		ensure!(PlayerGames::<T>::get(player).is_empty(), Error::<T>::PlayerHasOngoingGames);
+		ensure!(EscrowedNftCount::<T>::get(player) == 0, Error::<T>::PlayerHasOpenMarketItems);
+		ensure!(HighestBidCount::<T>::get(player) == 0, Error::<T>::PlayerHasOpenBids);
+		Ok(())
+	}
+
+	/// Returns the rounds in which a player scored or became the champion, if there are not more
+	/// than `max_rounds`.
+	fn rounds_of(player: &AccountIdOf<T>, max_rounds: u32) -> Result<Vec<u32>, DispatchError> {
+		let rounds: Vec<u32> = PlayerRounds::<T>::iter_key_prefix(player)
+			.take((max_rounds as usize).saturating_add(1))
+			.collect();
+		ensure!(rounds.len() <= max_rounds as usize, Error::<T>::TooManyRounds);
+		Ok(rounds)
+	}
+
+	/// Removes the data of a player, its places and scores in the given rounds and returns its
+	/// registration deposit. The ban of a banned player is kept in the banned accounts and the
+	/// suspension of a suspended player in the suspended accounts.
+	fn remove_player(
+		player: &AccountIdOf<T>,
+		rounds: Vec<u32>,
+	) -> Result<CurrencyBalanceOf<T>, DispatchError> {
+		let user = Users::<T>::take(player).ok_or(Error::<T>::UserNotRegistered)?;
+		if let ModerationState::Suspended(until) = user.moderation {
+			if until > <frame_system::Pallet<T>>::block_number() {
+				SuspendedAccounts::<T>::insert(player, until);
+			}
+		}
+		Appeals::<T>::remove(player);
+		EscrowedNftCount::<T>::remove(player);
+		HighestBidCount::<T>::remove(player);
+		Self::remove_from_leaderboards(player);
+		for round in rounds {
+			PlayerRounds::<T>::remove(player, round);
+			RoundScores::<T>::remove(round, player);
+			if RoundChampion::<T>::get(round).as_ref() == Some(player) {
+				RoundChampion::<T>::remove(round);
+			}
+			Rounds::<T>::mutate(round, |round_info| {
+				if let Some(round_info) = round_info {
+					round_info.leaderboard.retain(|(id, _)| id != player);
+					if round_info.champion.as_ref() == Some(player) {
+						round_info.champion = None;
+					}
+				}
+			});
+		}
+		let deposit = RegistrationDeposits::<T>::take(player).unwrap_or_default();
+		<T as pallet::Config>::Currency::unreserve(player, deposit);
+		frame_system::Pallet::<T>::dec_providers(player)?;
+		Ok(deposit)
+	}
+}
@@ -0,0 +1,44 @@
+//! Roles of the game.
+//!
+//! The privileged calls of the game are split into areas that are each gated by a role. The
+//! `GameOrigin` can call every privileged call and grants and revokes the roles. A signed account
+//! can call the privileged calls of the roles it has been granted, unless it is a suspended or
+//! banned player.
+
+use crate::*;
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Returns true if the account has been granted the role.
+	pub fn has_role(account: &AccountIdOf<T>, role: Role) -> bool {
+		RoleMembers::<T>::get(role).contains(account)
+	}
+
+	/// Checks that the origin is the `GameOrigin` or an active signed account with the role.
+	/// Returns the signed account.
+	pub(crate) fn ensure_role(
+		origin: OriginFor<T>,
+		role: Role,
+	) -> Result<Option<AccountIdOf<T>>, DispatchError> {
+		match T::GameOrigin::try_origin(origin) {
+			Ok(_) => Ok(None),
+			Err(origin) => {
+				let signer = ensure_signed(origin)?;
+				ensure!(Self::has_role(&signer, role), Error::<T>::NoPermission);
+				Self::ensure_active(&signer)?;
+				Ok(Some(signer))
+			},
+		}
+	}
+
+	/// Grants the role to the account.
+	pub(crate) fn do_grant_role(role: Role, account: AccountIdOf<T>) -> DispatchResult {
+		RoleMembers::<T>::try_mutate(&role, |members| {
+			ensure!(!members.contains(&account), Error::<T>::RoleAlreadyGranted);
+			members.try_push(account.clone()).map_err(|_| Error::<T>::TooManyRoleMembers)
+		})?;
+		Self::deposit_event(Event::<T>::RoleGranted { role, account });
+		Ok(())
+	}
+}
@@ -1,9 +1,72 @@
-use crate::{mock::*, Error, Event, PropertyInfoData};
+use crate::{
+	mock::*, offchain::PriceFeedEntry, Error, Event, ListingDetails, ModerationState, NftColor,
+	PropertyInfoData, PropertyMetadata, PropertyRecord, PropertyStatus, PropertyType, Role,
+	RoundInfo, Score,
+};
+use codec::{Decode, Encode};
 use frame_support::{
 	assert_noop, assert_ok,
-	traits::{OnFinalize, OnInitialize},
+	pallet_prelude::ConstU32,
+	traits::{
+		Currency, OffchainWorker, OnFinalize, OnIdle, OnInitialize, Randomness, ReservableCurrency,
+	},
+	weights::Weight,
+	BoundedVec,
+};
+use sp_core::{
+	offchain::{testing, OffchainDbExt, OffchainWorkerExt, StorageKind, TransactionPoolExt},
+	sr25519, H256,
 };
-use sp_runtime::{traits::BadOrigin, DispatchError, ModuleError};
+use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+use sp_runtime::{
+	traits::{BadOrigin, IdentifyAccount},
+	BuildStorage, DispatchError, ModuleError, MultiSigner, Permill,
+};
+
+/// Adds the properties of the tests. Every property has the price 220_000 and its own data, the
+/// salt of the commitments is the secret that the tests reveal. A settled game retires its
+/// property, so there are enough properties for ten settled games.
+fn add_test_properties() {
+	let properties = [
+		(147229391, "London", PropertyType::Flat, 2, 1_704_067_200),
+		(147100001, "Liverpool", PropertyType::Terraced, 2, 1_704_153_600),
+		(147100002, "Sheffield", PropertyType::SemiDetached, 3, 1_704_240_000),
+		(147100003, "Newcastle", PropertyType::Flat, 1, 1_704_326_400),
+		(147100004, "Nottingham", PropertyType::Bungalow, 2, 1_704_412_800),
+		(147100005, "Glasgow", PropertyType::Flat, 2, 1_704_499_200),
+		(147100006, "Cardiff", PropertyType::Terraced, 3, 1_704_585_600),
+		(146480642, "Manchester", PropertyType::Terraced, 3, 1_706_745_600),
+		(147031382, "Bristol", PropertyType::SemiDetached, 3, 1_709_251_200),
+		(147095938, "Leeds", PropertyType::Detached, 4, 1_711_929_600),
+	]
+	.into_iter()
+	.map(|(id, region, property_type, bedrooms, listing_date)| {
+		let data: BoundedVec<u8, ConstU32<5000>> =
+			format!("realxdeal test property {}", id).into_bytes().try_into().unwrap();
+		PropertyInfoData {
+			id,
+			commitment: GameModule::property_commitment(
+				220_000,
+				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes(),
+				&data,
+			),
+			data,
+			metadata: PropertyMetadata {
+				region: region.as_bytes().to_vec().try_into().unwrap(),
+				property_type,
+				bedrooms,
+				listing_date,
+			},
+		}
+	})
+	.collect::<Vec<_>>();
+	assert_ok!(GameModule::add_properties(RuntimeOrigin::root(), properties.try_into().unwrap()));
+}
+
+fn setup_game_with_properties() {
+	assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+	add_test_properties();
+}
 
 fn practise_round(player: AccountId, game_id: u32) {
 	assert_ok!(GameModule::play_game(
@@ -14,13 +77,98 @@ fn practise_round(player: AccountId, game_id: u32) {
 	System::assert_last_event(Event::AnswerSubmitted { player, game_id, guess: 220_000 }.into());
 	assert_ok!(GameModule::check_result(
 		RuntimeOrigin::root(),
+		game_id,
 		220_000,
+		"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+	));
+}
+
+fn win_nft(player: AccountId, game_id: u32) {
+	assert_ok!(GameModule::play_game(
+		RuntimeOrigin::signed(player.clone()),
+		crate::DifficultyLevel::Player,
+	));
+	assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed(player), 220_000, game_id));
+	assert_ok!(GameModule::check_result(
+		RuntimeOrigin::root(),
 		game_id,
 		220_000,
 		"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
 	));
 }
 
+/// Sets up the game and registers the players `[0; 32]` up to `[count - 1; 32]` with the
+/// registrar `[4; 32]`.
+fn register_players(count: u8) {
+	setup_game_with_properties();
+	assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+	for player in 0..count {
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[player; 32].into()
+		));
+	}
+}
+
+/// Registers the players like `register_players`, lets every player play a practise round with
+/// the index of the player as game id and gives every player a free balance of 1_000.
+fn setup_players(count: u8) {
+	register_players(count);
+	for player in 0..count {
+		practise_round([player; 32].into(), player as u32);
+		Balances::make_free_balance_be(&[player; 32].into(), 1_000);
+	}
+}
+
+fn give_nft(player: AccountId, color_index: u32) -> (u32, u32) {
+	let collection_id =
+		GameModule::round_collection(GameModule::current_round(), color_index).unwrap();
+	let item_id = crate::NextColorId::<Test>::get(collection_id);
+	crate::NextColorId::<Test>::insert(collection_id, item_id + 1);
+	assert_ok!(Nfts::do_mint(
+		collection_id,
+		item_id,
+		Some(GameModule::account_id()),
+		GameModule::account_id(),
+		GameModule::default_item_config(),
+		|_, _| Ok(())
+	));
+	assert_ok!(GameModule::release_nft(&player, collection_id, item_id));
+	(collection_id, item_id)
+}
+
+fn points_of(
+	leaderboard: BoundedVec<(AccountId, Score<BlockNumber>), LeaderLimit>,
+) -> Vec<(AccountId, u32)> {
+	leaderboard
+		.into_iter()
+		.map(|(account, score)| (account, score.points))
+		.collect()
+}
+
+fn property_metadata() -> PropertyMetadata<Test> {
+	PropertyMetadata {
+		region: "Liverpool".as_bytes().to_vec().try_into().unwrap(),
+		property_type: PropertyType::Terraced,
+		bedrooms: 2,
+		listing_date: 1_714_521_600,
+	}
+}
+
+fn offchain_test_ext(
+	pool: testing::TestTransactionPoolExt,
+) -> (sp_io::TestExternalities, sr25519::Public) {
+	let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+	let keystore = MemoryKeystore::new();
+	let public = keystore.sr25519_generate_new(crate::KEY_TYPE, Some("//Oracle")).unwrap();
+	let mut t = new_test_ext();
+	t.register_extension(OffchainWorkerExt::new(offchain.clone()));
+	t.register_extension(OffchainDbExt::new(offchain));
+	t.register_extension(TransactionPoolExt::new(pool));
+	t.register_extension(KeystoreExt::new(keystore));
+	(t, public)
+}
+
 fn run_to_block(n: u64) {
 	while System::block_number() < n {
 		GameModule::on_finalize(System::block_number());
@@ -37,6 +185,9 @@ fn setup_game_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		assert_eq!(GameModule::current_round(), 1);
+		assert_eq!(GameModule::round_active(), true);
+		assert_eq!(GameModule::available_property_count(), 0);
 	});
 }
 
@@ -44,852 +195,3999 @@ fn setup_game_works() {
 fn setup_game_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_noop!(GameModule::setup_game(RuntimeOrigin::signed([0; 32].into())), BadOrigin);
+		assert_noop!(
+			GameModule::setup_game(RuntimeOrigin::signed([0; 32].into())),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		assert_noop!(
+			GameModule::setup_game(RuntimeOrigin::root()),
+			Error::<Test>::RoundAlreadyActive
+		);
+		assert_eq!(GameModule::current_round(), 1);
 	});
 }
 
 #[test]
-fn add_to_admins_works() {
+fn grant_role_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [0; 32].into()));
-		assert_eq!(GameModule::admins().len(), 1);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [0; 32].into()));
+		assert_eq!(GameModule::role_members(Role::Registrar).len(), 1);
+		assert!(GameModule::has_role(&[0; 32].into(), Role::Registrar));
+		assert!(!GameModule::has_role(&[0; 32].into(), Role::Settler));
+		System::assert_last_event(
+			Event::RoleGranted { role: Role::Registrar, account: [0; 32].into() }.into(),
+		);
 	});
 }
 
 #[test]
-fn add_to_admins_fails() {
+fn grant_role_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		assert_noop!(
-			GameModule::add_to_admins(RuntimeOrigin::signed([0; 32].into()), [0; 32].into()),
+			GameModule::grant_role(
+				RuntimeOrigin::signed([0; 32].into()),
+				Role::Registrar,
+				[0; 32].into()
+			),
 			BadOrigin
 		);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [0; 32].into()));
-		assert_eq!(GameModule::admins().len(), 1);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [0; 32].into()));
+		assert_noop!(
+			GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [0; 32].into()),
+			Error::<Test>::RoleAlreadyGranted
+		);
+		for account in 1..10 {
+			assert_ok!(GameModule::grant_role(
+				RuntimeOrigin::root(),
+				Role::Registrar,
+				[account; 32].into()
+			));
+		}
 		assert_noop!(
-			GameModule::add_to_admins(RuntimeOrigin::root(), [0; 32].into()),
-			Error::<Test>::AccountAlreadyAdmin
+			GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [10; 32].into()),
+			Error::<Test>::TooManyRoleMembers
 		);
 	});
 }
 
 #[test]
-fn remove_admins_works() {
+fn revoke_role_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [0; 32].into()));
-		assert_eq!(GameModule::admins().len(), 1);
-		assert_ok!(GameModule::remove_from_admins(RuntimeOrigin::root(), [0; 32].into()));
-		assert_eq!(GameModule::admins().len(), 0);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [0; 32].into()));
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Settler, [0; 32].into()));
+		assert_ok!(GameModule::revoke_role(RuntimeOrigin::root(), Role::Registrar, [0; 32].into()));
+		assert_eq!(GameModule::role_members(Role::Registrar).len(), 0);
+		assert!(GameModule::has_role(&[0; 32].into(), Role::Settler));
+		System::assert_last_event(
+			Event::RoleRevoked { role: Role::Registrar, account: [0; 32].into() }.into(),
+		);
+		assert_noop!(
+			GameModule::register_user(RuntimeOrigin::signed([0; 32].into()), [1; 32].into()),
+			Error::<Test>::NoPermission
+		);
 	});
 }
 
 #[test]
-fn remove_admins_fails() {
+fn revoke_role_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		assert_noop!(
-			GameModule::remove_from_admins(RuntimeOrigin::root(), [0; 32].into()),
-			Error::<Test>::NotAdmin
+			GameModule::revoke_role(RuntimeOrigin::root(), Role::Registrar, [0; 32].into()),
+			Error::<Test>::RoleNotGranted
+		);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [0; 32].into()));
+		assert_noop!(
+			GameModule::revoke_role(RuntimeOrigin::root(), Role::Settler, [0; 32].into()),
+			Error::<Test>::RoleNotGranted
 		);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [0; 32].into()));
-		assert_eq!(GameModule::admins().len(), 1);
 		assert_noop!(
-			GameModule::remove_from_admins(RuntimeOrigin::signed([0; 32].into()), [0; 32].into()),
+			GameModule::revoke_role(
+				RuntimeOrigin::signed([0; 32].into()),
+				Role::Registrar,
+				[0; 32].into()
+			),
 			BadOrigin
 		);
 	});
 }
 
 #[test]
-fn play_game_works() {
+fn register_with_deposit_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
-		assert_ok!(GameModule::register_user(
+		setup_game_with_properties();
+		Balances::make_free_balance_be(&[0; 32].into(), 20);
+		assert_ok!(GameModule::register(RuntimeOrigin::signed([0; 32].into()), None));
+		System::assert_last_event(Event::NewPlayerRegistered { player: [0; 32].into() }.into());
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 50);
+		assert_eq!(GameModule::registration_deposits::<AccountId>([0; 32].into()), Some(5));
+		assert_eq!(Balances::reserved_balance(&([0; 32].into())), 5);
+		assert_eq!(Balances::free_balance(&([0; 32].into())), 15);
+	});
+}
+
+#[test]
+fn register_with_invite_code_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		let code_hashes: BoundedVec<_, MaxInviteCodesPerCall> = vec![
+			GameModule::invite_code_hash("first".as_bytes(), &[0; 32].into()),
+			GameModule::invite_code_hash("second".as_bytes(), &[1; 32].into()),
+		]
+		.try_into()
+		.unwrap();
+		assert_ok!(GameModule::create_invite_codes(
 			RuntimeOrigin::signed([4; 32].into()),
-			[0; 32].into()
+			code_hashes
 		));
-		assert_eq!(Balances::free_balance(&([0; 32].into())), 10);
-		assert_ok!(GameModule::give_points(RuntimeOrigin::root(), [0; 32].into(), 100));
-		practise_round([0; 32].into(), 0);
-		assert_ok!(GameModule::play_game(
+		System::assert_last_event(Event::InviteCodesCreated { amount: 2 }.into());
+		Balances::make_free_balance_be(&[0; 32].into(), 1);
+		assert_ok!(GameModule::register(
 			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
+			Some("first".as_bytes().to_vec().try_into().unwrap())
+		));
+		assert!(GameModule::users::<AccountId>([0; 32].into()).is_some());
+		assert_eq!(GameModule::registration_deposits::<AccountId>([0; 32].into()), None);
+		assert_eq!(Balances::reserved_balance(&([0; 32].into())), 0);
+		assert_eq!(
+			GameModule::invite_codes(GameModule::invite_code_hash(
+				"first".as_bytes(),
+				&[0; 32].into()
+			)),
+			None
+		);
+		assert!(GameModule::invite_codes(GameModule::invite_code_hash(
+			"second".as_bytes(),
+			&[1; 32].into()
+		))
+		.is_some());
+		Balances::make_free_balance_be(&[1; 32].into(), 20);
+		assert_noop!(
+			GameModule::register(
+				RuntimeOrigin::signed([1; 32].into()),
+				Some("first".as_bytes().to_vec().try_into().unwrap())
+			),
+			Error::<Test>::InvalidInviteCode
+		);
+		Balances::make_free_balance_be(&[2; 32].into(), 20);
+		assert_noop!(
+			GameModule::register(
+				RuntimeOrigin::signed([2; 32].into()),
+				Some("second".as_bytes().to_vec().try_into().unwrap())
+			),
+			Error::<Test>::InvalidInviteCode
+		);
+		assert_ok!(GameModule::register(
+			RuntimeOrigin::signed([1; 32].into()),
+			Some("second".as_bytes().to_vec().try_into().unwrap())
 		));
-		assert_eq!(GameModule::game_info(1).unwrap().property.id, 147229391);
+		assert_eq!(Balances::reserved_balance(&([1; 32].into())), 0);
 	});
 }
 
 #[test]
-fn play_game_fails() {
+fn register_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
+		setup_game_with_properties();
 		assert_noop!(
-			GameModule::play_game(
+			GameModule::register(RuntimeOrigin::signed([0; 32].into()), None),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()));
+		assert_noop!(
+			GameModule::register(RuntimeOrigin::signed([0; 32].into()), None),
+			Error::<Test>::PlayerAlreadyRegistered
+		);
+		let code_hashes: BoundedVec<_, MaxInviteCodesPerCall> =
+			vec![GameModule::invite_code_hash("code".as_bytes(), &[1; 32].into())]
+				.try_into()
+				.unwrap();
+		assert_noop!(
+			GameModule::create_invite_codes(
 				RuntimeOrigin::signed([0; 32].into()),
-				crate::DifficultyLevel::Player,
+				code_hashes.clone()
 			),
-			Error::<Test>::UserNotRegistered
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(GameModule::create_invite_codes(RuntimeOrigin::root(), code_hashes.clone()));
+		assert_noop!(
+			GameModule::create_invite_codes(RuntimeOrigin::root(), code_hashes),
+			Error::<Test>::InviteCodeAlreadyExists
+		);
+		Balances::make_free_balance_be(&[1; 32].into(), 20);
+		assert_noop!(
+			GameModule::register(
+				RuntimeOrigin::signed([1; 32].into()),
+				Some("other".as_bytes().to_vec().try_into().unwrap())
+			),
+			Error::<Test>::InvalidInviteCode
 		);
 	});
 }
 
 #[test]
-fn play_game_fails_no_active_round() {
+fn unregister_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
-		assert_ok!(GameModule::register_user(
-			RuntimeOrigin::signed([4; 32].into()),
-			[0; 32].into()
-		));
-		assert_noop!(
-			GameModule::play_game(
-				RuntimeOrigin::signed([0; 32].into()),
-				crate::DifficultyLevel::Practice,
-			),
-			Error::<Test>::NoActiveRound
+		setup_game_with_properties();
+		Balances::make_free_balance_be(&[0; 32].into(), 20);
+		assert_ok!(GameModule::register(RuntimeOrigin::signed([0; 32].into()), None));
+		practise_round([0; 32].into(), 0);
+		assert_eq!(GameModule::leaderboard().len(), 1);
+		assert_eq!(GameModule::round_leaderboard().len(), 1);
+		assert_ok!(GameModule::unregister(RuntimeOrigin::signed([0; 32].into()), 1));
+		System::assert_last_event(
+			Event::PlayerUnregistered { player: [0; 32].into(), deposit: 5 }.into(),
+		);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()), None);
+		assert_eq!(GameModule::registration_deposits::<AccountId>([0; 32].into()), None);
+		assert_eq!(Balances::reserved_balance(&([0; 32].into())), 0);
+		assert_eq!(Balances::free_balance(&([0; 32].into())), 20);
+		assert_eq!(GameModule::leaderboard().len(), 0);
+		assert_eq!(GameModule::round_leaderboard().len(), 0);
+		assert_eq!(GameModule::round_scores(1, AccountId::from([0; 32])).points, 0);
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [1; 32].into()));
+		give_nft([1; 32].into(), 0);
+		assert_ok!(GameModule::unregister(RuntimeOrigin::signed([1; 32].into()), 1));
+		System::assert_last_event(
+			Event::PlayerUnregistered { player: [1; 32].into(), deposit: 0 }.into(),
 		);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [1; 32].into());
+		assert_ok!(GameModule::register(RuntimeOrigin::signed([0; 32].into()), None));
 	});
 }
 
 #[test]
-fn play_game_fails_not_enough_points() {
+fn unregister_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
-		assert_ok!(GameModule::register_user(
-			RuntimeOrigin::signed([4; 32].into()),
-			[0; 32].into()
-		));
-		practise_round([0; 32].into(), 0);
-		assert_ok!(GameModule::play_game(
-			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
-		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 10, 1));
+		setup_game_with_properties();
+		assert_noop!(
+			GameModule::unregister(RuntimeOrigin::signed([0; 32].into()), 1),
+			Error::<Test>::UserNotRegistered
+		);
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()));
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
+			crate::DifficultyLevel::Practice,
 		));
+		assert_noop!(
+			GameModule::unregister(RuntimeOrigin::signed([0; 32].into()), 1),
+			Error::<Test>::PlayerHasOngoingGames
+		);
 	});
 }
 
 #[test]
-fn submit_answer_works() {
+fn deregister_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
-		assert_ok!(GameModule::register_user(
-			RuntimeOrigin::signed([4; 32].into()),
-			[0; 32].into()
-		));
+		setup_game_with_properties();
+		Balances::make_free_balance_be(&[0; 32].into(), 20);
+		assert_ok!(GameModule::register(RuntimeOrigin::signed([0; 32].into()), None));
 		practise_round([0; 32].into(), 0);
-		assert_ok!(GameModule::play_game(
+		assert_eq!(GameModule::leaderboard().len(), 1);
+		assert_eq!(GameModule::round_leaderboard().len(), 1);
+		assert_ok!(GameModule::deregister(
 			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
+			[0; 32].into(),
+			0,
+			1
 		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 223_000, 1));
 		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 223_000 }.into(),
+			Event::PlayerDeregistered { player: [0; 32].into(), deposit: 5, burned_nfts: 0 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			223_000,
-			1,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
-		System::assert_last_event(Event::ResultChecked { game_id: 1, secret: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(), points: 25, won: true, nft_received: false }.into());
-		assert_eq!(GameModule::game_info(0).is_none(), true);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 80);
-		assert_ok!(GameModule::play_game(
-			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()), None);
+		assert_eq!(GameModule::registration_deposits::<AccountId>([0; 32].into()), None);
+		assert_eq!(Balances::reserved_balance(&([0; 32].into())), 0);
+		assert_eq!(Balances::free_balance(&([0; 32].into())), 20);
+		assert_eq!(GameModule::leaderboard().len(), 0);
+		assert_eq!(GameModule::round_leaderboard().len(), 0);
+		assert_eq!(GameModule::round_scores(1, AccountId::from([0; 32])).points, 0);
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(GameModule::deregister(
+			RuntimeOrigin::signed([1; 32].into()),
+			[1; 32].into(),
+			0,
+			1
 		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 2));
 		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 2, guess: 220_000 }.into(),
+			Event::PlayerDeregistered { player: [1; 32].into(), deposit: 0, burned_nfts: 0 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			2,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
-		System::assert_last_event(Event::ResultChecked { game_id: 2, secret: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(), points: 100, won: true, nft_received: true }.into());
-		assert_eq!(GameModule::game_info(1).is_none(), true);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 180);
-		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xorange, 1);
-		assert_ok!(GameModule::play_game(
+		assert_ok!(GameModule::register(RuntimeOrigin::signed([0; 32].into()), None));
+	});
+}
+
+#[test]
+fn deregister_burns_game_nfts() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()));
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [1; 32].into()));
+		give_nft([0; 32].into(), 0);
+		give_nft([0; 32].into(), 1);
+		give_nft([1; 32].into(), 2);
+		assert_noop!(
+			GameModule::deregister(RuntimeOrigin::signed([0; 32].into()), [0; 32].into(), 1, 1),
+			Error::<Test>::TooManyNfts
+		);
+		assert_ok!(GameModule::deregister(
 			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
+			[0; 32].into(),
+			2,
+			1
 		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 0, 3));
 		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 3, guess: 0 }.into(),
+			Event::PlayerDeregistered { player: [0; 32].into(), deposit: 0, burned_nfts: 2 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			0,
-			3,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
-		for x in 4..8 {
-			assert_ok!(GameModule::play_game(
-				RuntimeOrigin::signed([0; 32].into()),
-				crate::DifficultyLevel::Player,
-			));
-			assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, x));
-			assert_ok!(GameModule::check_result(
-				RuntimeOrigin::root(),
-				220_000,
-				x,
-				220_000,
-				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-			));
-		}
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xorange, 5);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 835);
+		assert_eq!(Nfts::owner(0, 0), None);
+		assert_eq!(Nfts::owner(1, 0), None);
+		assert_eq!(Nfts::owner(2, 0).unwrap(), [1; 32].into());
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()), None);
+		assert_eq!(points_of(GameModule::leaderboard()), vec![([1; 32].into(), 150)]);
+		assert_ok!(GameModule::do_try_state());
 	});
 }
 
 #[test]
-fn submit_answer_works_with_high_difference() {
+fn deregister_by_registrar_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
-		assert_ok!(GameModule::register_user(
-			RuntimeOrigin::signed([4; 32].into()),
-			[0; 32].into()
-		));
-		practise_round([0; 32].into(), 0);
-		assert_ok!(GameModule::play_game(
-			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
-		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 2_000, 1));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 2_000 }.into(),
+		setup_game_with_properties();
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()));
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [1; 32].into()));
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [2; 32].into()));
+		give_nft([0; 32].into(), 0);
+		assert_noop!(
+			GameModule::deregister(RuntimeOrigin::signed([1; 32].into()), [0; 32].into(), 1, 1),
+			Error::<Test>::NoPermission
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			2_000,
+		assert_ok!(GameModule::deregister(
+			RuntimeOrigin::signed([2; 32].into()),
+			[0; 32].into(),
 			1,
-			220_000_000_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+			1
 		));
-		System::assert_last_event(Event::ResultChecked { game_id: 1, secret: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(), points: 25, won: false, nft_received: false }.into());
-		assert_eq!(GameModule::game_info(0).is_none(), true);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 30);
+		assert_eq!(Nfts::owner(0, 0), None);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()), None);
+		assert_ok!(GameModule::deregister(RuntimeOrigin::root(), [1; 32].into(), 0, 1));
+		assert_eq!(GameModule::users::<AccountId>([1; 32].into()), None);
 	});
 }
 
-/* #[test]
-fn game_expires_works() {
+#[test]
+fn deregister_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
-		assert_ok!(GameModule::register_user(
-			RuntimeOrigin::signed([4; 32].into()),
-			[0; 32].into()
-		));
-		practise_round([0; 32].into(), 0);
-		assert_ok!(GameModule::play_game(
-			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
-		));
-		run_to_block(20);
-		System::assert_last_event(Event::NoAnswer { game_id: 1, points: 25 }.into());
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 30);
+		setup_game_with_properties();
+		assert_noop!(
+			GameModule::deregister(RuntimeOrigin::signed([0; 32].into()), [0; 32].into(), 0, 1),
+			Error::<Test>::UserNotRegistered
+		);
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()));
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
+			crate::DifficultyLevel::Practice,
+		));
+		assert_noop!(
+			GameModule::deregister(RuntimeOrigin::signed([0; 32].into()), [0; 32].into(), 0, 1),
+			Error::<Test>::PlayerHasOngoingGames
+		);
+	});
+}
+
+#[test]
+fn deregister_fails_with_open_market_items() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()));
+		give_nft([0; 32].into(), 0);
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None));
+		assert_eq!(GameModule::escrowed_nft_count::<AccountId>([0; 32].into()), 1);
+		assert_eq!(
+			GameModule::escrow_depositors(0, 0).map(|deposit| deposit.depositor),
+			Some([0; 32].into())
+		);
+		assert_noop!(
+			GameModule::deregister(RuntimeOrigin::signed([0; 32].into()), [0; 32].into(), 1, 1),
+			Error::<Test>::PlayerHasOpenMarketItems
+		);
+		assert_ok!(GameModule::delist_nft(RuntimeOrigin::signed([0; 32].into()), 0));
+		assert_eq!(GameModule::escrowed_nft_count::<AccountId>([0; 32].into()), 0);
+		assert_eq!(GameModule::escrow_depositors(0, 0), None);
+		assert_ok!(GameModule::do_try_state());
+		assert_ok!(GameModule::deregister(
+			RuntimeOrigin::signed([0; 32].into()),
+			[0; 32].into(),
+			1,
+			1
+		));
+		assert_eq!(Nfts::owner(0, 0), None);
+	});
+}
+
+#[test]
+fn deregister_fails_with_open_bids() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_players(2);
+		win_nft([0; 32].into(), 2);
+		assert_ok!(GameModule::create_auction(RuntimeOrigin::signed([0; 32].into()), 0, 0, 10));
+		assert_ok!(GameModule::bid(RuntimeOrigin::signed([1; 32].into()), 0, 10));
+		assert_eq!(GameModule::highest_bid_count::<AccountId>([1; 32].into()), 1);
+		assert_noop!(
+			GameModule::deregister(RuntimeOrigin::signed([1; 32].into()), [1; 32].into(), 0, 1),
+			Error::<Test>::PlayerHasOpenBids
+		);
+		assert_noop!(
+			GameModule::unregister(RuntimeOrigin::signed([1; 32].into()), 1),
+			Error::<Test>::PlayerHasOpenBids
+		);
+		run_to_block(11);
+		assert_eq!(GameModule::highest_bid_count::<AccountId>([1; 32].into()), 0);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [1; 32].into());
+		assert_ok!(GameModule::do_try_state());
+		assert_ok!(GameModule::deregister(
+			RuntimeOrigin::signed([1; 32].into()),
+			[1; 32].into(),
+			1,
+			1
+		));
+		assert_eq!(Balances::reserved_balance(&[1; 32].into()), 0);
+	});
+}
+
+#[test]
+fn deregister_removes_the_player_from_past_rounds() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(2);
+		practise_round([0; 32].into(), 0);
+		practise_round([1; 32].into(), 1);
+		assert_ok!(GameModule::end_round(RuntimeOrigin::root()));
+		assert_eq!(GameModule::rounds(1).unwrap().leaderboard.len(), 2);
+		assert_eq!(GameModule::round_scores(1, AccountId::from([0; 32])).points, 5);
+		assert_eq!(GameModule::player_rounds::<AccountId, u32>([0; 32].into(), 1), Some(()));
+		System::set_block_number(2);
+		assert_ok!(GameModule::start_round(RuntimeOrigin::root(), None, None));
+		give_nft([0; 32].into(), 0);
+		assert_noop!(
+			GameModule::deregister(RuntimeOrigin::root(), [0; 32].into(), 1, 1),
+			Error::<Test>::TooManyRounds
+		);
+		assert_ok!(GameModule::deregister(RuntimeOrigin::root(), [0; 32].into(), 1, 2));
+		assert_eq!(
+			points_of(GameModule::rounds(1).unwrap().leaderboard),
+			vec![([1; 32].into(), 5)]
+		);
+		assert_eq!(GameModule::round_scores(1, AccountId::from([0; 32])).points, 0);
+		assert_eq!(GameModule::round_scores(2, AccountId::from([0; 32])).points, 0);
+		assert_eq!(GameModule::player_rounds::<AccountId, u32>([0; 32].into(), 1), None);
+		assert_eq!(GameModule::round_scores(1, AccountId::from([1; 32])).points, 5);
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn suspend_player_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()));
+		assert_ok!(GameModule::grant_role(
+			RuntimeOrigin::root(),
+			Role::PlayerModerator,
+			[5; 32].into()
+		));
+		assert_ok!(GameModule::suspend_player(
+			RuntimeOrigin::signed([5; 32].into()),
+			[0; 32].into(),
+			10,
+			H256::repeat_byte(1)
+		));
+		System::assert_last_event(
+			Event::PlayerSuspended {
+				player: [0; 32].into(),
+				until: 10,
+				reason: H256::repeat_byte(1),
+			}
+			.into(),
+		);
+		assert_eq!(
+			GameModule::users::<AccountId>([0; 32].into()).unwrap().moderation,
+			ModerationState::Suspended(10)
+		);
+		assert_eq!(GameModule::suspensions_ending(10).into_inner(), vec![[0; 32].into()]);
+		assert!(!GameModule::is_active(&[0; 32].into()));
+		assert_noop!(
+			GameModule::play_game(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Practice,
+			),
+			Error::<Test>::PlayerIsSuspended
+		);
+		assert_noop!(
+			GameModule::request_token(RuntimeOrigin::signed([0; 32].into())),
+			Error::<Test>::PlayerIsSuspended
+		);
+		assert_ok!(GameModule::file_appeal(
+			RuntimeOrigin::signed([0; 32].into()),
+			H256::repeat_byte(2)
+		));
+		System::assert_last_event(
+			Event::AppealFiled { player: [0; 32].into(), statement: H256::repeat_byte(2) }.into(),
+		);
+		assert_eq!(GameModule::appeals::<AccountId>([0; 32].into()), Some(H256::repeat_byte(2)));
+		assert_ok!(GameModule::do_try_state());
+		run_to_block(10);
+		System::assert_has_event(Event::SuspensionEnded { player: [0; 32].into() }.into());
+		assert_eq!(
+			GameModule::users::<AccountId>([0; 32].into()).unwrap().moderation,
+			ModerationState::Active
+		);
+		assert_eq!(GameModule::suspensions_ending(10).len(), 0);
+		assert_eq!(GameModule::appeals::<AccountId>([0; 32].into()), None);
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn suspension_can_be_replaced() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()));
+		assert_ok!(GameModule::suspend_player(
+			RuntimeOrigin::root(),
+			[0; 32].into(),
+			10,
+			H256::repeat_byte(1)
+		));
+		assert_ok!(GameModule::suspend_player(
+			RuntimeOrigin::root(),
+			[0; 32].into(),
+			20,
+			H256::repeat_byte(1)
+		));
+		assert_eq!(GameModule::suspensions_ending(10).len(), 0);
+		assert_eq!(GameModule::suspensions_ending(20).into_inner(), vec![[0; 32].into()]);
+		run_to_block(10);
+		assert!(!GameModule::is_active(&[0; 32].into()));
+		assert_ok!(GameModule::deregister(RuntimeOrigin::root(), [0; 32].into(), 0, 1));
+		assert_eq!(GameModule::suspended_accounts::<AccountId>([0; 32].into()), Some(20));
+		assert_eq!(GameModule::suspensions_ending(20).into_inner(), vec![[0; 32].into()]);
+		assert_noop!(
+			GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()),
+			Error::<Test>::PlayerIsSuspended
+		);
+		assert_ok!(GameModule::do_try_state());
+		run_to_block(20);
+		assert_eq!(GameModule::suspended_accounts::<AccountId>([0; 32].into()), None);
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()));
+		assert!(GameModule::is_active(&[0; 32].into()));
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn suspension_of_a_removed_player_can_be_lifted() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()));
+		assert_ok!(GameModule::suspend_player(
+			RuntimeOrigin::root(),
+			[0; 32].into(),
+			10,
+			H256::repeat_byte(1)
+		));
+		assert_ok!(GameModule::deregister(RuntimeOrigin::root(), [0; 32].into(), 0, 0));
+		assert_ok!(GameModule::reinstate_player(
+			RuntimeOrigin::root(),
+			[0; 32].into(),
+			H256::repeat_byte(2)
+		));
+		assert_eq!(GameModule::suspended_accounts::<AccountId>([0; 32].into()), None);
+		assert_eq!(GameModule::suspensions_ending(10).len(), 0);
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()));
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn ban_player_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()));
+		give_nft([0; 32].into(), 0);
+		assert_ok!(GameModule::suspend_player(
+			RuntimeOrigin::root(),
+			[0; 32].into(),
+			10,
+			H256::repeat_byte(1)
+		));
+		assert_ok!(GameModule::ban_player(
+			RuntimeOrigin::root(),
+			[0; 32].into(),
+			H256::repeat_byte(3)
+		));
+		System::assert_last_event(
+			Event::PlayerBanned { player: [0; 32].into(), reason: H256::repeat_byte(3) }.into(),
+		);
+		assert_eq!(GameModule::suspensions_ending(10).len(), 0);
+		assert_eq!(GameModule::banned_accounts::<AccountId>([0; 32].into()), Some(()));
+		assert_noop!(
+			GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None),
+			Error::<Test>::PlayerIsBanned
+		);
+		assert_noop!(
+			GameModule::deregister(RuntimeOrigin::signed([0; 32].into()), [0; 32].into(), 1, 1),
+			Error::<Test>::PlayerIsBanned
+		);
+		assert_noop!(
+			GameModule::suspend_player(
+				RuntimeOrigin::root(),
+				[0; 32].into(),
+				10,
+				H256::repeat_byte(1)
+			),
+			Error::<Test>::PlayerIsBanned
+		);
+		run_to_block(10);
+		assert!(!GameModule::is_active(&[0; 32].into()));
+		assert_ok!(GameModule::file_appeal(
+			RuntimeOrigin::signed([0; 32].into()),
+			H256::repeat_byte(2)
+		));
+		assert_ok!(GameModule::reject_appeal(
+			RuntimeOrigin::root(),
+			[0; 32].into(),
+			H256::repeat_byte(4)
+		));
+		System::assert_last_event(
+			Event::AppealRejected { player: [0; 32].into(), reason: H256::repeat_byte(4) }.into(),
+		);
+		assert_eq!(GameModule::appeals::<AccountId>([0; 32].into()), None);
+		assert_ok!(GameModule::file_appeal(
+			RuntimeOrigin::signed([0; 32].into()),
+			H256::repeat_byte(5)
+		));
+		assert_ok!(GameModule::reinstate_player(
+			RuntimeOrigin::root(),
+			[0; 32].into(),
+			H256::repeat_byte(6)
+		));
+		System::assert_last_event(
+			Event::PlayerReinstated { player: [0; 32].into(), reason: H256::repeat_byte(6) }.into(),
+		);
+		assert_eq!(GameModule::appeals::<AccountId>([0; 32].into()), None);
+		assert_eq!(GameModule::banned_accounts::<AccountId>([0; 32].into()), None);
+		assert!(GameModule::is_active(&[0; 32].into()));
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None));
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn ban_outlives_deregistration() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()));
+		assert_ok!(GameModule::ban_player(
+			RuntimeOrigin::root(),
+			[0; 32].into(),
+			H256::repeat_byte(1)
+		));
+		assert_ok!(GameModule::deregister(RuntimeOrigin::root(), [0; 32].into(), 0, 1));
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()), None);
+		assert_eq!(GameModule::banned_accounts::<AccountId>([0; 32].into()), Some(()));
+		assert_noop!(
+			GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()),
+			Error::<Test>::PlayerIsBanned
+		);
+		Balances::make_free_balance_be(&[0; 32].into(), 20);
+		assert_noop!(
+			GameModule::register(RuntimeOrigin::signed([0; 32].into()), None),
+			Error::<Test>::PlayerIsBanned
+		);
+		assert_noop!(
+			GameModule::reinstate_player(
+				RuntimeOrigin::root(),
+				[1; 32].into(),
+				H256::repeat_byte(2)
+			),
+			Error::<Test>::UserNotRegistered
+		);
+		assert_ok!(GameModule::reinstate_player(
+			RuntimeOrigin::root(),
+			[0; 32].into(),
+			H256::repeat_byte(2)
+		));
+		assert_eq!(GameModule::banned_accounts::<AccountId>([0; 32].into()), None);
+		assert_ok!(GameModule::register(RuntimeOrigin::signed([0; 32].into()), None));
+		assert!(GameModule::is_active(&[0; 32].into()));
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn moderation_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		for player in 0u8..3 {
+			assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [player; 32].into()));
+		}
+		assert_noop!(
+			GameModule::suspend_player(
+				RuntimeOrigin::signed([1; 32].into()),
+				[0; 32].into(),
+				10,
+				H256::repeat_byte(1)
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			GameModule::suspend_player(RuntimeOrigin::root(), [0; 32].into(), 1, H256::zero()),
+			Error::<Test>::InvalidSuspension
+		);
+		assert_noop!(
+			GameModule::ban_player(RuntimeOrigin::root(), [7; 32].into(), H256::zero()),
+			Error::<Test>::UserNotRegistered
+		);
+		assert_noop!(
+			GameModule::reinstate_player(RuntimeOrigin::root(), [0; 32].into(), H256::zero()),
+			Error::<Test>::PlayerNotRestricted
+		);
+		assert_noop!(
+			GameModule::file_appeal(RuntimeOrigin::signed([0; 32].into()), H256::zero()),
+			Error::<Test>::PlayerNotRestricted
+		);
+		assert_noop!(
+			GameModule::reject_appeal(RuntimeOrigin::root(), [0; 32].into(), H256::zero()),
+			Error::<Test>::NoAppeal
+		);
+		assert_ok!(GameModule::suspend_player(
+			RuntimeOrigin::root(),
+			[0; 32].into(),
+			10,
+			H256::zero()
+		));
+		assert_ok!(GameModule::suspend_player(
+			RuntimeOrigin::root(),
+			[1; 32].into(),
+			10,
+			H256::zero()
+		));
+		assert_noop!(
+			GameModule::suspend_player(RuntimeOrigin::root(), [2; 32].into(), 10, H256::zero()),
+			Error::<Test>::TooManySuspensions
+		);
+		assert_ok!(GameModule::grant_role(
+			RuntimeOrigin::root(),
+			Role::PlayerModerator,
+			[1; 32].into()
+		));
+		assert_noop!(
+			GameModule::ban_player(
+				RuntimeOrigin::signed([1; 32].into()),
+				[2; 32].into(),
+				H256::zero()
+			),
+			Error::<Test>::PlayerIsSuspended
+		);
+	});
+}
+
+#[test]
+fn play_game_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		assert_eq!(Balances::free_balance(&([0; 32].into())), 10);
+		assert_ok!(GameModule::give_points(RuntimeOrigin::root(), [0; 32].into(), 100));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_eq!(GameModule::game_info(1).unwrap().property.id, 147095938);
+		assert_eq!(GameModule::available_property_count(), 8);
+		assert_eq!(GameModule::game_properties(147095938).unwrap().status, PropertyStatus::InPlay);
+	});
+}
+
+#[test]
+fn player_profile_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		assert_eq!(GameModule::player_profile([1; 32].into()), None);
+		assert_eq!(GameModule::player_profile([0; 32].into()).unwrap().rank, None);
+		practise_round([0; 32].into(), 0);
+		let profile = GameModule::player_profile([0; 32].into()).unwrap();
+		assert_eq!(profile.points, 55);
+		assert_eq!(profile.practise_rounds, 1);
+		assert_eq!(profile.rank, Some(1));
+		assert_eq!(profile.round_rank, Some(1));
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		let open_games = GameModule::open_games([0; 32].into());
+		assert_eq!(open_games.len(), 1);
+		assert_eq!(open_games[0].game_id, 1);
+		assert_eq!(open_games[0].guess, None);
+		assert_eq!(open_games[0].expiry_block, 11);
+		assert_eq!(open_games[0].region, "Leeds".as_bytes().to_vec());
+		assert_eq!(GameModule::open_games([1; 32].into()).len(), 0);
+	});
+}
+
+#[test]
+fn play_game_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_noop!(
+			GameModule::play_game(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Player,
+			),
+			Error::<Test>::UserNotRegistered
+		);
+	});
+}
+
+#[test]
+fn play_game_fails_no_active_round() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		assert_noop!(
+			GameModule::play_game(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Practice,
+			),
+			Error::<Test>::NoActiveRound
+		);
+	});
+}
+
+#[test]
+fn play_game_fails_not_enough_points() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 10, 1));
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+	});
+}
+
+#[test]
+fn play_game_fails_too_many_games_for_player() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		for _ in 0..3 {
+			assert_ok!(GameModule::play_game(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Player,
+			));
+		}
+		assert_eq!(GameModule::player_games::<AccountId>([0; 32].into()).len(), 3);
+		assert_noop!(
+			GameModule::play_game(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Player,
+			),
+			Error::<Test>::TooManyPlayerGames
+		);
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			1,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(GameModule::player_games::<AccountId>([0; 32].into()).len(), 2);
+		assert_eq!(GameModule::active_games().len(), 2);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+	});
+}
+
+#[test]
+fn play_game_fails_too_many_games() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[1; 32].into()
+		));
+		let data: BoundedVec<u8, ConstU32<5000>> =
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap();
+		assert_ok!(GameModule::add_property(
+			RuntimeOrigin::root(),
+			PropertyInfoData {
+				id: 147000000,
+				data: data.clone(),
+				commitment: GameModule::property_commitment(220_000, &data, &data),
+				metadata: property_metadata(),
+			}
+		));
+		for _ in 0..3 {
+			assert_ok!(GameModule::play_game(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Practice,
+			));
+		}
+		for _ in 0..2 {
+			assert_ok!(GameModule::play_game(
+				RuntimeOrigin::signed([1; 32].into()),
+				crate::DifficultyLevel::Practice,
+			));
+		}
+		assert_eq!(GameModule::active_games().len(), 5);
+		assert_noop!(
+			GameModule::play_game(
+				RuntimeOrigin::signed([1; 32].into()),
+				crate::DifficultyLevel::Practice,
+			),
+			Error::<Test>::TooManyGames
+		);
+		run_to_block(11);
+		assert_eq!(GameModule::active_games().len(), 0);
+		assert_eq!(GameModule::player_games::<AccountId>([1; 32].into()).len(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([1; 32].into()),
+			crate::DifficultyLevel::Practice,
+		));
+	});
+}
+
+#[test]
+fn submit_answer_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 223_000, 1));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 223_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			1,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		System::assert_last_event(Event::ResultChecked { game_id: 1, secret: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(), points: 25, won: true, nft_received: false }.into());
+		assert_eq!(GameModule::game_info(0).is_none(), true);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 80);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
 		));
 		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 2));
 		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 2, guess: 220_000 }.into(),
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 2, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			2,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		System::assert_last_event(Event::ResultChecked { game_id: 2, secret: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(), points: 100, won: true, nft_received: true }.into());
+		assert_eq!(GameModule::game_info(1).is_none(), true);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 180);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xorange, 1);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 0, 3));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 3, guess: 0 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			3,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
+		for x in 4..8 {
+			assert_ok!(GameModule::play_game(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Player,
+			));
+			assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, x));
+			assert_ok!(GameModule::check_result(
+				RuntimeOrigin::root(),
+				x,
+				220_000,
+				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+			));
+		}
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xorange, 5);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 835);
+	});
+}
+
+#[test]
+fn submit_answer_works_with_high_difference() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 2_000, 1));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 2_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			1,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		System::assert_last_event(Event::ResultChecked { game_id: 1, secret: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(), points: 25, won: false, nft_received: false }.into());
+		assert_eq!(GameModule::game_info(0).is_none(), true);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 30);
+	});
+}
+
+#[test]
+fn game_expires_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		run_to_block(20);
+		System::assert_last_event(Event::NoAnswer { game_id: 1, points: 25 }.into());
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 30);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 223_000, 2));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 2, guess: 223_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			2,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 55);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Pro,
+		));
+		run_to_block(30);
+		System::assert_last_event(Event::NoAnswer { game_id: 3, points: 50 }.into());
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 5);
+	});
+}
+
+#[test]
+fn answered_game_does_not_expire() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_eq!(GameModule::games_expiring(11).len(), 1);
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		assert_eq!(GameModule::games_expiring(11).len(), 0);
+		assert_eq!(GameModule::games_expiring(21).len(), 1);
+		run_to_block(20);
+		assert_eq!(GameModule::game_info(1).is_some(), true);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			1,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(GameModule::games_expiring(21).len(), 0);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().wins, 1);
+	});
+}
+
+#[test]
+fn answered_game_expires_when_not_settled() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		assert_noop!(
+			GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 221_000, 1),
+			Error::<Test>::AnswerAlreadySubmitted
+		);
+		assert_eq!(GameModule::games_expiring(21).len(), 1);
+		run_to_block(21);
+		System::assert_last_event(Event::SettlementExpired { game_id: 1 }.into());
+		assert_eq!(GameModule::game_info(1).is_none(), true);
+		assert_eq!(GameModule::active_games().len(), 0);
+		assert_eq!(GameModule::player_games::<AccountId>([0; 32].into()).len(), 0);
+		assert_eq!(GameModule::available_property_count(), 9);
+		let user = GameModule::users::<AccountId>([0; 32].into()).unwrap();
+		assert_eq!(user.points, 55);
+		assert_eq!(user.losses, 0);
+	});
+}
+
+#[test]
+fn leaderboard_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[1; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[2; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		practise_round([1; 32].into(), 1);
+		practise_round([2; 32].into(), 2);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 230_000, 3));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 3, guess: 230_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			3,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		System::assert_last_event(Event::ResultChecked { game_id: 3, secret: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(), points: 15, won: true, nft_received: false }.into());
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([1; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([1; 32].into()), 225_000, 4));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 4, guess: 225_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			4,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([2; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([2; 32].into()), 220_000, 5));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [2; 32].into(), game_id: 5, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			5,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(GameModule::game_info(0).is_none(), true);
+		assert_eq!(GameModule::users::<AccountId>([2; 32].into()).unwrap().points, 155);
+		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().points, 80);
+		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().wins, 1);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 70);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().wins, 1);
+		assert_eq!(
+			points_of(GameModule::leaderboard()),
+			vec![([2; 32].into(), 155), ([1; 32].into(), 80), ([0; 32].into(), 70)]
+		);
+		assert_eq!(
+			points_of(GameModule::round_leaderboard()),
+			vec![([2; 32].into(), 105), ([1; 32].into(), 30), ([0; 32].into(), 20)]
+		);
+	});
+}
+
+#[test]
+fn leaderboards_honour_the_limit_and_rerank_on_point_losses() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		for player in 0u8..4 {
+			assert_ok!(GameModule::register_user(
+				RuntimeOrigin::signed([4; 32].into()),
+				[player; 32].into()
+			));
+			System::set_block_number(player as u64 + 1);
+			give_nft([player; 32].into(), 0);
+		}
+		assert_eq!(
+			points_of(GameModule::leaderboard()),
+			vec![([0; 32].into(), 150), ([1; 32].into(), 150), ([2; 32].into(), 150)]
+		);
+		assert_eq!(GameModule::leaderboard()[2].1, Score { points: 150, wins: 0, achieved: 3 });
+		assert_eq!(
+			points_of(GameModule::round_leaderboard()),
+			vec![([0; 32].into(), 100), ([1; 32].into(), 100), ([2; 32].into(), 100)]
+		);
+		System::set_block_number(5);
+		give_nft([3; 32].into(), 2);
+		assert_eq!(
+			points_of(GameModule::leaderboard()),
+			vec![([3; 32].into(), 250), ([0; 32].into(), 150), ([1; 32].into(), 150)]
+		);
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([3; 32].into()), 0, 3, None, None));
+		assert_eq!(
+			points_of(GameModule::leaderboard()),
+			vec![([0; 32].into(), 150), ([1; 32].into(), 150), ([2; 32].into(), 150)]
+		);
+		assert_eq!(GameModule::leaderboard()[2].1, Score { points: 150, wins: 0, achieved: 3 });
+		assert_eq!(
+			points_of(GameModule::round_leaderboard()),
+			vec![([0; 32].into(), 100), ([1; 32].into(), 100), ([2; 32].into(), 100)]
+		);
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None));
+		assert_eq!(
+			points_of(GameModule::leaderboard()),
+			vec![([1; 32].into(), 150), ([2; 32].into(), 150), ([3; 32].into(), 150)]
+		);
+		assert_eq!(GameModule::player_profile([0; 32].into()).unwrap().rank, None);
+		assert_eq!(
+			points_of(GameModule::round_leaderboard()),
+			vec![([1; 32].into(), 100), ([2; 32].into(), 100), ([3; 32].into(), 100)]
+		);
+		assert_eq!(GameModule::round_scores(1, AccountId::from([0; 32])).points, 0);
+		assert_eq!(GameModule::scores(AccountId::from([0; 32])).points, 50);
+		assert_eq!(points_of(GameModule::leaderboard_runners_up()), vec![([0; 32].into(), 50)]);
+		assert_eq!(GameModule::round_runners_up().len(), 0);
+		assert_ok!(GameModule::do_try_state());
+		assert_ok!(GameModule::unregister(RuntimeOrigin::signed([1; 32].into()), 1));
+		assert_eq!(
+			points_of(GameModule::leaderboard()),
+			vec![([2; 32].into(), 150), ([3; 32].into(), 150), ([0; 32].into(), 50)]
+		);
+		assert_eq!(GameModule::leaderboard_runners_up().len(), 0);
+		assert_eq!(
+			points_of(GameModule::round_leaderboard()),
+			vec![([2; 32].into(), 100), ([3; 32].into(), 100)]
+		);
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn scores_rank_by_points_then_wins_then_achievement() {
+	let score = |points, wins, achieved| Score::<BlockNumber> { points, wins, achieved };
+	assert!(score(101, 0, 9).rank_cmp(&score(100, 5, 1)).is_lt());
+	assert!(score(100, 2, 9).rank_cmp(&score(100, 1, 1)).is_lt());
+	assert!(score(100, 1, 1).rank_cmp(&score(100, 1, 2)).is_lt());
+	assert!(score(100, 1, 2).rank_cmp(&score(100, 1, 1)).is_gt());
+}
+
+#[test]
+fn round_leaderboard_is_archived_and_reset() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(2);
+		give_nft([0; 32].into(), 0);
+		give_nft([1; 32].into(), 0);
+		give_nft([1; 32].into(), 2);
+		assert_ok!(GameModule::end_round(RuntimeOrigin::root()));
+		assert_eq!(
+			points_of(GameModule::rounds(1).unwrap().leaderboard),
+			vec![([1; 32].into(), 200), ([0; 32].into(), 100)]
+		);
+		assert_eq!(GameModule::round_leaderboard().len(), 0);
+		System::set_block_number(2);
+		assert_ok!(GameModule::start_round(RuntimeOrigin::root(), None, None));
+		give_nft([0; 32].into(), 0);
+		assert_eq!(points_of(GameModule::round_leaderboard()), vec![([0; 32].into(), 100)]);
+		assert_eq!(
+			points_of(GameModule::leaderboard()),
+			vec![([1; 32].into(), 250), ([0; 32].into(), 250)]
+		);
+		assert_eq!(GameModule::round_scores(2, AccountId::from([0; 32])).points, 100);
+		assert_eq!(GameModule::round_scores(1, AccountId::from([0; 32])).points, 100);
+		let profile = GameModule::player_profile([1; 32].into()).unwrap();
+		assert_eq!(profile.rank, Some(1));
+		assert_eq!(profile.round_rank, None);
+	});
+}
+
+#[test]
+fn submit_answer_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_noop!(
+			GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 223_000, 1),
+			Error::<Test>::NoActiveGame
+		);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			1,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_noop!(
+			GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 230_000, 1),
+			Error::<Test>::NoActiveGame
+		);
+	});
+}
+
+#[test]
+fn transfer_of_nft_does_not_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		assert_ok!(GameModule::give_points(RuntimeOrigin::root(), [0; 32].into(), 100));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			1,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(GameModule::game_info(1).is_none(), true);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
+		assert_noop!(
+			Nfts::transfer(
+				RuntimeOrigin::signed([0; 32].into()),
+				0,
+				0,
+				sp_runtime::MultiAddress::Id([1; 32].into())
+			),
+			DispatchError::Module(ModuleError {
+				index: 3,
+				error: [12, 0, 0, 0],
+				message: Some("ItemLocked")
+			})
+		);
+	});
+}
+
+#[test]
+fn list_nft_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			1,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(GameModule::game_info(1).is_none(), true);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None));
+		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
+		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
+	});
+}
+
+#[test]
+fn listings_query_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			1,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			Some(100),
+			Some(20)
+		));
+		assert_eq!(
+			GameModule::listings_by(None, None),
+			vec![ListingDetails {
+				listing_id: 0,
+				owner: [0; 32].into(),
+				collection_id: 0,
+				item_id: 0,
+				color: Some(NftColor::Xorange),
+				price: Some(100),
+				expiry: Some(20),
+			}]
+		);
+		assert_eq!(GameModule::listings_by(Some([0; 32].into()), Some(NftColor::Xorange)).len(), 1);
+		assert_eq!(GameModule::listings_by(Some([1; 32].into()), None).len(), 0);
+		assert_eq!(GameModule::listings_by(None, Some(NftColor::Xpink)).len(), 0);
+		assert_eq!(GameModule::offers_by(None, None).len(), 0);
+	});
+}
+
+#[test]
+fn list_nft_doesnt_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			1,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(GameModule::game_info(1).is_none(), true);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
+		assert_noop!(
+			GameModule::list_nft(RuntimeOrigin::signed([1; 32].into()), 0, 0, None, None),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn delist_nft_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			1,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(GameModule::game_info(0).is_none(), true);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None));
+		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
+		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
+		assert_ok!(GameModule::delist_nft(RuntimeOrigin::signed([0; 32].into()), 0,));
+		assert_noop!(
+			Nfts::transfer(
+				RuntimeOrigin::signed([0; 32].into()),
+				0,
+				0,
+				sp_runtime::MultiAddress::Id([1; 32].into())
+			),
+			DispatchError::Module(ModuleError {
+				index: 3,
+				error: [12, 0, 0, 0],
+				message: Some("ItemLocked")
+			})
+		);
+	});
+}
+
+#[test]
+fn delist_nft_doesnt_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			1,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(GameModule::game_info(0).is_none(), true);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None));
+		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
+		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
+		assert_noop!(
+			GameModule::delist_nft(RuntimeOrigin::signed([1; 32].into()), 0,),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn make_offer_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[1; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			1,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(GameModule::game_info(0).is_none(), true);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
+		practise_round([1; 32].into(), 2);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([1; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([1; 32].into()), 220_000, 3));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 3, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			3,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None));
+		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
+		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1, None));
+		assert_eq!(GameModule::offers(0).unwrap().owner, [1; 32].into());
+	});
+}
+
+#[test]
+fn make_offer_doesnt_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		assert_ok!(GameModule::give_points(RuntimeOrigin::root(), [0; 32].into(), 100));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		assert_noop!(
+			GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 0, None),
+			Error::<Test>::ListingDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn withdraw_offer_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[1; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			1,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(GameModule::game_info(0).is_none(), true);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().wins, 1);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
+		practise_round([1; 32].into(), 2);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([1; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([1; 32].into()), 220_000, 3));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 3, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			3,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None));
+		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
+		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1, None));
+		assert_eq!(GameModule::offers(0).unwrap().owner, [1; 32].into());
+		assert_ok!(GameModule::withdraw_offer(RuntimeOrigin::signed([1; 32].into()), 0));
+		assert_eq!(GameModule::offers(0).is_none(), true);
+	});
+}
+
+#[test]
+fn withdraw_offer_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[1; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			1,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(GameModule::game_info(0).is_none(), true);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
+		practise_round([1; 32].into(), 2);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([1; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([1; 32].into()), 220_000, 3));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 3, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			3,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None));
+		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
+		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
+		assert_noop!(
+			GameModule::withdraw_offer(RuntimeOrigin::signed([0; 32].into()), 0),
+			Error::<Test>::OfferDoesNotExist
+		);
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1, None));
+		assert_eq!(GameModule::offers(0).unwrap().owner, [1; 32].into());
+		assert_noop!(
+			GameModule::withdraw_offer(RuntimeOrigin::signed([0; 32].into()), 0),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn handle_offer_accept_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[1; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			1,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
+		assert_eq!(GameModule::game_info(0).is_none(), true);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
+		practise_round([1; 32].into(), 2);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([1; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([1; 32].into()), 220_000, 3));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 3, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			3,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 4));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 4, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			4,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 275);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 5));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 5, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			5,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xorange, 3);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 495);
+		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().nfts.xorange, 1);
+		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None));
+		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
+		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1, None));
+		assert_eq!(GameModule::offers(0).unwrap().owner, [1; 32].into());
+		assert_ok!(GameModule::handle_offer(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			crate::Offer::Accept,
+		));
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [1; 32].into());
+		assert_eq!(Nfts::owner(0, 1).unwrap(), [0; 32].into());
+		assert_eq!(GameModule::offers(0).is_none(), true);
+		assert_eq!(GameModule::listings(0).is_none(), true);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xorange, 3);
+		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().nfts.xorange, 1);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 495);
+		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().points, 155);
+		assert_noop!(
+			Nfts::transfer(
+				RuntimeOrigin::signed([0; 32].into()),
+				0,
+				1,
+				sp_runtime::MultiAddress::Id([1; 32].into())
+			),
+			DispatchError::Module(ModuleError {
+				index: 3,
+				error: [12, 0, 0, 0],
+				message: Some("ItemLocked")
+			})
+		);
+		assert_noop!(
+			Nfts::transfer(
+				RuntimeOrigin::signed([1; 32].into()),
+				0,
+				0,
+				sp_runtime::MultiAddress::Id([1; 32].into())
+			),
+			DispatchError::Module(ModuleError {
+				index: 3,
+				error: [12, 0, 0, 0],
+				message: Some("ItemLocked")
+			})
+		);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		System::assert_last_event(
+			Event::GameStarted { player: [0; 32].into(), game_id: 6 }.into(),
+		);
+		run_to_block(20);
+		System::assert_last_event(Event::NoAnswer { game_id: 6, points: 25 }.into());
+		assert_eq!(GameModule::game_info(6).is_none(), true);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xorange, 3);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 470);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().wins, 3);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().losses, 1);
+	});
+}
+
+#[test]
+fn handle_offer_reject_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[1; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			1,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(GameModule::game_info(0).is_none(), true);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
+		practise_round([1; 32].into(), 2);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([1; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([1; 32].into()), 220_000, 3));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 3, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			3,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None));
+		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
+		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1, None));
+		assert_eq!(GameModule::offers(0).unwrap().owner, [1; 32].into());
+		assert_ok!(GameModule::handle_offer(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			crate::Offer::Reject,
+		));
+		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
+		assert_eq!(GameModule::offers(0).is_none(), true);
+		assert_eq!(GameModule::listings(0).is_some(), true);
+		assert_noop!(
+			Nfts::transfer(
+				RuntimeOrigin::signed([1; 32].into()),
+				0,
+				1,
+				sp_runtime::MultiAddress::Id([1; 32].into())
+			),
+			DispatchError::Module(ModuleError {
+				index: 3,
+				error: [12, 0, 0, 0],
+				message: Some("ItemLocked")
+			})
+		);
+	});
+}
+
+#[test]
+fn handle_offer_doesnt_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[1; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			1,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(GameModule::game_info(0).is_none(), true);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
+		practise_round([1; 32].into(), 2);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([1; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([1; 32].into()), 220_000, 3));
+		System::assert_last_event(
+			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 3, guess: 220_000 }.into(),
+		);
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			3,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		));
+		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None));
+		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
+		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
+		assert_noop!(
+			GameModule::handle_offer(
+				RuntimeOrigin::signed([0; 32].into()),
+				0,
+				crate::Offer::Reject,
+			),
+			Error::<Test>::OfferDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn accepting_an_offer_refunds_the_other_offers() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(3);
+		for player in 0u8..3 {
+			practise_round([player; 32].into(), player as u32 * 2);
+			win_nft([player; 32].into(), player as u32 * 2 + 1);
+			assert_eq!(Nfts::owner(0, player as u32).unwrap(), [player; 32].into());
+		}
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None));
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xorange, 0);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 55);
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1, None));
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([2; 32].into()), 0, 0, 2, None));
+		assert_eq!(GameModule::listing_offers(0).into_inner(), vec![0, 1]);
+		assert_eq!(GameModule::users::<AccountId>([2; 32].into()).unwrap().nfts.xorange, 0);
+		assert_ok!(GameModule::do_try_state());
+		assert_ok!(GameModule::handle_offer(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			crate::Offer::Accept,
+		));
+		System::assert_has_event(
+			Event::OfferRefunded { owner: [2; 32].into(), offer_id: 1 }.into(),
+		);
+		System::assert_last_event(
+			Event::OfferHandeld { offer_id: 0, offer: crate::Offer::Accept }.into(),
+		);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [1; 32].into());
+		assert_eq!(Nfts::owner(0, 1).unwrap(), [0; 32].into());
+		assert_eq!(Nfts::owner(0, 2).unwrap(), [2; 32].into());
+		assert_eq!(GameModule::offers(1).is_none(), true);
+		assert_eq!(GameModule::listing_offers(0).len(), 0);
+		for player in 0u8..3 {
+			let user = GameModule::users::<AccountId>([player; 32].into()).unwrap();
+			assert_eq!(user.nfts.xorange, 1);
+			assert_eq!(user.points, 155);
+		}
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn delist_nft_refunds_the_offers() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(2);
+		for player in 0u8..2 {
+			practise_round([player; 32].into(), player as u32 * 2);
+			win_nft([player; 32].into(), player as u32 * 2 + 1);
+		}
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None));
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1, None));
+		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().points, 55);
+		assert_eq!(GameModule::leaderboard()[0].0, [0; 32].into());
+		assert_eq!(GameModule::leaderboard()[0].1.points, 55);
+		assert_ok!(GameModule::delist_nft(RuntimeOrigin::signed([0; 32].into()), 0));
+		System::assert_has_event(
+			Event::OfferRefunded { owner: [1; 32].into(), offer_id: 0 }.into(),
+		);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
+		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
+		assert_eq!(GameModule::offers(0).is_none(), true);
+		assert_eq!(GameModule::listing_offers(0).len(), 0);
+		for player in 0u8..2 {
+			let user = GameModule::users::<AccountId>([player; 32].into()).unwrap();
+			assert_eq!(user.nfts.xorange, 1);
+			assert_eq!(user.points, 155);
+		}
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn buy_nft_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(3);
+		for player in 0u8..3 {
+			practise_round([player; 32].into(), player as u32);
+		}
+		for player in 0u8..2 {
+			win_nft([player; 32].into(), player as u32 + 3);
+		}
+		Balances::make_free_balance_be(&[2; 32].into(), 1_000);
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			Some(100),
+			None
+		));
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1, None));
+		assert_ok!(GameModule::buy_nft(RuntimeOrigin::signed([2; 32].into()), 0));
+		System::assert_last_event(
+			Event::NftSold {
+				listing_id: 0,
+				seller: [0; 32].into(),
+				buyer: [2; 32].into(),
+				price: 100,
+				fee: 5,
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			Event::OfferRefunded { owner: [1; 32].into(), offer_id: 0 }.into(),
+		);
+		assert_eq!(Balances::free_balance(&[0; 32].into()), 105);
+		assert_eq!(Balances::free_balance(&[2; 32].into()), 900);
+		assert_eq!(Balances::free_balance(&GameModule::account_id()), 999_975);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [2; 32].into());
+		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
+		assert_eq!(GameModule::listings(0).is_none(), true);
+		assert_eq!(GameModule::offers(0).is_none(), true);
+		let seller = GameModule::users::<AccountId>([0; 32].into()).unwrap();
+		assert_eq!(seller.nfts.xorange, 0);
+		assert_eq!(seller.points, 55);
+		let buyer = GameModule::users::<AccountId>([2; 32].into()).unwrap();
+		assert_eq!(buyer.nfts.xorange, 1);
+		assert_eq!(buyer.points, 155);
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn buy_nft_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(3);
+		for player in 0u8..3 {
+			practise_round([player; 32].into(), player as u32);
+		}
+		for player in 0u8..2 {
+			win_nft([player; 32].into(), player as u32 + 3);
+		}
+		assert_noop!(
+			GameModule::buy_nft(RuntimeOrigin::signed([2; 32].into()), 0),
+			Error::<Test>::ListingDoesNotExist
+		);
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None));
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			1,
+			Some(100),
+			None
+		));
+		assert_noop!(
+			GameModule::buy_nft(RuntimeOrigin::signed([2; 32].into()), 0),
+			Error::<Test>::ListingNotForSale
+		);
+		assert_noop!(
+			GameModule::buy_nft(RuntimeOrigin::signed([1; 32].into()), 1),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			GameModule::buy_nft(RuntimeOrigin::signed([3; 32].into()), 1),
+			Error::<Test>::UserNotRegistered
+		);
+		assert_noop!(
+			GameModule::buy_nft(RuntimeOrigin::signed([2; 32].into()), 1),
+			sp_runtime::TokenError::FundsUnavailable
+		);
+	});
+}
+
+#[test]
+fn listings_and_offers_expire_in_on_idle() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(3);
+		for player in 0u8..3 {
+			practise_round([player; 32].into(), player as u32);
+		}
+		for player in 0u8..3 {
+			win_nft([player; 32].into(), player as u32 + 3);
+		}
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			None,
+			Some(5)
+		));
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1, None));
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([2; 32].into()), 0, 0, 2, Some(3)));
+		assert_eq!(GameModule::listings_expiring(5).into_inner(), vec![0]);
+		assert_eq!(GameModule::offers_expiring(3).into_inner(), vec![1]);
+		run_to_block(3);
+		GameModule::on_idle(3, Weight::MAX);
+		System::assert_last_event(
+			Event::OfferExpired { offer_id: 1, owner: [2; 32].into() }.into(),
+		);
+		assert_eq!(GameModule::offers(1).is_none(), true);
+		assert_eq!(GameModule::listing_offers(0).into_inner(), vec![0]);
+		assert_eq!(Nfts::owner(0, 2).unwrap(), [2; 32].into());
+		assert_eq!(GameModule::expiry_sweep_block(), Some(4));
+		assert_ok!(GameModule::do_try_state());
+		run_to_block(5);
+		GameModule::on_idle(5, Weight::MAX);
+		System::assert_has_event(
+			Event::OfferRefunded { owner: [1; 32].into(), offer_id: 0 }.into(),
+		);
+		System::assert_last_event(
+			Event::ListingExpired { listing_id: 0, owner: [0; 32].into() }.into(),
+		);
+		assert_eq!(GameModule::listings(0).is_none(), true);
+		assert_eq!(GameModule::offers(0).is_none(), true);
+		assert_eq!(GameModule::listings_expiring(5).len(), 0);
+		assert_eq!(GameModule::expiry_sweep_block(), Some(6));
+		for player in 0u8..3 {
+			assert_eq!(Nfts::owner(0, player as u32).unwrap(), [player; 32].into());
+			let user = GameModule::users::<AccountId>([player; 32].into()).unwrap();
+			assert_eq!(user.nfts.xorange, 1);
+			assert_eq!(user.points, 155);
+		}
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn expiry_sweep_continues_when_the_weight_is_used_up() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(3);
+		for player in 0u8..3 {
+			practise_round([player; 32].into(), player as u32);
+		}
+		for player in 0u8..3 {
+			win_nft([player; 32].into(), player as u32 + 3);
+		}
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None));
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1, Some(3)));
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([2; 32].into()), 0, 0, 2, Some(3)));
+		run_to_block(3);
+		GameModule::on_idle(3, Weight::from_parts(70_000_000, 5_000));
+		assert_eq!(GameModule::offers(0).is_none(), true);
+		assert_eq!(GameModule::offers(1).is_some(), true);
+		assert_eq!(GameModule::offers_expiring(3).into_inner(), vec![1]);
+		assert_eq!(GameModule::expiry_sweep_block(), Some(3));
+		assert_ok!(GameModule::do_try_state());
+		run_to_block(4);
+		GameModule::on_idle(4, Weight::MAX);
+		System::assert_last_event(
+			Event::OfferExpired { offer_id: 1, owner: [2; 32].into() }.into(),
+		);
+		assert_eq!(GameModule::offers(1).is_none(), true);
+		assert_eq!(GameModule::offers_expiring(3).len(), 0);
+		assert_eq!(GameModule::expiry_sweep_block(), Some(5));
+		assert_eq!(GameModule::listing_offers(0).len(), 0);
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn closed_listings_and_offers_free_their_expiry_slots() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(3);
+		for player in 0u8..3 {
+			practise_round([player; 32].into(), player as u32);
+		}
+		for player in 0u8..3 {
+			win_nft([player; 32].into(), player as u32 + 3);
+		}
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			None,
+			Some(3)
+		));
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1, Some(3)));
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([2; 32].into()), 0, 0, 2, Some(3)));
+		assert_noop!(
+			GameModule::list_nft(RuntimeOrigin::signed([1; 32].into()), 0, 1, None, Some(3)),
+			Error::<Test>::TooManyExpiries
+		);
+		assert_ok!(GameModule::withdraw_offer(RuntimeOrigin::signed([1; 32].into()), 0));
+		assert_eq!(GameModule::offers_expiring(3).into_inner(), vec![1]);
+		assert_ok!(GameModule::do_try_state());
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1, Some(3)));
+		assert_eq!(GameModule::offers_expiring(3).into_inner(), vec![1, 2]);
+		assert_ok!(GameModule::delist_nft(RuntimeOrigin::signed([0; 32].into()), 0));
+		assert_eq!(GameModule::listings_expiring(3).len(), 0);
+		assert_eq!(GameModule::offers_expiring(3).len(), 0);
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn expiry_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(3);
+		for player in 0u8..3 {
+			practise_round([player; 32].into(), player as u32);
+		}
+		for player in 0u8..3 {
+			win_nft([player; 32].into(), player as u32 + 3);
+		}
+		assert_noop!(
+			GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, Some(100), Some(1)),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			Some(100),
+			Some(3)
+		));
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			1,
+			None,
+			Some(3)
+		));
+		assert_noop!(
+			GameModule::list_nft(RuntimeOrigin::signed([2; 32].into()), 0, 2, None, Some(3)),
+			Error::<Test>::TooManyExpiries
+		);
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([2; 32].into()), 0, 0, 2, Some(2)));
+		run_to_block(2);
+		assert_noop!(
+			GameModule::handle_offer(
+				RuntimeOrigin::signed([0; 32].into()),
+				0,
+				crate::Offer::Accept
+			),
+			Error::<Test>::OfferHasExpired
+		);
+		run_to_block(3);
+		assert_noop!(
+			GameModule::buy_nft(RuntimeOrigin::signed([2; 32].into()), 0),
+			Error::<Test>::ListingHasExpired
+		);
+		assert_noop!(
+			GameModule::make_offer(RuntimeOrigin::signed([2; 32].into()), 0, 0, 2, None),
+			Error::<Test>::ListingHasExpired
+		);
+		assert_noop!(
+			GameModule::handle_offer(
+				RuntimeOrigin::signed([0; 32].into()),
+				0,
+				crate::Offer::Accept
+			),
+			Error::<Test>::ListingHasExpired
+		);
+	});
+}
+
+#[test]
+fn standing_orders_match_each_other() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(3);
+		assert_eq!(give_nft([0; 32].into(), 2), (2, 0));
+		assert_eq!(give_nft([1; 32].into(), 7), (7, 0));
+		assert_ok!(GameModule::place_standing_order(
+			RuntimeOrigin::signed([0; 32].into()),
+			2,
+			0,
+			NftColor::Xgreen
+		));
+		System::assert_last_event(
+			Event::StandingOrderPlaced {
+				order_id: 0,
+				owner: [0; 32].into(),
+				collection_id: 2,
+				item_id: 0,
+				give: NftColor::Xblue,
+				want: NftColor::Xgreen,
+			}
+			.into(),
+		);
+		assert_eq!(Nfts::owner(2, 0).unwrap(), GameModule::account_id());
+		assert_eq!(
+			GameModule::standing_orders_by_color(NftColor::Xblue, NftColor::Xgreen).into_inner(),
+			vec![0]
+		);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xblue, 0);
+		assert_ok!(GameModule::do_try_state());
+		assert_ok!(GameModule::place_standing_order(
+			RuntimeOrigin::signed([1; 32].into()),
+			7,
+			0,
+			NftColor::Xblue
+		));
+		System::assert_last_event(
+			Event::StandingOrderMatched {
+				order_id: 0,
+				owner: [0; 32].into(),
+				counterparty: [1; 32].into(),
+				listing_id: None,
+			}
+			.into(),
+		);
+		assert_eq!(Nfts::owner(2, 0).unwrap(), [1; 32].into());
+		assert_eq!(Nfts::owner(7, 0).unwrap(), [0; 32].into());
+		assert_eq!(GameModule::standing_orders(0).is_none(), true);
+		assert_eq!(GameModule::standing_orders(1).is_none(), true);
+		assert_eq!(
+			GameModule::standing_orders_by_color(NftColor::Xblue, NftColor::Xgreen).len(),
+			0
+		);
+		assert_eq!(
+			GameModule::standing_orders_by_color(NftColor::Xgreen, NftColor::Xblue).len(),
+			0
+		);
+		let first = GameModule::users::<AccountId>([0; 32].into()).unwrap();
+		assert_eq!((first.nfts.xblue, first.nfts.xgreen), (0, 1));
+		let second = GameModule::users::<AccountId>([1; 32].into()).unwrap();
+		assert_eq!((second.nfts.xblue, second.nfts.xgreen), (1, 0));
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn standing_order_skips_the_own_orders() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(2);
+		assert_eq!(give_nft([0; 32].into(), 2), (2, 0));
+		assert_eq!(give_nft([1; 32].into(), 2), (2, 1));
+		assert_eq!(give_nft([0; 32].into(), 7), (7, 0));
+		assert_ok!(GameModule::place_standing_order(
+			RuntimeOrigin::signed([0; 32].into()),
+			2,
+			0,
+			NftColor::Xgreen
+		));
+		assert_ok!(GameModule::place_standing_order(
+			RuntimeOrigin::signed([1; 32].into()),
+			2,
+			1,
+			NftColor::Xgreen
+		));
+		assert_ok!(GameModule::place_standing_order(
+			RuntimeOrigin::signed([0; 32].into()),
+			7,
+			0,
+			NftColor::Xblue
+		));
+		System::assert_last_event(
+			Event::StandingOrderMatched {
+				order_id: 1,
+				owner: [1; 32].into(),
+				counterparty: [0; 32].into(),
+				listing_id: None,
+			}
+			.into(),
+		);
+		assert_eq!(Nfts::owner(2, 1).unwrap(), [0; 32].into());
+		assert_eq!(Nfts::owner(7, 0).unwrap(), [1; 32].into());
+		assert_eq!(
+			GameModule::standing_orders_by_color(NftColor::Xblue, NftColor::Xgreen).into_inner(),
+			vec![0]
+		);
+		assert_eq!(GameModule::standing_orders(2).is_none(), true);
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn standing_order_matches_a_new_listing() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(3);
+		give_nft([0; 32].into(), 2);
+		give_nft([1; 32].into(), 7);
+		give_nft([2; 32].into(), 7);
+		assert_ok!(GameModule::place_standing_order(
+			RuntimeOrigin::signed([0; 32].into()),
+			2,
+			0,
+			NftColor::Xgreen
+		));
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([2; 32].into()),
+			7,
+			1,
+			Some(100),
+			None
+		));
+		assert_eq!(GameModule::listings(0).is_some(), true);
+		assert_eq!(GameModule::standing_orders(0).is_some(), true);
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([1; 32].into()), 7, 0, None, None));
+		System::assert_last_event(
+			Event::StandingOrderMatched {
+				order_id: 0,
+				owner: [0; 32].into(),
+				counterparty: [1; 32].into(),
+				listing_id: Some(1),
+			}
+			.into(),
+		);
+		assert_eq!(GameModule::listings(1).is_none(), true);
+		assert_eq!(GameModule::standing_orders(0).is_none(), true);
+		assert_eq!(Nfts::owner(2, 0).unwrap(), [1; 32].into());
+		assert_eq!(Nfts::owner(7, 0).unwrap(), [0; 32].into());
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xgreen, 1);
+		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().nfts.xblue, 1);
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn cancel_standing_order_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(3);
+		give_nft([0; 32].into(), 2);
+		assert_ok!(GameModule::place_standing_order(
+			RuntimeOrigin::signed([0; 32].into()),
+			2,
+			0,
+			NftColor::Xgreen
+		));
+		assert_ok!(GameModule::cancel_standing_order(RuntimeOrigin::signed([0; 32].into()), 0));
+		System::assert_last_event(
+			Event::StandingOrderCancelled { order_id: 0, owner: [0; 32].into() }.into(),
+		);
+		assert_eq!(GameModule::standing_orders(0).is_none(), true);
+		assert_eq!(
+			GameModule::standing_orders_by_color(NftColor::Xblue, NftColor::Xgreen).len(),
+			0
+		);
+		assert_eq!(Nfts::owner(2, 0).unwrap(), [0; 32].into());
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xblue, 1);
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn standing_order_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(3);
+		for _ in 0..3 {
+			give_nft([0; 32].into(), 2);
+		}
+		assert_noop!(
+			GameModule::place_standing_order(
+				RuntimeOrigin::signed([0; 32].into()),
+				2,
+				0,
+				NftColor::Xblue
+			),
+			Error::<Test>::SameColor
+		);
+		assert_noop!(
+			GameModule::place_standing_order(
+				RuntimeOrigin::signed([0; 32].into()),
+				99,
+				0,
+				NftColor::Xgreen
+			),
+			Error::<Test>::CollectionUnknown
+		);
+		assert_noop!(
+			GameModule::place_standing_order(
+				RuntimeOrigin::signed([1; 32].into()),
+				2,
+				0,
+				NftColor::Xgreen
+			),
+			Error::<Test>::NoPermission
+		);
+		for item_id in 0..2 {
+			assert_ok!(GameModule::place_standing_order(
+				RuntimeOrigin::signed([0; 32].into()),
+				2,
+				item_id,
+				NftColor::Xgreen
+			));
+		}
+		assert_noop!(
+			GameModule::place_standing_order(
+				RuntimeOrigin::signed([0; 32].into()),
+				2,
+				2,
+				NftColor::Xgreen
+			),
+			Error::<Test>::TooManyStandingOrders
+		);
+		assert_noop!(
+			GameModule::cancel_standing_order(RuntimeOrigin::signed([0; 32].into()), 5),
+			Error::<Test>::StandingOrderDoesNotExist
+		);
+		assert_noop!(
+			GameModule::cancel_standing_order(RuntimeOrigin::signed([1; 32].into()), 0),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn create_auction_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_players(3);
+		win_nft([0; 32].into(), 3);
+		assert_ok!(GameModule::create_auction(RuntimeOrigin::signed([0; 32].into()), 0, 0, 10));
+		System::assert_last_event(
+			Event::AuctionCreated {
+				auction_id: 0,
+				owner: [0; 32].into(),
+				collection_id: 0,
+				item_id: 0,
+				min_bid: 10,
+				end_block: 11,
+			}
+			.into(),
+		);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
+		assert_eq!(GameModule::auctions(0).unwrap().highest_bid, None);
+		assert_eq!(GameModule::auctions_ending(11).into_inner(), vec![0]);
+		assert_eq!(GameModule::next_auction_id(), 1);
+		let user = GameModule::users::<AccountId>([0; 32].into()).unwrap();
+		assert_eq!(user.nfts.xorange, 0);
+		assert_eq!(user.points, 55);
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn create_auction_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_players(3);
+		win_nft([0; 32].into(), 3);
+		win_nft([1; 32].into(), 4);
+		win_nft([2; 32].into(), 5);
+		assert_noop!(
+			GameModule::create_auction(RuntimeOrigin::signed([1; 32].into()), 0, 0, 10),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(GameModule::create_auction(RuntimeOrigin::signed([0; 32].into()), 0, 0, 10));
+		assert_ok!(GameModule::create_auction(RuntimeOrigin::signed([1; 32].into()), 0, 1, 10));
+		assert_noop!(
+			GameModule::create_auction(RuntimeOrigin::signed([2; 32].into()), 0, 2, 10),
+			Error::<Test>::TooManyAuctions
+		);
+	});
+}
+
+#[test]
+fn bid_extends_and_settles_the_auction() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_players(3);
+		win_nft([0; 32].into(), 3);
+		assert_ok!(GameModule::create_auction(RuntimeOrigin::signed([0; 32].into()), 0, 0, 10));
+		assert_ok!(GameModule::bid(RuntimeOrigin::signed([1; 32].into()), 0, 10));
+		assert_eq!(Balances::reserved_balance(&[1; 32].into()), 10);
+		assert_ok!(GameModule::bid(RuntimeOrigin::signed([2; 32].into()), 0, 20));
+		assert_eq!(Balances::reserved_balance(&[1; 32].into()), 0);
+		assert_eq!(Balances::reserved_balance(&[2; 32].into()), 20);
+		assert_eq!(GameModule::highest_bid_count::<AccountId>([1; 32].into()), 0);
+		assert_eq!(GameModule::highest_bid_count::<AccountId>([2; 32].into()), 1);
+		assert_eq!(GameModule::auctions(0).unwrap().end_block, 11);
+		run_to_block(9);
+		assert_ok!(GameModule::bid(RuntimeOrigin::signed([1; 32].into()), 0, 40));
+		System::assert_last_event(
+			Event::BidPlaced { auction_id: 0, bidder: [1; 32].into(), amount: 40, end_block: 12 }
+				.into(),
+		);
+		assert_eq!(Balances::reserved_balance(&[2; 32].into()), 0);
+		assert_eq!(GameModule::auctions_ending(11).len(), 0);
+		assert_eq!(GameModule::auctions_ending(12).into_inner(), vec![0]);
+		run_to_block(11);
+		assert_eq!(GameModule::auctions(0).is_some(), true);
+		assert_ok!(GameModule::do_try_state());
+		run_to_block(12);
+		System::assert_last_event(
+			Event::AuctionSettled {
+				auction_id: 0,
+				owner: [0; 32].into(),
+				winner: Some([1; 32].into()),
+				amount: Some(40),
+			}
+			.into(),
+		);
+		assert_eq!(GameModule::auctions(0).is_none(), true);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [1; 32].into());
+		assert_eq!(Balances::free_balance(&[0; 32].into()), 1_038);
+		assert_eq!(Balances::free_balance(&[1; 32].into()), 960);
+		assert_eq!(Balances::reserved_balance(&[1; 32].into()), 0);
+		assert_eq!(Balances::free_balance(&GameModule::account_id()), 999_972);
+		let seller = GameModule::users::<AccountId>([0; 32].into()).unwrap();
+		assert_eq!(seller.nfts.xorange, 0);
+		assert_eq!(seller.points, 55);
+		let winner = GameModule::users::<AccountId>([1; 32].into()).unwrap();
+		assert_eq!(winner.nfts.xorange, 1);
+		assert_eq!(winner.points, 155);
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn bid_extends_the_auction_to_the_next_free_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_players(2);
+		win_nft([0; 32].into(), 2);
+		assert_ok!(GameModule::create_auction(RuntimeOrigin::signed([0; 32].into()), 0, 0, 10));
+		crate::AuctionsEnding::<Test>::insert(12, BoundedVec::truncate_from(vec![7, 8]));
+		run_to_block(9);
+		assert_ok!(GameModule::bid(RuntimeOrigin::signed([1; 32].into()), 0, 10));
+		System::assert_last_event(
+			Event::BidPlaced { auction_id: 0, bidder: [1; 32].into(), amount: 10, end_block: 13 }
+				.into(),
+		);
+		assert_eq!(GameModule::auctions(0).unwrap().end_block, 13);
+		assert_eq!(GameModule::auctions_ending(11).len(), 0);
+		assert_eq!(GameModule::auctions_ending(12).into_inner(), vec![7, 8]);
+		assert_eq!(GameModule::auctions_ending(13).into_inner(), vec![0]);
+	});
+}
+
+#[test]
+fn auction_fails_if_the_bid_is_not_covered() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_players(2);
+		win_nft([0; 32].into(), 2);
+		assert_ok!(GameModule::create_auction(RuntimeOrigin::signed([0; 32].into()), 0, 0, 10));
+		assert_ok!(GameModule::bid(RuntimeOrigin::signed([1; 32].into()), 0, 10));
+		Balances::unreserve(&[1; 32].into(), 5);
+		run_to_block(11);
+		System::assert_last_event(
+			Event::AuctionFailed {
+				auction_id: 0,
+				owner: [0; 32].into(),
+				bidder: [1; 32].into(),
+				amount: 10,
+			}
+			.into(),
+		);
+		assert_eq!(GameModule::auctions(0).is_none(), true);
+		assert_eq!(GameModule::auctions_ending(11).len(), 0);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
+		assert_eq!(GameModule::highest_bid_count::<AccountId>([1; 32].into()), 0);
+		assert_eq!(Balances::reserved_balance(&[1; 32].into()), 0);
+		assert_eq!(Balances::free_balance(&[1; 32].into()), 1_000);
+		assert_eq!(Balances::free_balance(&[0; 32].into()), 1_000);
+		let user = GameModule::users::<AccountId>([0; 32].into()).unwrap();
+		assert_eq!(user.nfts.xorange, 1);
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn auction_without_bids_returns_the_nft() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_players(3);
+		win_nft([0; 32].into(), 3);
+		assert_ok!(GameModule::create_auction(RuntimeOrigin::signed([0; 32].into()), 0, 0, 10));
+		run_to_block(11);
+		System::assert_last_event(
+			Event::AuctionSettled {
+				auction_id: 0,
+				owner: [0; 32].into(),
+				winner: None,
+				amount: None,
+			}
+			.into(),
+		);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
+		let user = GameModule::users::<AccountId>([0; 32].into()).unwrap();
+		assert_eq!(user.nfts.xorange, 1);
+		assert_eq!(user.points, 155);
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn bid_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_players(3);
+		win_nft([0; 32].into(), 3);
+		assert_noop!(
+			GameModule::bid(RuntimeOrigin::signed([1; 32].into()), 0, 10),
+			Error::<Test>::AuctionDoesNotExist
+		);
+		assert_ok!(GameModule::create_auction(RuntimeOrigin::signed([0; 32].into()), 0, 0, 10));
+		assert_noop!(
+			GameModule::bid(RuntimeOrigin::signed([0; 32].into()), 0, 10),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			GameModule::bid(RuntimeOrigin::signed([3; 32].into()), 0, 10),
+			Error::<Test>::UserNotRegistered
+		);
+		assert_noop!(
+			GameModule::bid(RuntimeOrigin::signed([1; 32].into()), 0, 9),
+			Error::<Test>::BidTooLow
+		);
+		assert_noop!(
+			GameModule::bid(RuntimeOrigin::signed([1; 32].into()), 0, 2_000),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert_ok!(GameModule::bid(RuntimeOrigin::signed([1; 32].into()), 0, 20));
+		assert_noop!(
+			GameModule::bid(RuntimeOrigin::signed([2; 32].into()), 0, 20),
+			Error::<Test>::BidTooLow
+		);
+		assert_noop!(
+			GameModule::settle_auction(RuntimeOrigin::signed([2; 32].into()), 0),
+			Error::<Test>::AuctionNotEnded
+		);
+		run_to_block(11);
+		assert_noop!(
+			GameModule::bid(RuntimeOrigin::signed([2; 32].into()), 0, 30),
+			Error::<Test>::AuctionDoesNotExist
+		);
+		assert_noop!(
+			GameModule::settle_auction(RuntimeOrigin::signed([2; 32].into()), 0),
+			Error::<Test>::AuctionDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn make_offer_fails_with_too_many_offers() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(2);
+		practise_round([0; 32].into(), 0);
+		win_nft([0; 32].into(), 1);
+		practise_round([1; 32].into(), 2);
+		for game_id in 3..7 {
+			win_nft([1; 32].into(), game_id);
+		}
+		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().points, 835);
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None));
+		for item_id in 1..4 {
+			assert_ok!(GameModule::make_offer(
+				RuntimeOrigin::signed([1; 32].into()),
+				0,
+				0,
+				item_id,
+				None
+			));
+		}
+		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().nfts.xorange, 1);
+		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().points, 155);
+		assert_noop!(
+			GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 4, None),
+			Error::<Test>::TooManyOffers
+		);
+		assert_ok!(GameModule::withdraw_offer(RuntimeOrigin::signed([1; 32].into()), 1));
+		assert_eq!(GameModule::listing_offers(0).into_inner(), vec![0, 2]);
+		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().nfts.xorange, 2);
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 4, None));
+		assert_ok!(GameModule::do_try_state());
+	});
+}
+
+#[test]
+fn play_multiple_rounds_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::add_properties(
+			RuntimeOrigin::root(),
+			new_properties(1..11).try_into().unwrap()
+		));
+		assert_ok!(GameModule::add_properties(
+			RuntimeOrigin::root(),
+			new_properties(11..13).try_into().unwrap()
+		));
+		assert_eq!(GameModule::available_property_count(), 22);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		for x in 1..=20 {
+			assert_ok!(GameModule::play_game(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Player,
+			));
+			assert_ok!(GameModule::submit_answer(
+				RuntimeOrigin::signed([0; 32].into()),
+				217_000,
+				x
+			));
+			System::assert_last_event(
+				Event::AnswerSubmitted { player: [0; 32].into(), game_id: x, guess: 217_000 }
+					.into(),
+			);
+			assert_ok!(GameModule::check_result(
+				RuntimeOrigin::root(),
+				x,
+				220_000,
+				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+			));
+		}
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_eq!(GameModule::game_info(21).is_some(), true);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 555);
+	});
+}
+
+#[test]
+fn start_round_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(GameModule::start_round(RuntimeOrigin::root(), None, Some(20)));
+		System::assert_last_event(Event::RoundStarted { round: 1, end_block: Some(20) }.into());
+		assert_eq!(GameModule::current_round(), 1);
+		assert_eq!(GameModule::round_active(), true);
+		assert_eq!(
+			GameModule::rounds(1).unwrap(),
+			RoundInfo {
+				start_block: 1,
+				end_block: Some(20),
+				closed_block: None,
+				champion: None,
+				games_played: 0,
+				nfts_minted: 0,
+				leaderboard: Default::default(),
+			}
+		);
+	});
+}
+
+#[test]
+fn start_round_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_noop!(
+			GameModule::start_round(RuntimeOrigin::signed([0; 32].into()), None, None),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			GameModule::start_round(RuntimeOrigin::root(), None, Some(5)),
+			Error::<Test>::InvalidRoundSchedule
+		);
+		assert_noop!(
+			GameModule::start_round(RuntimeOrigin::root(), Some(10), Some(8)),
+			Error::<Test>::InvalidRoundSchedule
+		);
+		assert_ok!(GameModule::start_round(RuntimeOrigin::root(), Some(10), None));
+		assert_noop!(
+			GameModule::start_round(RuntimeOrigin::root(), None, None),
+			Error::<Test>::RoundAlreadyScheduled
+		);
+		run_to_block(10);
+		assert_noop!(
+			GameModule::start_round(RuntimeOrigin::root(), None, None),
+			Error::<Test>::RoundAlreadyActive
+		);
+	});
+}
+
+#[test]
+fn scheduled_round_starts_and_ends_in_time() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(GameModule::start_round(RuntimeOrigin::root(), Some(5), Some(30)));
+		System::assert_last_event(
+			Event::RoundScheduled { start_block: 5, end_block: Some(30) }.into(),
 		);
-		assert_ok!(GameModule::check_result(
+		assert_eq!(GameModule::scheduled_round(), Some((5, Some(30))));
+		assert_eq!(GameModule::round_active(), false);
+		run_to_block(4);
+		assert_eq!(GameModule::round_active(), false);
+		run_to_block(5);
+		System::assert_last_event(Event::RoundStarted { round: 1, end_block: Some(30) }.into());
+		assert_eq!(GameModule::scheduled_round(), None);
+		assert_eq!(GameModule::round_active(), true);
+		assert_eq!(GameModule::rounds(1).unwrap().start_block, 5);
+		assert_ok!(GameModule::add_property(
 			RuntimeOrigin::root(),
-			220_000,
-			2,
-			223_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+			new_properties(147_000_000..147_000_001).remove(0)
+		));
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
 		));
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 55);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Pro,
+			crate::DifficultyLevel::Practice,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 0));
+		assert_ok!(GameModule::check_result(
+			RuntimeOrigin::root(),
+			0,
+			220_000,
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
 		));
+		run_to_block(29);
+		assert_eq!(GameModule::round_active(), true);
 		run_to_block(30);
-		System::assert_last_event(Event::NoAnswer { game_id: 3, points: 50 }.into());
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 5);
+		System::assert_last_event(Event::RoundEnded { round: 1, champion: None }.into());
+		assert_eq!(GameModule::round_active(), false);
+		assert_eq!(GameModule::round_champion(1), None);
+		let round = GameModule::rounds(1).unwrap();
+		assert_eq!(round.closed_block, Some(30));
+		assert_eq!(round.games_played, 1);
+		assert_eq!(round.nfts_minted, 0);
+		assert_eq!(points_of(round.leaderboard), vec![([0; 32].into(), 55)]);
 	});
-} */
+}
 
 #[test]
-fn leaderboard_works() {
+fn end_round_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
 		));
-		assert_ok!(GameModule::register_user(
-			RuntimeOrigin::signed([4; 32].into()),
-			[1; 32].into()
-		));
-		assert_ok!(GameModule::register_user(
-			RuntimeOrigin::signed([4; 32].into()),
-			[2; 32].into()
-		));
 		practise_round([0; 32].into(), 0);
-		practise_round([1; 32].into(), 1);
-		practise_round([2; 32].into(), 2);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
 			crate::DifficultyLevel::Player,
 		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 230_000, 3));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 3, guess: 230_000 }.into(),
-		);
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
 		assert_ok!(GameModule::check_result(
 			RuntimeOrigin::root(),
-			230_000,
-			3,
+			1,
 			220_000,
 			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
 		));
-		System::assert_last_event(Event::ResultChecked { game_id: 3, secret: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(), points: 15, won: true, nft_received: false }.into());
+		System::set_block_number(3);
+		assert_ok!(GameModule::end_round(RuntimeOrigin::root()));
+		System::assert_last_event(Event::RoundEnded { round: 1, champion: None }.into());
+		assert_eq!(GameModule::round_active(), false);
+		let round = GameModule::rounds(1).unwrap();
+		assert_eq!(round.closed_block, Some(3));
+		assert_eq!(round.games_played, 2);
+		assert_eq!(round.nfts_minted, 1);
+		assert_eq!(points_of(round.leaderboard), vec![([0; 32].into(), 155)]);
+		assert_eq!(GameModule::round_leaderboard().len(), 0);
+		assert_noop!(
+			GameModule::play_game(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Player,
+			),
+			Error::<Test>::NoActiveRound
+		);
+		assert_ok!(GameModule::start_round(RuntimeOrigin::root(), None, None));
+		assert_eq!(GameModule::current_round(), 2);
+		assert_eq!(GameModule::rounds(2).unwrap().start_block, 3);
 		assert_ok!(GameModule::play_game(
-			RuntimeOrigin::signed([1; 32].into()),
+			RuntimeOrigin::signed([0; 32].into()),
 			crate::DifficultyLevel::Player,
 		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([1; 32].into()), 225_000, 4));
+	});
+}
+
+#[test]
+fn end_round_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(GameModule::end_round(RuntimeOrigin::root()), Error::<Test>::NoActiveRound);
+		setup_game_with_properties();
+		assert_noop!(
+			GameModule::end_round(RuntimeOrigin::signed([0; 32].into())),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn prize_pool_pays_the_round_leaderboard() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(3);
+		let shares: BoundedVec<_, LeaderLimit> =
+			vec![Permill::from_percent(50), Permill::from_percent(30)].try_into().unwrap();
+		assert_ok!(GameModule::set_prize_distribution(RuntimeOrigin::root(), shares.clone()));
+		System::assert_last_event(Event::PrizeDistributionUpdated { shares }.into());
+		EntryFee::set(2);
+		practise_round([0; 32].into(), 0);
+		assert_eq!(GameModule::prize_pool(), 0);
+		win_nft([0; 32].into(), 1);
+		assert_eq!(Balances::free_balance(&[0; 32].into()), 8);
+		assert_eq!(GameModule::prize_pool(), 2);
+		practise_round([1; 32].into(), 2);
+		assert_ok!(GameModule::fund_prize_pool(RuntimeOrigin::signed([2; 32].into()), 4));
 		System::assert_last_event(
-			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 4, guess: 225_000 }.into(),
+			Event::PrizePoolFunded { who: Some([2; 32].into()), amount: 4 }.into(),
+		);
+		assert_eq!(Balances::free_balance(&[2; 32].into()), 6);
+		assert_ok!(GameModule::fund_prize_pool(RuntimeOrigin::root(), 4));
+		System::assert_last_event(Event::PrizePoolFunded { who: None, amount: 4 }.into());
+		assert_eq!(GameModule::prize_pool(), 10);
+		assert_ok!(GameModule::end_round(RuntimeOrigin::root()));
+		System::assert_has_event(
+			Event::PrizeAwarded { round: 1, player: [0; 32].into(), amount: 5 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			225_000,
-			4,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
-		assert_ok!(GameModule::play_game(
-			RuntimeOrigin::signed([2; 32].into()),
-			crate::DifficultyLevel::Player,
-		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([2; 32].into()), 220_000, 5));
 		System::assert_last_event(
-			Event::AnswerSubmitted { player: [2; 32].into(), game_id: 5, guess: 220_000 }.into(),
+			Event::PrizeAwarded { round: 1, player: [1; 32].into(), amount: 3 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			5,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
-		assert_eq!(GameModule::game_info(0).is_none(), true);
-		assert_eq!(GameModule::users::<AccountId>([2; 32].into()).unwrap().points, 155);
-		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().points, 80);
-		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().wins, 1);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 70);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().wins, 1);
-		assert_eq!(GameModule::leaderboard().len(), 3);
-		assert_eq!(GameModule::leaderboard()[0], ([2; 32].into(), 155));
-		assert_eq!(GameModule::leaderboard()[1], ([1; 32].into(), 80));
-		assert_eq!(GameModule::leaderboard()[2], ([0; 32].into(), 70));
+		assert_eq!(GameModule::round_prizes(1, AccountId::from([0; 32])), Some(5));
+		assert_eq!(GameModule::round_prizes(1, AccountId::from([2; 32])), None);
+		assert_eq!(GameModule::prize_pool(), 2);
+		assert_eq!(GameModule::unclaimed_prizes(), 8);
+		assert_ok!(GameModule::claim_prize(RuntimeOrigin::signed([0; 32].into()), 1));
+		System::assert_last_event(
+			Event::PrizeClaimed { round: 1, player: [0; 32].into(), amount: 5 }.into(),
+		);
+		assert_eq!(Balances::free_balance(&[0; 32].into()), 13);
+		assert_eq!(GameModule::round_prizes(1, AccountId::from([0; 32])), None);
+		assert_eq!(GameModule::round_prizes(1, AccountId::from([1; 32])), Some(3));
+		assert_eq!(GameModule::unclaimed_prizes(), 3);
+		assert_ok!(GameModule::do_try_state());
 	});
 }
 
 #[test]
-fn submit_answer_fails() {
+fn prize_pool_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
 		));
+		let shares: BoundedVec<_, LeaderLimit> =
+			vec![Permill::from_percent(60), Permill::from_percent(50)].try_into().unwrap();
+		assert_noop!(
+			GameModule::set_prize_distribution(
+				RuntimeOrigin::signed([0; 32].into()),
+				shares.clone()
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			GameModule::set_prize_distribution(RuntimeOrigin::root(), shares),
+			Error::<Test>::InvalidPrizeDistribution
+		);
+		assert_noop!(
+			GameModule::fund_prize_pool(RuntimeOrigin::root(), 1_000_000),
+			Error::<Test>::InsufficientPalletFunds
+		);
+		assert_noop!(
+			GameModule::fund_prize_pool(RuntimeOrigin::signed([0; 32].into()), 20),
+			sp_runtime::TokenError::FundsUnavailable
+		);
+		assert_noop!(
+			GameModule::claim_prize(RuntimeOrigin::signed([0; 32].into()), 1),
+			Error::<Test>::NoPrize
+		);
 		practise_round([0; 32].into(), 0);
+		EntryFee::set(20);
 		assert_noop!(
-			GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 223_000, 1),
-			Error::<Test>::NoActiveGame
+			GameModule::play_game(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Player
+			),
+			sp_runtime::TokenError::FundsUnavailable
 		);
-		assert_ok!(GameModule::play_game(
-			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
-		));
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			230_000,
-			1,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+	});
+}
+
+#[test]
+fn prize_pool_is_not_funded_with_unclaimed_prizes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(1);
+		let shares: BoundedVec<_, LeaderLimit> =
+			vec![Permill::from_percent(100)].try_into().unwrap();
+		assert_ok!(GameModule::set_prize_distribution(RuntimeOrigin::root(), shares));
+		practise_round([0; 32].into(), 0);
+		let available = Balances::free_balance(&GameModule::account_id()) - 1;
+		assert_ok!(GameModule::fund_prize_pool(RuntimeOrigin::root(), available));
+		assert_ok!(GameModule::end_round(RuntimeOrigin::root()));
+		assert_eq!(GameModule::prize_pool(), 0);
+		assert_eq!(GameModule::unclaimed_prizes(), available);
 		assert_noop!(
-			GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 230_000, 1),
-			Error::<Test>::NoActiveGame
+			GameModule::fund_prize_pool(RuntimeOrigin::root(), 1),
+			Error::<Test>::InsufficientPalletFunds
 		);
+		assert_ok!(GameModule::claim_prize(RuntimeOrigin::signed([0; 32].into()), 1));
+		assert_eq!(GameModule::unclaimed_prizes(), 0);
+		assert_ok!(GameModule::do_try_state());
 	});
 }
 
 #[test]
-fn transfer_of_nft_does_not_work() {
+fn nfts_are_minted_into_the_collections_of_the_round() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			sp_runtime::MultiAddress::Id([5; 32].into()),
+			GameModule::default_collection_config()
+		));
+		setup_game_with_properties();
+		assert_eq!(GameModule::round_collections(1).into_inner(), (1..9).collect::<Vec<u32>>());
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
 		));
-		assert_ok!(GameModule::give_points(RuntimeOrigin::root(), [0; 32].into(), 100));
 		practise_round([0; 32].into(), 0);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
 			crate::DifficultyLevel::Player,
 		));
 		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
-		);
 		assert_ok!(GameModule::check_result(
 			RuntimeOrigin::root(),
-			220_000,
 			1,
 			220_000,
 			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
 		));
-		assert_eq!(GameModule::game_info(1).is_none(), true);
-		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
-		assert_noop!(
-			Nfts::transfer(
-				RuntimeOrigin::signed([0; 32].into()),
-				0,
-				0,
-				sp_runtime::MultiAddress::Id([1; 32].into())
-			),
-			DispatchError::Module(ModuleError {
-				index: 3,
-				error: [12, 0, 0, 0],
-				message: Some("ItemLocked")
-			})
-		);
-	});
-}
-
-#[test]
-fn list_nft_works() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
-		assert_ok!(GameModule::register_user(
-			RuntimeOrigin::signed([4; 32].into()),
-			[0; 32].into()
+		assert_eq!(Nfts::owner(1, 0).unwrap(), [0; 32].into());
+		assert_ok!(GameModule::end_round(RuntimeOrigin::root()));
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			sp_runtime::MultiAddress::Id([5; 32].into()),
+			GameModule::default_collection_config()
 		));
-		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::start_round(RuntimeOrigin::root(), None, None));
+		assert_eq!(GameModule::round_collections(2).into_inner(), (10..18).collect::<Vec<u32>>());
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
 			crate::DifficultyLevel::Player,
 		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
-		);
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 2));
 		assert_ok!(GameModule::check_result(
 			RuntimeOrigin::root(),
-			220_000,
-			1,
+			2,
 			220_000,
 			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
 		));
-		assert_eq!(GameModule::game_info(1).is_none(), true);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
-		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
-		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0,));
-		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
-		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
+		assert_eq!(Nfts::owner(10, 0).unwrap(), [0; 32].into());
+		assert_eq!(GameModule::collection_color(10), Some(NftColor::Xorange));
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xorange, 1);
+	});
+}
+
+#[test]
+fn add_property_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		let data: BoundedVec<u8, ConstU32<5000>> =
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap();
+		let new_property = PropertyInfoData {
+			id: 147000000,
+			data: data.clone(),
+			commitment: GameModule::property_commitment(300_000, "salt".as_bytes(), &data),
+			metadata: property_metadata(),
+		};
+		assert_ok!(GameModule::add_property(RuntimeOrigin::root(), new_property.clone()));
+		assert_eq!(GameModule::available_property_count(), 11);
+		assert_eq!(
+			GameModule::game_properties(147000000).unwrap(),
+			PropertyRecord { property: new_property, status: PropertyStatus::Available }
+		);
+		System::assert_last_event(Event::PropertyAdded { property_id: 147000000 }.into());
+	});
+}
+
+#[test]
+fn add_property_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		let data: BoundedVec<u8, ConstU32<5000>> =
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap();
+		let new_property = PropertyInfoData {
+			id: 147031382,
+			data: data.clone(),
+			commitment: GameModule::property_commitment(300_000, "salt".as_bytes(), &data),
+			metadata: property_metadata(),
+		};
+		assert_noop!(
+			GameModule::add_property(RuntimeOrigin::signed([0; 32].into()), new_property.clone()),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			GameModule::add_property(RuntimeOrigin::root(), new_property),
+			Error::<Test>::PropertyAlreadyExists
+		);
+	});
+}
+
+fn new_properties(ids: core::ops::Range<u32>) -> Vec<PropertyInfoData<Test>> {
+	let data: BoundedVec<u8, ConstU32<5000>> =
+		"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap();
+	ids.map(|id| PropertyInfoData {
+		id,
+		data: data.clone(),
+		commitment: GameModule::property_commitment(220_000, &data, &data),
+		metadata: property_metadata(),
+	})
+	.collect()
+}
+
+#[test]
+fn add_properties_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::add_properties(
+			RuntimeOrigin::root(),
+			new_properties(1..11).try_into().unwrap()
+		));
+		assert_eq!(GameModule::available_property_count(), 20);
+		assert_eq!(GameModule::game_properties(10).unwrap().status, PropertyStatus::Available);
+		System::assert_has_event(Event::PropertyAdded { property_id: 1 }.into());
+		System::assert_last_event(Event::PropertyAdded { property_id: 10 }.into());
+	});
+}
+
+#[test]
+fn add_properties_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_noop!(
+			GameModule::add_properties(
+				RuntimeOrigin::signed([0; 32].into()),
+				new_properties(1..3).try_into().unwrap()
+			),
+			Error::<Test>::NoPermission
+		);
+		let mut properties = new_properties(1..3);
+		properties.extend(new_properties(2..3));
+		assert_noop!(
+			GameModule::add_properties(RuntimeOrigin::root(), properties.try_into().unwrap()),
+			Error::<Test>::PropertyAlreadyExists
+		);
+		assert_eq!(GameModule::available_property_count(), 10);
 	});
 }
 
 #[test]
-fn list_nft_doesnt_work() {
-	new_test_ext().execute_with(|| {
+fn genesis_config_works() {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(GameModule::account_id(), 1_000_000)],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	crate::GenesisConfig::<Test> {
+		roles: vec![(Role::Registrar, [4; 32].into())],
+		properties: new_properties(1..4),
+		players: vec![([0; 32].into(), 100), ([1; 32].into(), 50)],
+		start_round: true,
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	sp_io::TestExternalities::new(storage).execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert!(GameModule::has_role(&[4; 32].into(), Role::Registrar));
+		assert_eq!(GameModule::available_property_count(), 3);
+		assert_eq!(GameModule::game_properties(2).unwrap().status, PropertyStatus::Available);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 100);
+		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().points, 50);
+		assert_eq!(GameModule::current_round(), 1);
+		assert_eq!(GameModule::round_active(), true);
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
-			[0; 32].into()
+			[2; 32].into()
 		));
-		practise_round([0; 32].into(), 0);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
+			crate::DifficultyLevel::Practice,
 		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
+	});
+}
+
+#[test]
+fn remove_property_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::remove_property(RuntimeOrigin::root(), 146480642));
+		assert_eq!(GameModule::available_property_count(), 9);
+		assert_eq!(GameModule::game_properties(146480642).unwrap().status, PropertyStatus::Retired);
+		System::assert_last_event(Event::PropertyRetired { property_id: 146480642 }.into());
+		assert_noop!(
+			GameModule::remove_property(RuntimeOrigin::root(), 146480642),
+			Error::<Test>::PropertyAlreadyRetired
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			1,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
-		assert_eq!(GameModule::game_info(1).is_none(), true);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
-		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
 		assert_noop!(
-			GameModule::list_nft(RuntimeOrigin::signed([1; 32].into()), 0, 0,),
-			Error::<Test>::NoPermission
+			GameModule::remove_property(RuntimeOrigin::root(), 1),
+			Error::<Test>::NoProperty
 		);
 	});
 }
 
 #[test]
-fn delist_nft_works() {
+fn property_is_revealed_after_settlement_and_returned_after_expiry() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
 		));
-		practise_round([0; 32].into(), 0);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
+			crate::DifficultyLevel::Practice,
 		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
+		let property_id = GameModule::game_info(0).unwrap().property.id;
+		assert_eq!(GameModule::available_property_count(), 9);
+		assert_eq!(
+			GameModule::game_properties(property_id).unwrap().status,
+			PropertyStatus::InPlay
 		);
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 0));
 		assert_ok!(GameModule::check_result(
 			RuntimeOrigin::root(),
-			220_000,
-			1,
+			0,
 			220_000,
 			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
 		));
-		assert_eq!(GameModule::game_info(0).is_none(), true);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
-		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
-		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0,));
-		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
-		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
-		assert_ok!(GameModule::delist_nft(RuntimeOrigin::signed([0; 32].into()), 0,));
+		assert_eq!(GameModule::available_property_count(), 9);
+		assert_eq!(
+			GameModule::game_properties(property_id).unwrap().status,
+			PropertyStatus::Revealed
+		);
+		assert_eq!(crate::AvailablePropertySlot::<Test>::get(property_id), None);
 		assert_noop!(
-			Nfts::transfer(
-				RuntimeOrigin::signed([0; 32].into()),
-				0,
-				0,
-				sp_runtime::MultiAddress::Id([1; 32].into())
-			),
-			DispatchError::Module(ModuleError {
-				index: 3,
-				error: [12, 0, 0, 0],
-				message: Some("ItemLocked")
-			})
+			GameModule::remove_property(RuntimeOrigin::root(), property_id),
+			Error::<Test>::PropertyAlreadyRetired
+		);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Practice,
+		));
+		let property_id = GameModule::game_info(1).unwrap().property.id;
+		run_to_block(12);
+		assert_eq!(GameModule::available_property_count(), 9);
+		assert_eq!(
+			GameModule::game_properties(property_id).unwrap().status,
+			PropertyStatus::Available
 		);
 	});
 }
 
 #[test]
-fn delist_nft_doesnt_works() {
+fn settled_property_is_never_drawn_again() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
 		));
-		practise_round([0; 32].into(), 0);
-		assert_ok!(GameModule::play_game(
-			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
-		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
-		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			1,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
-		assert_eq!(GameModule::game_info(0).is_none(), true);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
-		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
-		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0,));
-		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
-		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
+		let mut revealed = Vec::new();
+		for game_id in 0..10 {
+			let difficulty = if game_id == 0 {
+				crate::DifficultyLevel::Practice
+			} else {
+				crate::DifficultyLevel::Player
+			};
+			assert_ok!(GameModule::play_game(RuntimeOrigin::signed([0; 32].into()), difficulty));
+			let property_id = GameModule::game_info(game_id).unwrap().property.id;
+			assert!(!revealed.contains(&property_id));
+			assert_ok!(GameModule::submit_answer(
+				RuntimeOrigin::signed([0; 32].into()),
+				220_000,
+				game_id
+			));
+			assert_ok!(GameModule::check_result(
+				RuntimeOrigin::root(),
+				game_id,
+				220_000,
+				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+			));
+			assert_eq!(
+				GameModule::game_properties(property_id).unwrap().status,
+				PropertyStatus::Revealed
+			);
+			assert_eq!(crate::AvailablePropertySlot::<Test>::get(property_id), None);
+			revealed.push(property_id);
+		}
+		assert_eq!(GameModule::available_property_count(), 0);
 		assert_noop!(
-			GameModule::delist_nft(RuntimeOrigin::signed([1; 32].into()), 0,),
-			Error::<Test>::NoPermission
+			GameModule::play_game(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Player,
+			),
+			Error::<Test>::NoProperty
 		);
 	});
 }
 
 #[test]
-fn make_offer_works() {
+fn retired_property_in_play_is_not_returned() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
 		));
-		assert_ok!(GameModule::register_user(
-			RuntimeOrigin::signed([4; 32].into()),
-			[1; 32].into()
-		));
-		practise_round([0; 32].into(), 0);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
-		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
-		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			1,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
-		assert_eq!(GameModule::game_info(0).is_none(), true);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
-		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
-		practise_round([1; 32].into(), 2);
-		assert_ok!(GameModule::play_game(
-			RuntimeOrigin::signed([1; 32].into()),
-			crate::DifficultyLevel::Player,
+			crate::DifficultyLevel::Practice,
 		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([1; 32].into()), 220_000, 3));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 3, guess: 220_000 }.into(),
+		let property_id = GameModule::game_info(0).unwrap().property.id;
+		assert_ok!(GameModule::remove_property(RuntimeOrigin::root(), property_id));
+		assert_eq!(GameModule::available_property_count(), 9);
+		run_to_block(12);
+		assert_eq!(GameModule::active_games().len(), 0);
+		assert_eq!(GameModule::available_property_count(), 9);
+		assert_eq!(
+			GameModule::game_properties(property_id).unwrap().status,
+			PropertyStatus::Retired
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			3,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+	});
+}
+
+#[test]
+fn request_token_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
 		));
-		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
-		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0,));
-		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
-		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
-		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1,));
-		assert_eq!(GameModule::offers(0).unwrap().owner, [1; 32].into());
+		System::assert_has_event(Event::FaucetDrip { player: [0; 32].into(), amount: 10 }.into());
+		assert_eq!(Balances::free_balance(&([0; 32].into())), 10);
+		Balances::make_free_balance_be(&[0; 32].into(), 4);
+		let total_issuance = Balances::total_issuance();
+		System::set_block_number(100802);
+		assert_ok!(GameModule::request_token(RuntimeOrigin::signed([0; 32].into())));
+		System::assert_has_event(Event::FaucetDrip { player: [0; 32].into(), amount: 6 }.into());
+		System::assert_last_event(Event::TokenReceived { player: [0; 32].into() }.into());
+		assert_eq!(Balances::free_balance(&([0; 32].into())), 10);
+		assert_eq!(Balances::free_balance(&GameModule::account_id()), 999_984);
+		assert_eq!(Balances::total_issuance(), total_issuance);
+		assert_eq!(GameModule::faucet_usage(), (100802, 6));
 	});
 }
 
 #[test]
-fn make_offer_doesnt_works() {
+fn request_token_doesnt_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		setup_game_with_properties();
+		assert_noop!(
+			GameModule::request_token(RuntimeOrigin::signed([0; 32].into())),
+			Error::<Test>::UserNotRegistered
+		);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
 		));
-		assert_ok!(GameModule::give_points(RuntimeOrigin::root(), [0; 32].into(), 100));
+		assert_noop!(
+			GameModule::request_token(RuntimeOrigin::signed([0; 32].into())),
+			Error::<Test>::CantRequestToken
+		);
+		System::set_block_number(100802);
+		assert_noop!(
+			GameModule::request_token(RuntimeOrigin::signed([0; 32].into())),
+			Error::<Test>::FaucetTargetReached
+		);
+	});
+}
+
+#[test]
+fn faucet_respects_the_target_and_the_cap() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		FaucetCap::set(25);
+		setup_game_with_properties();
+		Balances::make_free_balance_be(&[0; 32].into(), 1_000);
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()));
+		assert_eq!(Balances::free_balance(&([0; 32].into())), 1_000);
+		Balances::make_free_balance_be(&[1; 32].into(), 3);
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [1; 32].into()));
+		assert_eq!(Balances::free_balance(&([1; 32].into())), 10);
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [2; 32].into()));
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [3; 32].into()));
+		assert_eq!(Balances::free_balance(&([2; 32].into())), 10);
+		assert_eq!(Balances::free_balance(&([3; 32].into())), 8);
+		assert_eq!(GameModule::faucet_usage(), (1, 25));
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [5; 32].into()));
+		assert_eq!(Balances::free_balance(&([5; 32].into())), 0);
+		System::set_block_number(200);
+		assert_noop!(
+			GameModule::request_token(RuntimeOrigin::signed([3; 32].into())),
+			Error::<Test>::FaucetCapReached
+		);
+		System::set_block_number(DAYS + 1);
+		assert_ok!(GameModule::request_token(RuntimeOrigin::signed([3; 32].into())));
+		assert_eq!(Balances::free_balance(&([3; 32].into())), 10);
+		assert_eq!(GameModule::faucet_usage(), (DAYS + 1, 2));
+	});
+}
+
+#[test]
+fn faucet_does_not_drip_the_prize_pool() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::fund_prize_pool(RuntimeOrigin::root(), 999_999));
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()));
+		assert_eq!(Balances::free_balance(&([0; 32].into())), 0);
+		System::set_block_number(200);
+		assert_noop!(
+			GameModule::request_token(RuntimeOrigin::signed([0; 32].into())),
+			Error::<Test>::FaucetDepleted
+		);
+		assert_eq!(Balances::free_balance(&GameModule::account_id()), 1_000_000);
+	});
+}
+
+#[test]
+fn faucet_does_not_drip_unclaimed_prizes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		register_players(2);
+		let shares: BoundedVec<_, LeaderLimit> =
+			vec![Permill::from_percent(100)].try_into().unwrap();
+		assert_ok!(GameModule::set_prize_distribution(RuntimeOrigin::root(), shares));
 		practise_round([0; 32].into(), 0);
-		assert_ok!(GameModule::play_game(
-			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
-		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		let available = Balances::free_balance(&GameModule::account_id()) - 1;
+		assert_ok!(GameModule::fund_prize_pool(RuntimeOrigin::root(), available));
+		assert_ok!(GameModule::end_round(RuntimeOrigin::root()));
+		assert_eq!(GameModule::prize_pool(), 0);
+		assert_eq!(GameModule::unclaimed_prizes(), available);
+		Balances::make_free_balance_be(&[1; 32].into(), 0);
+		System::set_block_number(200);
 		assert_noop!(
-			GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 0,),
-			Error::<Test>::ListingDoesNotExist
+			GameModule::request_token(RuntimeOrigin::signed([1; 32].into())),
+			Error::<Test>::FaucetDepleted
 		);
+		assert_ok!(GameModule::claim_prize(RuntimeOrigin::signed([0; 32].into()), 1));
+		assert_eq!(Balances::free_balance(&GameModule::account_id()), 1);
 	});
 }
 
 #[test]
-fn withdraw_offer_works() {
+fn check_result_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		setup_game_with_properties();
+		assert_eq!(GameModule::available_property_count(), 10);
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
 		));
-		assert_ok!(GameModule::register_user(
-			RuntimeOrigin::signed([4; 32].into()),
-			[1; 32].into()
-		));
 		practise_round([0; 32].into(), 0);
-		assert_ok!(GameModule::play_game(
-			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
-		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
+		assert_noop!(
+			GameModule::check_result(
+				RuntimeOrigin::root(),
+				0,
+				220_000,
+				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+			),
+			Error::<Test>::NoActiveGame
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			1,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
-		assert_eq!(GameModule::game_info(0).is_none(), true);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().wins, 1);
-		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
-		practise_round([1; 32].into(), 2);
-		assert_ok!(GameModule::play_game(
-			RuntimeOrigin::signed([1; 32].into()),
-			crate::DifficultyLevel::Player,
-		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([1; 32].into()), 220_000, 3));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 3, guess: 220_000 }.into(),
+		assert_noop!(
+			GameModule::check_result(
+				RuntimeOrigin::root(),
+				1,
+				220_000,
+				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+			),
+			Error::<Test>::NoActiveGame
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			3,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
-		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
-		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0,));
-		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
-		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
-		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1,));
-		assert_eq!(GameModule::offers(0).unwrap().owner, [1; 32].into());
-		assert_ok!(GameModule::withdraw_offer(RuntimeOrigin::signed([1; 32].into()), 0));
-		assert_eq!(GameModule::offers(0).is_none(), true);
 	});
 }
 
 #[test]
-fn withdraw_offer_fails() {
+fn check_result_fails_with_wrong_reveal() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
 		));
-		assert_ok!(GameModule::register_user(
-			RuntimeOrigin::signed([4; 32].into()),
-			[1; 32].into()
-		));
 		practise_round([0; 32].into(), 0);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
 			crate::DifficultyLevel::Player,
 		));
 		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
+		assert_noop!(
+			GameModule::check_result(
+				RuntimeOrigin::root(),
+				1,
+				221_000,
+				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+			),
+			Error::<Test>::CommitmentMismatch
+		);
+		assert_noop!(
+			GameModule::check_result(
+				RuntimeOrigin::root(),
+				1,
+				220_000,
+				"wrong secret".as_bytes().to_vec().try_into().unwrap()
+			),
+			Error::<Test>::CommitmentMismatch
 		);
 		assert_ok!(GameModule::check_result(
 			RuntimeOrigin::root(),
-			220_000,
 			1,
 			220_000,
 			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
 		));
-		assert_eq!(GameModule::game_info(0).is_none(), true);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
-		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
-		practise_round([1; 32].into(), 2);
-		assert_ok!(GameModule::play_game(
-			RuntimeOrigin::signed([1; 32].into()),
-			crate::DifficultyLevel::Player,
+	});
+}
+
+#[test]
+fn roles_gate_privileged_calls() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(GameModule::grant_role(
+			RuntimeOrigin::root(),
+			Role::RoundManager,
+			[5; 32].into()
 		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([1; 32].into()), 220_000, 3));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 3, guess: 220_000 }.into(),
-		);
-		assert_ok!(GameModule::check_result(
+		assert_ok!(GameModule::grant_role(
 			RuntimeOrigin::root(),
-			220_000,
-			3,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+			Role::PropertyCurator,
+			[6; 32].into()
 		));
-		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
-		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0,));
-		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
-		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [7; 32].into()));
 		assert_noop!(
-			GameModule::withdraw_offer(RuntimeOrigin::signed([0; 32].into()), 0),
-			Error::<Test>::OfferDoesNotExist
+			GameModule::setup_game(RuntimeOrigin::signed([6; 32].into())),
+			Error::<Test>::NoPermission
 		);
-		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1,));
-		assert_eq!(GameModule::offers(0).unwrap().owner, [1; 32].into());
+		assert_ok!(GameModule::setup_game(RuntimeOrigin::signed([5; 32].into())));
+		add_test_properties();
 		assert_noop!(
-			GameModule::withdraw_offer(RuntimeOrigin::signed([0; 32].into()), 0),
+			GameModule::register_user(RuntimeOrigin::signed([5; 32].into()), [0; 32].into()),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([7; 32].into()),
+			[1; 32].into()
+		));
+		assert_noop!(
+			GameModule::remove_property(RuntimeOrigin::signed([5; 32].into()), 146480642),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(GameModule::remove_property(RuntimeOrigin::signed([6; 32].into()), 146480642));
+		assert_noop!(
+			GameModule::give_points(RuntimeOrigin::signed([6; 32].into()), [0; 32].into(), 10),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(GameModule::give_points(
+			RuntimeOrigin::signed([5; 32].into()),
+			[0; 32].into(),
+			10
+		));
+		assert_ok!(GameModule::end_round(RuntimeOrigin::signed([5; 32].into())));
+		assert_ok!(GameModule::revoke_role(
+			RuntimeOrigin::root(),
+			Role::RoundManager,
+			[5; 32].into()
+		));
+		assert_noop!(
+			GameModule::start_round(RuntimeOrigin::signed([5; 32].into()), None, None),
 			Error::<Test>::NoPermission
 		);
 	});
 }
 
 #[test]
-fn handle_offer_accept_works() {
+fn marketplace_moderator_removes_listings_and_offers() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::grant_role(
+			RuntimeOrigin::root(),
+			Role::MarketplaceModerator,
+			[5; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -898,422 +4196,623 @@ fn handle_offer_accept_works() {
 			RuntimeOrigin::signed([4; 32].into()),
 			[1; 32].into()
 		));
+		give_nft([0; 32].into(), 0);
+		give_nft([1; 32].into(), 1);
+		give_nft([1; 32].into(), 2);
+		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0, None, None));
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 1, 0, None));
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 2, 0, None));
+		assert_noop!(
+			GameModule::withdraw_offer(RuntimeOrigin::signed([4; 32].into()), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(GameModule::withdraw_offer(RuntimeOrigin::signed([5; 32].into()), 0));
+		assert_eq!(Nfts::owner(1, 0).unwrap(), [1; 32].into());
+		System::assert_last_event(
+			Event::OfferWithdrawn { owner: [1; 32].into(), offer_id: 0 }.into(),
+		);
+		assert_noop!(
+			GameModule::delist_nft(RuntimeOrigin::signed([4; 32].into()), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(GameModule::delist_nft(RuntimeOrigin::signed([5; 32].into()), 0));
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
+		assert_eq!(Nfts::owner(2, 0).unwrap(), [1; 32].into());
+		assert_eq!(GameModule::listings(0), None);
+		assert_eq!(GameModule::offers(1), None);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xorange, 1);
+	});
+}
+
+#[test]
+fn submit_result_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Settler, [5; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
 		practise_round([0; 32].into(), 0);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
 			crate::DifficultyLevel::Player,
 		));
 		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
-		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
+		run_to_block(11);
+		assert_ok!(GameModule::submit_result(
+			RuntimeOrigin::signed([5; 32].into()),
 			1,
 			220_000,
 			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
 		));
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
-		assert_eq!(GameModule::game_info(0).is_none(), true);
-		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
-		practise_round([1; 32].into(), 2);
-		assert_ok!(GameModule::play_game(
-			RuntimeOrigin::signed([1; 32].into()),
-			crate::DifficultyLevel::Player,
-		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([1; 32].into()), 220_000, 3));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 3, guess: 220_000 }.into(),
-		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			3,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		assert_eq!(GameModule::game_info(1), None);
+		assert_eq!(GameModule::active_games().len(), 0);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xorange, 1);
+	});
+}
+
+#[test]
+fn submit_result_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Settler, [5; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
 		));
+		practise_round([0; 32].into(), 0);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
 			crate::DifficultyLevel::Player,
 		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 4));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 4, guess: 220_000 }.into(),
+		assert_noop!(
+			GameModule::submit_result(
+				RuntimeOrigin::signed([5; 32].into()),
+				1,
+				220_000,
+				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+			),
+			Error::<Test>::NoGuess
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			4,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 275);
-		assert_ok!(GameModule::play_game(
-			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
-		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 5));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 5, guess: 220_000 }.into(),
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		assert_noop!(
+			GameModule::submit_result(
+				RuntimeOrigin::signed([5; 32].into()),
+				1,
+				220_000,
+				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+			),
+			Error::<Test>::AnswerWindowOpen
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			5,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xorange, 3);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 495);
-		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().nfts.xorange, 1);
-		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
-		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0,));
-		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
-		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
-		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1,));
-		assert_eq!(GameModule::offers(0).unwrap().owner, [1; 32].into());
-		assert_ok!(GameModule::handle_offer(
-			RuntimeOrigin::signed([0; 32].into()),
-			0,
-			crate::Offer::Accept,
-		));
-		assert_eq!(Nfts::owner(0, 0).unwrap(), [1; 32].into());
-		assert_eq!(Nfts::owner(0, 1).unwrap(), [0; 32].into());
-		assert_eq!(GameModule::offers(0).is_none(), true);
-		assert_eq!(GameModule::listings(0).is_none(), true);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xorange, 3);
-		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().nfts.xorange, 1);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 495);
-		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().points, 155);
+		run_to_block(11);
 		assert_noop!(
-			Nfts::transfer(
+			GameModule::submit_result(
 				RuntimeOrigin::signed([0; 32].into()),
-				0,
 				1,
-				sp_runtime::MultiAddress::Id([1; 32].into())
+				220_000,
+				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
 			),
-			DispatchError::Module(ModuleError {
-				index: 3,
-				error: [12, 0, 0, 0],
-				message: Some("ItemLocked")
-			})
+			Error::<Test>::NoPermission
 		);
 		assert_noop!(
-			Nfts::transfer(
-				RuntimeOrigin::signed([1; 32].into()),
-				0,
-				0,
-				sp_runtime::MultiAddress::Id([1; 32].into())
+			GameModule::submit_result(
+				RuntimeOrigin::signed([5; 32].into()),
+				1,
+				221_000,
+				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
 			),
-			DispatchError::Module(ModuleError {
-				index: 3,
-				error: [12, 0, 0, 0],
-				message: Some("ItemLocked")
-			})
+			Error::<Test>::CommitmentMismatch
 		);
+	});
+}
+
+#[test]
+fn offchain_worker_submits_result() {
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let (mut t, public) = offchain_test_ext(pool);
+	t.execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		let oracle = MultiSigner::from(public).into_account();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Settler, oracle));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
 			crate::DifficultyLevel::Player,
 		));
-		System::assert_last_event(
-			Event::GameStarted { player: [0; 32].into(), game_id: 6 }.into(),
+		let secret: BoundedVec<u8, ConstU32<5000>> =
+			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap();
+		let entry = PriceFeedEntry { price: 220_000, secret: secret.to_vec() };
+		let property_id = GameModule::game_info(1).unwrap().property.id;
+		sp_io::offchain::local_storage_set(
+			StorageKind::PERSISTENT,
+			&GameModule::price_feed_key(property_id),
+			&entry.encode(),
 		);
-		run_to_block(20);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			6,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+		GameModule::offchain_worker(System::block_number());
+		assert!(pool_state.read().transactions.is_empty());
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		GameModule::offchain_worker(System::block_number());
+		assert!(pool_state.read().transactions.is_empty());
+		run_to_block(11);
+		GameModule::offchain_worker(System::block_number());
+		let tx = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(tx.signature.unwrap().0, 0);
+		assert_eq!(
+			tx.call,
+			RuntimeCall::GameModule(crate::Call::submit_result {
+				game_id: 1,
+				price: 220_000,
+				secret
+			})
+		);
+		GameModule::offchain_worker(System::block_number());
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
+
+#[test]
+fn offchain_worker_ignores_wrong_price_feed() {
+	let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+	let (mut t, _) = offchain_test_ext(pool);
+	t.execute_with(|| {
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
 		));
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xorange, 3);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 470);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().wins, 3);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().losses, 1);
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		let entry = PriceFeedEntry { price: 221_000, secret: "wrong secret".as_bytes().to_vec() };
+		let property_id = GameModule::game_info(1).unwrap().property.id;
+		sp_io::offchain::local_storage_set(
+			StorageKind::PERSISTENT,
+			&GameModule::price_feed_key(property_id),
+			&entry.encode(),
+		);
+		run_to_block(11);
+		GameModule::offchain_worker(System::block_number());
+		assert!(pool_state.read().transactions.is_empty());
 	});
 }
 
 #[test]
-fn handle_offer_reject_works() {
+fn set_scoring_config_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
 		));
-		assert_ok!(GameModule::register_user(
-			RuntimeOrigin::signed([4; 32].into()),
-			[1; 32].into()
-		));
 		practise_round([0; 32].into(), 0);
-		assert_ok!(GameModule::play_game(
-			RuntimeOrigin::signed([0; 32].into()),
+		let config = crate::ScoringConfig::<Test> {
+			entry_points: 60,
+			nft_threshold: None,
+			bands: vec![crate::ScoreBand {
+				max_difference: 100,
+				outcome: crate::ScoreOutcome::Reward(7),
+			}]
+			.try_into()
+			.unwrap(),
+			miss: crate::ScoreOutcome::Penalty(3),
+			no_answer_penalty: 4,
+		};
+		assert_ok!(GameModule::set_scoring_config(
+			RuntimeOrigin::root(),
 			crate::DifficultyLevel::Player,
+			config.clone()
 		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
 		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
+			Event::ScoringConfigUpdated {
+				difficulty: crate::DifficultyLevel::Player,
+				config: config.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(GameModule::scoring_config(crate::DifficultyLevel::Player), config);
+		assert_noop!(
+			GameModule::play_game(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Player,
+			),
+			Error::<Test>::NotEnoughPoints
 		);
+		assert_ok!(GameModule::set_scoring_config(
+			RuntimeOrigin::root(),
+			crate::DifficultyLevel::Player,
+			crate::ScoringConfig { entry_points: 30, ..config }
+		));
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 210_000, 1));
 		assert_ok!(GameModule::check_result(
 			RuntimeOrigin::root(),
-			220_000,
 			1,
 			220_000,
 			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
 		));
-		assert_eq!(GameModule::game_info(0).is_none(), true);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
-		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
-		practise_round([1; 32].into(), 2);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 62);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xorange, 0);
 		assert_ok!(GameModule::play_game(
-			RuntimeOrigin::signed([1; 32].into()),
+			RuntimeOrigin::signed([0; 32].into()),
 			crate::DifficultyLevel::Player,
 		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([1; 32].into()), 220_000, 3));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 3, guess: 220_000 }.into(),
-		);
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 100_000, 2));
 		assert_ok!(GameModule::check_result(
 			RuntimeOrigin::root(),
-			220_000,
-			3,
+			2,
 			220_000,
 			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
 		));
-		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
-		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0,));
-		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
-		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
-		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1,));
-		assert_eq!(GameModule::offers(0).unwrap().owner, [1; 32].into());
-		assert_ok!(GameModule::handle_offer(
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 59);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().losses, 1);
+		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
-			0,
-			crate::Offer::Reject,
+			crate::DifficultyLevel::Player,
 		));
-		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
-		assert_eq!(GameModule::offers(0).is_none(), true);
-		assert_eq!(GameModule::listings(0).is_some(), true);
+		run_to_block(11);
+		System::assert_last_event(Event::NoAnswer { game_id: 3, points: 4 }.into());
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 55);
+	});
+}
+
+#[test]
+fn set_scoring_config_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let mut config = crate::ScoringConfig::<Test>::default_for(crate::DifficultyLevel::Pro);
 		assert_noop!(
-			Nfts::transfer(
-				RuntimeOrigin::signed([1; 32].into()),
-				0,
-				1,
-				sp_runtime::MultiAddress::Id([1; 32].into())
+			GameModule::set_scoring_config(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Pro,
+				config.clone()
 			),
-			DispatchError::Module(ModuleError {
-				index: 3,
-				error: [12, 0, 0, 0],
-				message: Some("ItemLocked")
-			})
+			Error::<Test>::NoPermission
+		);
+		config.nft_threshold = Some(40);
+		assert_noop!(
+			GameModule::set_scoring_config(
+				RuntimeOrigin::root(),
+				crate::DifficultyLevel::Pro,
+				config.clone()
+			),
+			Error::<Test>::InvalidScoringConfig
+		);
+		config.nft_threshold = Some(10);
+		config.bands = config.bands.into_iter().rev().collect::<Vec<_>>().try_into().unwrap();
+		assert_noop!(
+			GameModule::set_scoring_config(
+				RuntimeOrigin::root(),
+				crate::DifficultyLevel::Pro,
+				config
+			),
+			Error::<Test>::InvalidScoringConfig
+		);
+		let mut config =
+			crate::ScoringConfig::<Test>::default_for(crate::DifficultyLevel::Practice);
+		config.nft_threshold = Some(10);
+		assert_noop!(
+			GameModule::set_scoring_config(
+				RuntimeOrigin::root(),
+				crate::DifficultyLevel::Practice,
+				config.clone()
+			),
+			Error::<Test>::InvalidScoringConfig
+		);
+		config.nft_threshold = None;
+		config.entry_points = 5;
+		assert_noop!(
+			GameModule::set_scoring_config(
+				RuntimeOrigin::root(),
+				crate::DifficultyLevel::Practice,
+				config
+			),
+			Error::<Test>::InvalidScoringConfig
 		);
 	});
 }
 
 #[test]
-fn handle_offer_doesnt_works() {
+fn no_answer_penalty_saturates_at_zero() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
 		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Pro,
+		));
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		run_to_block(11);
+		System::assert_has_event(Event::NoAnswer { game_id: 1, points: 50 }.into());
+		System::assert_last_event(Event::NoAnswer { game_id: 2, points: 5 }.into());
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 0);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().losses, 2);
+		assert_eq!(GameModule::active_games().len(), 0);
+		assert_eq!(GameModule::player_games::<AccountId>([0; 32].into()).len(), 0);
+	});
+}
+
+#[test]
+fn penalty_stops_at_points_floor() {
+	new_test_ext().execute_with(|| {
+		PointsFloor::set(20);
+		System::set_block_number(1);
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
-			[1; 32].into()
+			[0; 32].into()
 		));
 		practise_round([0; 32].into(), 0);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
+			crate::DifficultyLevel::Pro,
 		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
-		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
-		);
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 100_000, 1));
 		assert_ok!(GameModule::check_result(
 			RuntimeOrigin::root(),
-			220_000,
 			1,
 			220_000,
 			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
 		));
-		assert_eq!(GameModule::game_info(0).is_none(), true);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
-		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
-		practise_round([1; 32].into(), 2);
-		assert_ok!(GameModule::play_game(
-			RuntimeOrigin::signed([1; 32].into()),
-			crate::DifficultyLevel::Player,
-		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([1; 32].into()), 220_000, 3));
 		System::assert_last_event(
-			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 3, guess: 220_000 }.into(),
-		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			3,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
-		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
-		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0,));
-		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
-		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
-		assert_noop!(
-			GameModule::handle_offer(
-				RuntimeOrigin::signed([0; 32].into()),
-				0,
-				crate::Offer::Reject,
-			),
-			Error::<Test>::OfferDoesNotExist
+			Event::ResultChecked {
+				game_id: 1,
+				secret: "nfdjakl;fueif;janf,dnfm,dhfhfdksks"
+					.as_bytes()
+					.to_vec()
+					.try_into()
+					.unwrap(),
+				points: 35,
+				won: false,
+				nft_received: false,
+			}
+			.into(),
 		);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 20);
+		assert_eq!(GameModule::game_info(1), None);
+		assert_eq!(GameModule::active_games().len(), 0);
 	});
 }
 
 #[test]
-fn play_multiple_rounds_works() {
+fn listing_a_nft_stops_at_points_floor() {
 	new_test_ext().execute_with(|| {
+		PointsFloor::set(20);
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		setup_game_with_properties();
+		assert_ok!(GameModule::grant_role(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
 		));
-		practise_round([0; 32].into(), 0);
-		for x in 1..=20 {
-			assert_ok!(GameModule::play_game(
-				RuntimeOrigin::signed([0; 32].into()),
-				crate::DifficultyLevel::Player,
-			));
-			assert_ok!(GameModule::submit_answer(
-				RuntimeOrigin::signed([0; 32].into()),
-				217_000,
-				x
-			));
-			System::assert_last_event(
-				Event::AnswerSubmitted { player: [0; 32].into(), game_id: x, guess: 217_000 }
-					.into(),
-			);
-			assert_ok!(GameModule::check_result(
-				RuntimeOrigin::root(),
-				217_000,
-				x,
-				220_000,
-				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-			));
-		}
-		assert_ok!(GameModule::play_game(
+		let (collection_id, item_id) = give_nft([0; 32].into(), 0);
+		crate::Users::<Test>::mutate(AccountId::from([0; 32]), |user| {
+			user.as_mut().unwrap().points = 60
+		});
+		assert_ok!(GameModule::list_nft(
 			RuntimeOrigin::signed([0; 32].into()),
-			crate::DifficultyLevel::Player,
+			collection_id,
+			item_id,
+			None,
+			None
 		));
-		assert_eq!(GameModule::game_info(21).is_some(), true);
-		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 555);
+		let user = GameModule::users::<AccountId>([0; 32].into()).unwrap();
+		assert_eq!(user.points, 20);
+		assert_eq!(user.nfts.xorange, 0);
+		assert_eq!(
+			GameModule::escrow_depositors(collection_id, item_id),
+			Some(crate::EscrowDeposit { depositor: [0; 32].into(), points: 40 })
+		);
+		assert_ok!(GameModule::delist_nft(RuntimeOrigin::signed([0; 32].into()), 0));
+		let user = GameModule::users::<AccountId>([0; 32].into()).unwrap();
+		assert_eq!(user.points, 60);
+		assert_eq!(user.nfts.xorange, 1);
+		assert_ok!(GameModule::do_try_state());
 	});
 }
 
 #[test]
-fn add_property_works() {
+fn random_draws_within_a_block_are_independent() {
 	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		let new_property = PropertyInfoData {
-			id: 147031382,
-			data: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
-		};
-		assert_ok!(GameModule::add_property(RuntimeOrigin::root(), new_property));
-		assert_eq!(GameModule::game_properties().len(), 5);
+		for block in 1..5u64 {
+			System::set_block_number(block);
+			System::set_parent_hash(H256::repeat_byte(block as u8));
+			RandomnessCollectiveFlip::on_initialize(block);
+		}
+		let player: AccountId = [0; 32].into();
+		let mut draws = (0..4)
+			.map(|game_id| {
+				GameModule::random_number(crate::randomness::PROPERTY_SUBJECT, game_id, &player)
+					.unwrap()
+			})
+			.collect::<Vec<_>>();
+		draws.sort();
+		draws.dedup();
+		assert_eq!(draws.len(), 4);
+		assert_ne!(
+			GameModule::random_number(crate::randomness::PROPERTY_SUBJECT, 0, &player),
+			GameModule::random_number(crate::randomness::NFT_SUBJECT, 0, &player)
+		);
+		assert_ne!(
+			GameModule::random_number(crate::randomness::PROPERTY_SUBJECT, 0, &player),
+			GameModule::random_number(crate::randomness::PROPERTY_SUBJECT, 0, &[1; 32].into())
+		);
 	});
 }
 
 #[test]
-fn remove_property_works() {
+fn vrf_randomness_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_ok!(GameModule::remove_property(RuntimeOrigin::root(), 146480642));
-		assert_eq!(GameModule::game_properties().len(), 3);
+		System::set_parent_hash(H256::repeat_byte(1));
+		RandomnessCollectiveFlip::on_initialize(1);
+		assert_eq!(GameVrfRandomness::random(b"game"), RandomnessCollectiveFlip::random(b"game"));
+		VrfOutput::set(Some(H256::repeat_byte(7)));
+		let (first, block_number) = GameVrfRandomness::random(b"first");
+		let (second, _) = GameVrfRandomness::random(b"second");
+		assert_eq!(block_number, 1);
+		assert_ne!(first, second);
+		assert_ne!(first, RandomnessCollectiveFlip::random(b"first").0);
 	});
 }
 
 #[test]
-fn request_token_works() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
-		assert_ok!(GameModule::register_user(
-			RuntimeOrigin::signed([4; 32].into()),
-			[0; 32].into()
-		));
-		System::set_block_number(100802);
-		assert_ok!(GameModule::request_token(RuntimeOrigin::signed([0; 32].into())));
-		assert_eq!(Balances::free_balance(&([0; 32].into())), 10);
-	});
-}
+fn migration_to_v1_works() {
+	use crate::migrations::v1::{v0, MigrateToV1};
+	use frame_support::{
+		storage::unhashed,
+		traits::{GetStorageVersion, OnRuntimeUpgrade},
+	};
 
-#[test]
-fn request_token_doesnt_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_noop!(
-			GameModule::request_token(RuntimeOrigin::signed([0; 32].into())),
-			Error::<Test>::UserNotRegistered
+		crate::RoundCollections::<Test>::remove(1);
+		crate::Rounds::<Test>::remove(1);
+		for collection_id in 0..3 {
+			assert_ok!(Nfts::do_mint(
+				collection_id,
+				0,
+				Some(GameModule::account_id()),
+				GameModule::account_id(),
+				GameModule::default_item_config(),
+				|_, _| Ok(())
+			));
+		}
+		let old_user = |points, nfts| v0::User::<Test> {
+			points,
+			wins: 1,
+			losses: 0,
+			practise_rounds: 1,
+			last_played_round: 1,
+			next_token_request: 0,
+			nfts,
+		};
+		let seller: AccountId = [0; 32].into();
+		let buyer: AccountId = [1; 32].into();
+		unhashed::put(
+			&crate::Users::<Test>::hashed_key_for(&seller),
+			&old_user(150, crate::CollectedColors { xorange: 1, ..Default::default() }),
 		);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
-		assert_ok!(GameModule::register_user(
-			RuntimeOrigin::signed([4; 32].into()),
-			[0; 32].into()
-		));
-		assert_noop!(
-			GameModule::request_token(RuntimeOrigin::signed([0; 32].into())),
-			Error::<Test>::CantRequestToken
+		unhashed::put(
+			&crate::Users::<Test>::hashed_key_for(&buyer),
+			&old_user(250, crate::CollectedColors { xpink: 1, xblue: 1, ..Default::default() }),
 		);
-	});
-}
+		unhashed::put(
+			&crate::Listings::<Test>::hashed_key_for(0),
+			&v0::ListingInfo::<Test> { owner: seller.clone(), collection_id: 0, item_id: 0 },
+		);
+		unhashed::put(
+			&crate::Offers::<Test>::hashed_key_for(0),
+			&v0::OfferInfo::<Test> {
+				owner: buyer.clone(),
+				listing_id: 0,
+				collection_id: 1,
+				item_id: 0,
+			},
+		);
+		unhashed::put(
+			&crate::Offers::<Test>::hashed_key_for(1),
+			&v0::OfferInfo::<Test> {
+				owner: buyer.clone(),
+				listing_id: 5,
+				collection_id: 2,
+				item_id: 0,
+			},
+		);
+		unhashed::put(
+			&crate::Leaderboard::<Test>::hashed_key(),
+			&vec![(buyer.clone(), 250u32), (seller.clone(), 150u32)],
+		);
+		let property = v0::PropertyInfoData::<Test> {
+			id: 147229391,
+			data: "realxdeal property".as_bytes().to_vec().try_into().unwrap(),
+		};
+		v0::GameProperties::<Test>::put(BoundedVec::truncate_from(vec![property.clone()]));
+		v0::GameInfo::<Test>::insert(
+			0,
+			v0::GameData::<Test> {
+				difficulty: crate::DifficultyLevel::Player,
+				player: seller.clone(),
+				property,
+				guess: None,
+			},
+		);
+		v0::Admins::<Test>::put(vec![AccountId::from([4; 32])]);
+		assert_eq!(GameModule::on_chain_storage_version(), 0);
 
-#[test]
-fn check_result_fails() {
-	new_test_ext().execute_with(|| {
-		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
-		assert_ok!(GameModule::register_user(
-			RuntimeOrigin::signed([4; 32].into()),
-			[0; 32].into()
-		));
-		practise_round([0; 32].into(), 0);
-		assert_noop!(
-			GameModule::check_result(
-				RuntimeOrigin::root(),
-				223_000,
-				0,
-				220_000,
-				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-			),
-			Error::<Test>::NoActiveGame
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(GameModule::on_chain_storage_version(), 1);
+		assert!(!v0::GameProperties::<Test>::exists());
+		assert!(GameModule::game_info(0).is_none());
+		assert!(!v0::Admins::<Test>::exists());
+		assert_eq!(GameModule::role_members(Role::Registrar).into_inner(), vec![[4; 32].into()]);
+		assert_eq!(GameModule::role_members(Role::Settler).len(), 0);
+		assert_eq!(GameModule::round_collections(1).into_inner(), (0..8).collect::<Vec<_>>());
+		assert!(GameModule::rounds(1).is_some());
+		let seller_info = GameModule::users(&seller).unwrap();
+		assert_eq!(seller_info.points, 50);
+		assert_eq!(seller_info.nfts, Default::default());
+		assert_eq!(seller_info.moderation, ModerationState::Active);
+		let buyer_info = GameModule::users(&buyer).unwrap();
+		assert_eq!(buyer_info.points, 150);
+		assert_eq!(buyer_info.nfts, crate::CollectedColors { xblue: 1, ..Default::default() });
+		let listing = GameModule::listings(0).unwrap();
+		assert_eq!((listing.price, listing.expiry), (None, None));
+		assert_eq!(
+			GameModule::escrow_depositors(0, 0),
+			Some(crate::EscrowDeposit { depositor: seller.clone(), points: 100 })
 		);
-		assert_noop!(
-			GameModule::check_result(
-				RuntimeOrigin::root(),
-				223_000,
-				1,
-				220_000,
-				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-			),
-			Error::<Test>::NoActiveGame
+		assert_eq!(GameModule::escrowed_nft_count(&seller), 1);
+		assert_eq!(GameModule::offers(0).unwrap().expiry, None);
+		assert_eq!(GameModule::listing_offers(0).into_inner(), vec![0]);
+		assert_eq!(
+			GameModule::escrow_depositors(1, 0),
+			Some(crate::EscrowDeposit { depositor: buyer.clone(), points: 100 })
 		);
+		assert!(GameModule::offers(1).is_none());
+		assert_eq!(Nfts::owner(2, 0), Some(buyer.clone()));
+		assert_eq!(
+			points_of(GameModule::leaderboard()),
+			vec![(buyer.clone(), 150), (seller.clone(), 50)]
+		);
+		assert_ok!(GameModule::do_try_state());
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(GameModule::users(&seller).unwrap().points, 50);
+		assert_eq!(GameModule::listing_offers(0).into_inner(), vec![0]);
 	});
 }
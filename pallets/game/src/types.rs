@@ -1,6 +1,7 @@
 use crate::*;
 use frame_support::pallet_prelude::*;
 use frame_system::pallet_prelude::*;
+use sp_std::vec::Vec;
 
 /// Difficulty level of game enum.
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -19,6 +20,36 @@ pub enum Offer {
 	Reject,
 }
 
+/// Role enum. Every role allows an account to call the privileged calls of an area of the game.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum Role {
+	/// Registers new players.
+	Registrar,
+	/// Adds and removes the properties of the game.
+	PropertyCurator,
+	/// Settles the games.
+	Settler,
+	/// Removes listings and offers from the marketplace.
+	MarketplaceModerator,
+	/// Sets up the game, manages the rounds, the scoring and the prizes and gives points.
+	RoundManager,
+	/// Suspends, bans and reinstates players and resolves their appeals.
+	PlayerModerator,
+}
+
+/// Moderation state enum of a player.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum ModerationState<BlockNumber> {
+	/// The player can use the game.
+	Active,
+	/// The player can not use the game until the block.
+	Suspended(BlockNumber),
+	/// The player can not use the game until a moderator reinstates the player.
+	Banned,
+}
+
 /// Nft color enum.
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
@@ -65,6 +96,56 @@ pub struct GameData<T: Config> {
 	pub player: AccountIdOf<T>,
 	pub property: PropertyInfoData<T>,
 	pub guess: Option<u32>,
+	pub start_block: BlockNumberFor<T>,
+}
+
+/// Summary of a round.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize), serde(bound = ""))]
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	frame_support::pallet_prelude::RuntimeDebugNoBound,
+	TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct RoundInfo<T: Config> {
+	pub start_block: BlockNumberFor<T>,
+	/// The block in which the round is closed if nobody has collected four nfts of every color
+	/// before.
+	pub end_block: Option<BlockNumberFor<T>>,
+	/// The block in which the round has been closed.
+	pub closed_block: Option<BlockNumberFor<T>>,
+	pub champion: Option<AccountIdOf<T>>,
+	pub games_played: u32,
+	pub nfts_minted: u32,
+	/// The leaderboard at the end of the round.
+	pub leaderboard: LeaderboardOf<T>,
+}
+
+/// Score of a player on a leaderboard.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct Score<BlockNumber> {
+	pub points: u32,
+	pub wins: u32,
+	/// The block in which the player reached the points.
+	pub achieved: BlockNumber,
+}
+
+impl<BlockNumber: Ord> Score<BlockNumber> {
+	/// Compares the rank of two scores. More points rank first, ties are decided by more wins
+	/// and then by the earlier achievement.
+	pub fn rank_cmp(&self, other: &Self) -> sp_std::cmp::Ordering {
+		other
+			.points
+			.cmp(&self.points)
+			.then(other.wins.cmp(&self.wins))
+			.then(self.achieved.cmp(&other.achieved))
+	}
 }
 
 /// Listing infos of a NFT.
@@ -75,6 +156,9 @@ pub struct ListingInfo<CollectionId, ItemId, T: Config> {
 	pub owner: AccountIdOf<T>,
 	pub collection_id: CollectionId,
 	pub item_id: ItemId,
+	pub price: Option<CurrencyBalanceOf<T>>,
+	/// The block in which the listing expires.
+	pub expiry: Option<BlockNumberFor<T>>,
 }
 
 /// Offer infos of a listing.
@@ -86,10 +170,54 @@ pub struct OfferInfo<CollectionId, ItemId, T: Config> {
 	pub listing_id: u32,
 	pub collection_id: CollectionId,
 	pub item_id: ItemId,
+	/// The block in which the offer expires.
+	pub expiry: Option<BlockNumberFor<T>>,
 }
 
-/// Struct to store the property data for a game.
+/// A nft in the escrow of the pallet account.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct EscrowDeposit<T: Config> {
+	/// The account that put the nft into the escrow.
+	pub depositor: AccountIdOf<T>,
+	/// The points that were deducted from the depositor for the nft. The depositor gets back
+	/// exactly these points when the nft is returned.
+	pub points: u32,
+}
+
+/// Auction infos of a NFT.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct AuctionInfo<CollectionId, ItemId, T: Config> {
+	pub owner: AccountIdOf<T>,
+	pub collection_id: CollectionId,
+	pub item_id: ItemId,
+	/// The lowest bid that is accepted.
+	pub min_bid: CurrencyBalanceOf<T>,
+	/// The bidder and the amount of the highest bid.
+	pub highest_bid: Option<(AccountIdOf<T>, CurrencyBalanceOf<T>)>,
+	/// The block in which the auction is settled.
+	pub end_block: BlockNumberFor<T>,
+}
+
+/// Standing order infos. The offered nft is swapped for any nft of the wanted color.
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct StandingOrderInfo<CollectionId, ItemId, T: Config> {
+	pub owner: AccountIdOf<T>,
+	pub collection_id: CollectionId,
+	pub item_id: ItemId,
+	/// The color of the offered nft.
+	pub give: NftColor,
+	/// The color of the wanted nft.
+	pub want: NftColor,
+}
+
+/// Struct to store the property data for a game.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize), serde(bound = ""))]
 #[derive(
 	Encode,
 	Decode,
@@ -104,6 +232,75 @@ pub struct OfferInfo<CollectionId, ItemId, T: Config> {
 pub struct PropertyInfoData<T: Config> {
 	pub id: u32,
 	pub data: BoundedVec<u8, <T as Config>::StringLimit>,
+	/// Hash of the price, the salt and the data of the property.
+	pub commitment: <T as frame_system::Config>::Hash,
+	pub metadata: PropertyMetadata<T>,
+}
+
+/// Type of a property.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum PropertyType {
+	Detached,
+	SemiDetached,
+	Terraced,
+	Flat,
+	Bungalow,
+	Other,
+}
+
+/// Structured metadata of a property that is shown to the player.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize), serde(bound = ""))]
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	frame_support::pallet_prelude::RuntimeDebugNoBound,
+	TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct PropertyMetadata<T: Config> {
+	pub region: BoundedVec<u8, <T as Config>::StringLimit>,
+	pub property_type: PropertyType,
+	pub bedrooms: u8,
+	/// Unix timestamp in seconds of the date when the property has been listed.
+	pub listing_date: u64,
+}
+
+/// Lifecycle status of a property.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum PropertyStatus {
+	/// The property can be chosen for a new game.
+	Available,
+	/// The property is used in an ongoing game.
+	InPlay,
+	/// The property will not be used for games anymore.
+	Retired,
+	/// The price of the property has been revealed by the settlement of a game, so it will not be
+	/// used for games anymore.
+	Revealed,
+}
+
+/// A property of the registry together with its status.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	frame_support::pallet_prelude::RuntimeDebugNoBound,
+	TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct PropertyRecord<T: Config> {
+	pub property: PropertyInfoData<T>,
+	pub status: PropertyStatus,
 }
 
 /// Struct for the user datas.
@@ -118,9 +315,18 @@ pub struct User<T: Config> {
 	pub last_played_round: u32,
 	pub next_token_request: BlockNumberFor<T>,
 	pub nfts: CollectedColors,
+	pub moderation: ModerationState<BlockNumberFor<T>>,
 }
 
 impl<T: pallet::Config> User<T> {
+	/// Resets the collected colors if the user moves on to another round.
+	pub fn enter_round(&mut self, round: u32) {
+		if self.last_played_round != round {
+			self.nfts = Default::default();
+			self.last_played_round = round;
+		}
+	}
+
 	pub fn add_nft_color(&mut self, color: NftColor) -> DispatchResult {
 		self.nfts.add_nft_color(color)?;
 		Ok(())
@@ -135,6 +341,13 @@ impl<T: pallet::Config> User<T> {
 		self.nfts.has_four_of_all_colors()
 	}
 
+	/// Deducts a penalty without going below the floor and returns the deducted points.
+	pub fn deduct_points(&mut self, penalty: u32, floor: u32) -> u32 {
+		let deducted = penalty.min(self.points.saturating_sub(floor));
+		self.points = self.points.saturating_sub(deducted);
+		deducted
+	}
+
 	pub fn calculate_points(&mut self, color: NftColor) -> u32 {
 		match color {
 			NftColor::Xorange if self.nfts.xorange == 1 => 100,
@@ -312,3 +525,183 @@ impl CollectedColors {
 			self.xgreen >= 4
 	}
 }
+
+/// Reward or penalty in points for a result.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum ScoreOutcome {
+	Reward(u32),
+	Penalty(u32),
+}
+
+/// Accuracy band of a scoring table.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct ScoreBand {
+	/// The highest difference between guess and price in per mille that falls into this band.
+	pub max_difference: u64,
+	pub outcome: ScoreOutcome,
+}
+
+/// Scoring table of a difficulty level.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	frame_support::pallet_prelude::RuntimeDebugNoBound,
+	TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct ScoringConfig<T: Config> {
+	/// The points a player needs to start a game.
+	pub entry_points: u32,
+	/// The highest difference in per mille for which the player receives a nft.
+	pub nft_threshold: Option<u64>,
+	/// The accuracy bands sorted ascending by their max difference.
+	pub bands: BoundedVec<ScoreBand, <T as Config>::MaxScoreBands>,
+	/// The outcome if the difference is higher than the max difference of all bands.
+	pub miss: ScoreOutcome,
+	/// The points a player loses if the game expires without an answer.
+	pub no_answer_penalty: u32,
+}
+
+impl<T: Config> ScoringConfig<T> {
+	/// Returns the default scoring table of a difficulty level.
+	pub fn default_for(difficulty: DifficultyLevel) -> Self {
+		use ScoreOutcome::{Penalty, Reward};
+		let band = |max_difference, outcome| ScoreBand { max_difference, outcome };
+		let (entry_points, nft_threshold, bands, miss, no_answer_penalty) = match difficulty {
+			DifficultyLevel::Practice => (0, None, sp_std::vec![], Reward(5), 0),
+			DifficultyLevel::Player => (
+				25,
+				Some(10),
+				sp_std::vec![
+					band(30, Reward(25)),
+					band(50, Reward(15)),
+					band(100, Reward(5)),
+					band(150, Penalty(5)),
+					band(200, Penalty(10)),
+					band(250, Penalty(15)),
+					band(300, Penalty(20)),
+				],
+				Penalty(25),
+				25,
+			),
+			DifficultyLevel::Pro => (
+				50,
+				Some(10),
+				sp_std::vec![
+					band(30, Reward(50)),
+					band(50, Reward(30)),
+					band(100, Reward(10)),
+					band(150, Penalty(10)),
+					band(200, Penalty(20)),
+					band(250, Penalty(30)),
+					band(300, Penalty(40)),
+				],
+				Penalty(50),
+				50,
+			),
+		};
+		ScoringConfig {
+			entry_points,
+			nft_threshold,
+			bands: BoundedVec::try_from(bands)
+				.expect("MaxScoreBands is at least 7, checked in integrity_test; qed"),
+			miss,
+			no_answer_penalty,
+		}
+	}
+
+	/// Returns the outcome for the difference between guess and price in per mille.
+	pub fn outcome(&self, difference: u64) -> ScoreOutcome {
+		self.bands
+			.iter()
+			.find(|band| difference <= band.max_difference)
+			.map(|band| band.outcome.clone())
+			.unwrap_or_else(|| self.miss.clone())
+	}
+
+	/// Checks that the nft threshold and the bands are sorted ascending and that a practice
+	/// table has neither an nft threshold nor entry points.
+	pub fn is_valid(&self, difficulty: &DifficultyLevel) -> bool {
+		if *difficulty == DifficultyLevel::Practice &&
+			(self.nft_threshold.is_some() || self.entry_points > 0)
+		{
+			return false;
+		}
+		let mut previous = self.nft_threshold;
+		for band in self.bands.iter() {
+			if previous.map_or(false, |previous| previous >= band.max_difference) {
+				return false;
+			}
+			previous = Some(band.max_difference);
+		}
+		true
+	}
+}
+
+/// Profile of a player as returned by the runtime api.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PlayerProfile<BlockNumber> {
+	pub points: u32,
+	pub wins: u32,
+	pub losses: u32,
+	pub practise_rounds: u8,
+	pub last_played_round: u32,
+	pub next_token_request: BlockNumber,
+	pub nfts: CollectedColors,
+	pub moderation: ModerationState<BlockNumber>,
+	/// Position of the player on the all-time leaderboard, starting at 1.
+	pub rank: Option<u32>,
+	/// Position of the player on the leaderboard of the current round, starting at 1.
+	pub round_rank: Option<u32>,
+}
+
+/// Ongoing game of a player as returned by the runtime api.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct OpenGame<BlockNumber> {
+	pub game_id: u32,
+	pub difficulty: DifficultyLevel,
+	pub property_id: u32,
+	pub data: Vec<u8>,
+	pub region: Vec<u8>,
+	pub property_type: PropertyType,
+	pub bedrooms: u8,
+	pub listing_date: u64,
+	pub guess: Option<u32>,
+	/// The block in which the game expires.
+	pub expiry_block: BlockNumber,
+}
+
+/// Listing as returned by the runtime api.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ListingDetails<AccountId, CollectionId, ItemId, Balance, BlockNumber> {
+	pub listing_id: u32,
+	pub owner: AccountId,
+	pub collection_id: CollectionId,
+	pub item_id: ItemId,
+	pub color: Option<NftColor>,
+	pub price: Option<Balance>,
+	pub expiry: Option<BlockNumber>,
+}
+
+/// Offer as returned by the runtime api. The collection, item and color are the ones of the
+/// offered nft.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct OfferDetails<AccountId, CollectionId, ItemId> {
+	pub offer_id: u32,
+	pub owner: AccountId,
+	pub listing_id: u32,
+	pub collection_id: CollectionId,
+	pub item_id: ItemId,
+	pub color: Option<NftColor>,
+}
@@ -1,13 +1,12 @@
-
-//! Autogenerated weights for `pallet_game`
+//! Weights for `pallet_game`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2024-08-28, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `recrafter-Legion-5-16IRX9`, CPU: `Intel(R) Core(TM) i7-14650HX`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
+//! THESE WEIGHTS ARE ESTIMATED BY HAND, THEY HAVE NOT BEEN GENERATED BY THE BENCHMARK CLI.
+//! The estimates scale the measurements of 2024-08-28 (STEPS: `50`, REPEAT: `20`,
+//! CPU: `Intel(R) Core(TM) i7-14650HX`, CHAIN: `Some("dev")`) by the storage accesses of the
+//! calls. Replace them with the output of `scripts/benchmark-game.sh` before a production
+//! release.
 
-// Executed Command:
+// Command to regenerate:
 // ./target/release/node-template
 // benchmark
 // pallet
@@ -44,340 +43,790 @@ pub trait WeightInfo {
 	fn delist_nft() -> Weight;
 	fn make_offer() -> Weight;
 	fn handle_offer() -> Weight;
+	fn withdraw_offer() -> Weight;
 	fn add_property() -> Weight;
 	fn remove_property() -> Weight;
-	fn add_to_admins() -> Weight;
-	fn remove_from_admins() -> Weight;
 	fn request_token() -> Weight;
+	fn submit_result() -> Weight;
+	fn set_scoring_config() -> Weight;
+	fn add_properties(n: u32) -> Weight;
+	fn start_round() -> Weight;
+	fn end_round() -> Weight;
+	fn buy_nft() -> Weight;
+	fn create_auction() -> Weight;
+	fn bid() -> Weight;
+	fn settle_auction() -> Weight;
+	fn expire_listing() -> Weight;
+	fn expire_offer() -> Weight;
+	fn place_standing_order() -> Weight;
+	fn cancel_standing_order() -> Weight;
+	fn fill_standing_order() -> Weight;
+	fn fund_prize_pool() -> Weight;
+	fn set_prize_distribution() -> Weight;
+	fn claim_prize() -> Weight;
+	fn grant_role() -> Weight;
+	fn revoke_role() -> Weight;
+	fn register() -> Weight;
+	fn unregister(r: u32) -> Weight;
+	fn deregister(n: u32, r: u32) -> Weight;
+	fn create_invite_codes(n: u32) -> Weight;
+	fn suspend_player() -> Weight;
+	fn ban_player() -> Weight;
+	fn reinstate_player() -> Weight;
+	fn file_appeal() -> Weight;
+	fn reject_appeal() -> Weight;
 }
 
 /// Weight functions for `pallet_game`.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Nfts::NextCollectionId` (r:1 w:1)
-	/// Proof: `Nfts::NextCollectionId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Collection` (r:8 w:8)
-	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `GameModule::GameProperties` (r:1 w:1)
-	/// Proof: `GameModule::GameProperties` (`max_values`: Some(1), `max_size`: Some(50602), added: 51097, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::CurrentRound` (r:1 w:1)
-	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::RoundActive` (r:0 w:1)
-	/// Proof: `GameModule::RoundActive` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoundCollections` (r:0 w:1)
 	/// Storage: `GameModule::CollectionColor` (r:0 w:8)
-	/// Proof: `GameModule::CollectionColor` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionRoleOf` (r:0 w:8)
-	/// Proof: `Nfts::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionConfigOf` (r:0 w:8)
-	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionAccount` (r:0 w:8)
-	/// Proof: `Nfts::CollectionAccount` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Rounds` (r:0 w:1)
 	fn setup_game() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `166`
-		//  Estimated: `52087`
-		// Minimum execution time: 188_020_000 picoseconds.
-		Weight::from_parts(195_145_000, 0)
-			.saturating_add(Weight::from_parts(0, 52087))
-			.saturating_add(T::DbWeight::get().reads(12))
-			.saturating_add(T::DbWeight::get().writes(45))
+		Weight::from_parts(170_912_000, 0)
+			.saturating_add(Weight::from_parts(0, 24073))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(46))
 	}
-	/// Storage: `GameModule::Admins` (r:1 w:0)
-	/// Proof: `GameModule::Admins` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoleMembers` (r:1 w:0)
 	/// Storage: `GameModule::Users` (r:1 w:1)
-	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
-	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `GameModule::FaucetUsage` (r:1 w:1)
+	/// Storage: `GameModule::PrizePool` (r:1 w:0)
+	/// Storage: `GameModule::UnclaimedPrizes` (r:1 w:0)
+	/// Storage: `GameModule::BannedAccounts` (r:1 w:0)
 	fn register_user() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `159`
-		//  Estimated: `3593`
-		// Minimum execution time: 23_158_000 picoseconds.
-		Weight::from_parts(24_101_000, 0)
-			.saturating_add(Weight::from_parts(0, 3593))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+		Weight::from_parts(60_273_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `GameModule::Users` (r:1 w:1)
-	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Storage: `GameModule::RoundScores` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
 	fn give_points() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `224`
-		//  Estimated: `3566`
-		// Minimum execution time: 13_285_000 picoseconds.
 		Weight::from_parts(13_974_000, 0)
 			.saturating_add(Weight::from_parts(0, 3566))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `GameModule::Users` (r:1 w:0)
-	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::RoundActive` (r:1 w:0)
-	/// Proof: `GameModule::RoundActive` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
-	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::GameId` (r:1 w:1)
-	/// Proof: `GameModule::GameId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::GamesExpiring` (r:1 w:1)
-	/// Proof: `GameModule::GamesExpiring` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
 	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
-	/// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::GameProperties` (r:1 w:1)
-	/// Proof: `GameModule::GameProperties` (`max_values`: Some(1), `max_size`: Some(50602), added: 51097, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::AvailablePropertyCount` (r:1 w:1)
+	/// Storage: `GameModule::AvailableProperties` (r:2 w:2)
+	/// Storage: `GameModule::AvailablePropertySlot` (r:1 w:2)
+	/// Storage: `GameModule::Rounds` (r:1 w:1)
 	/// Storage: `GameModule::GameInfo` (r:0 w:1)
-	/// Proof: `GameModule::GameInfo` (`max_values`: None, `max_size`: Some(564), added: 3039, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `GameModule::PrizePool` (r:1 w:1)
 	fn play_game() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `544`
-		//  Estimated: `52087`
-		// Minimum execution time: 31_376_000 picoseconds.
-		Weight::from_parts(32_290_000, 0)
+		Weight::from_parts(54_318_000, 0)
 			.saturating_add(Weight::from_parts(0, 52087))
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().writes(14))
 	}
+	/// Storage: `GameModule::Users` (r:1 w:0)
 	/// Storage: `GameModule::GameInfo` (r:1 w:1)
-	/// Proof: `GameModule::GameInfo` (`max_values`: None, `max_size`: Some(564), added: 3039, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::GamesExpiring` (r:2 w:2)
 	fn submit_answer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `341`
-		//  Estimated: `4029`
-		// Minimum execution time: 13_672_000 picoseconds.
 		Weight::from_parts(14_261_000, 0)
-			.saturating_add(Weight::from_parts(0, 4029))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 10623))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `GameModule::GameInfo` (r:1 w:1)
-	/// Proof: `GameModule::GameInfo` (`max_values`: None, `max_size`: Some(564), added: 3039, mode: `MaxEncodedLen`)
 	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
-	/// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
-	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::NextColorId` (r:1 w:1)
-	/// Proof: `GameModule::NextColorId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Item` (r:1 w:1)
-	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Collection` (r:1 w:1)
-	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
-	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
-	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
-	/// Proof: `Nfts::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::Users` (r:1 w:1)
-	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
-	/// Storage: `GameModule::CollectionColor` (r:1 w:0)
-	/// Proof: `GameModule::CollectionColor` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
 	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
-	/// Proof: `GameModule::Leaderboard` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Storage: `GameModule::RoundScores` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
 	/// Storage: `Nfts::Account` (r:0 w:1)
-	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
 	fn check_result() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1679`
-		//  Estimated: `4326`
-		// Minimum execution time: 78_825_000 picoseconds.
 		Weight::from_parts(81_057_000, 0)
 			.saturating_add(Weight::from_parts(0, 4326))
-			.saturating_add(T::DbWeight::get().reads(12))
-			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().writes(10))
 	}
 	/// Storage: `Nfts::Item` (r:1 w:1)
-	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
-	/// Proof: `Nfts::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
-	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Collection` (r:1 w:0)
-	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Attribute` (r:1 w:0)
-	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(446), added: 2921, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
-	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::NextListingId` (r:1 w:1)
-	/// Proof: `GameModule::NextListingId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::Listings` (r:0 w:1)
-	/// Proof: `GameModule::Listings` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Storage: `GameModule::CollectionColor` (r:1 w:0)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Storage: `GameModule::RoundScores` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
 	/// Storage: `Nfts::Account` (r:0 w:2)
-	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
-	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
-	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::EscrowDepositors` (r:0 w:1)
+	/// Storage: `GameModule::EscrowedNftCount` (r:1 w:1)
 	fn list_nft() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1336`
-		//  Estimated: `4326`
-		// Minimum execution time: 71_982_000 picoseconds.
 		Weight::from_parts(74_071_000, 0)
 			.saturating_add(Weight::from_parts(0, 4326))
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(T::DbWeight::get().reads(16))
+			.saturating_add(T::DbWeight::get().writes(14))
 	}
 	/// Storage: `GameModule::Listings` (r:1 w:1)
-	/// Proof: `GameModule::Listings` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Collection` (r:1 w:0)
-	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Attribute` (r:1 w:0)
-	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(446), added: 2921, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
-	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
-	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Item` (r:1 w:1)
-	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
-	/// Proof: `Nfts::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::ListingOffers` (r:1 w:1)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Storage: `GameModule::CollectionColor` (r:1 w:0)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Storage: `GameModule::RoundScores` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
 	/// Storage: `Nfts::Account` (r:0 w:2)
-	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
-	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
-	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::EscrowDepositors` (r:1 w:1)
+	/// Storage: `GameModule::EscrowedNftCount` (r:1 w:1)
 	fn delist_nft() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1433`
-		//  Estimated: `4326`
-		// Minimum execution time: 66_631_000 picoseconds.
 		Weight::from_parts(68_152_000, 0)
 			.saturating_add(Weight::from_parts(0, 4326))
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().reads(18))
+			.saturating_add(T::DbWeight::get().writes(14))
 	}
 	/// Storage: `GameModule::Listings` (r:1 w:0)
-	/// Proof: `GameModule::Listings` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
-	/// Proof: `Nfts::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
-	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Collection` (r:1 w:0)
-	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Attribute` (r:1 w:0)
-	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(446), added: 2921, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
-	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Item` (r:1 w:1)
-	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::NextOfferId` (r:1 w:1)
-	/// Proof: `GameModule::NextOfferId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::Offers` (r:0 w:1)
-	/// Proof: `GameModule::Offers` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::ListingOffers` (r:1 w:1)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Storage: `GameModule::CollectionColor` (r:1 w:0)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Storage: `GameModule::RoundScores` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
 	/// Storage: `Nfts::Account` (r:0 w:2)
-	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
-	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
-	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::EscrowDepositors` (r:0 w:1)
+	/// Storage: `GameModule::EscrowedNftCount` (r:1 w:1)
 	fn make_offer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1507`
-		//  Estimated: `4326`
-		// Minimum execution time: 72_078_000 picoseconds.
 		Weight::from_parts(75_280_000, 0)
 			.saturating_add(Weight::from_parts(0, 4326))
-			.saturating_add(T::DbWeight::get().reads(8))
-			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(T::DbWeight::get().reads(18))
+			.saturating_add(T::DbWeight::get().writes(15))
 	}
 	/// Storage: `GameModule::Offers` (r:1 w:1)
-	/// Proof: `GameModule::Offers` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::Listings` (r:1 w:1)
-	/// Proof: `GameModule::Listings` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Collection` (r:1 w:0)
-	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Attribute` (r:2 w:0)
-	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(446), added: 2921, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
-	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemConfigOf` (r:2 w:2)
-	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Item` (r:2 w:2)
-	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
-	/// Proof: `Nfts::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::Users` (r:2 w:2)
-	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::CollectionColor` (r:1 w:0)
-	/// Proof: `GameModule::CollectionColor` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::ListingOffers` (r:1 w:1)
+	/// Storage: `GameModule::RoundCollections` (r:2 w:0)
 	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
-	/// Proof: `GameModule::Leaderboard` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Storage: `GameModule::RoundScores` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
 	/// Storage: `Nfts::Account` (r:0 w:4)
-	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemPriceOf` (r:0 w:2)
-	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::PendingSwapOf` (r:0 w:2)
-	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::EscrowDepositors` (r:2 w:2)
+	/// Storage: `GameModule::EscrowedNftCount` (r:2 w:2)
 	fn handle_offer() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2135`
-		//  Estimated: `7662`
-		// Minimum execution time: 138_084_000 picoseconds.
 		Weight::from_parts(141_748_000, 0)
 			.saturating_add(Weight::from_parts(0, 7662))
-			.saturating_add(T::DbWeight::get().reads(15))
-			.saturating_add(T::DbWeight::get().writes(17))
+			.saturating_add(T::DbWeight::get().reads(26))
+			.saturating_add(T::DbWeight::get().writes(24))
 	}
 	/// Storage: `GameModule::GameProperties` (r:1 w:1)
-	/// Proof: `GameModule::GameProperties` (`max_values`: Some(1), `max_size`: Some(50602), added: 51097, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::AvailablePropertyCount` (r:1 w:1)
+	/// Storage: `GameModule::AvailableProperties` (r:0 w:1)
+	/// Storage: `GameModule::AvailablePropertySlot` (r:0 w:1)
 	fn add_property() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `246`
-		//  Estimated: `52087`
-		// Minimum execution time: 7_319_000 picoseconds.
 		Weight::from_parts(7_667_000, 0)
-			.saturating_add(Weight::from_parts(0, 52087))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 13539))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `GameModule::GameProperties` (r:1 w:1)
-	/// Proof: `GameModule::GameProperties` (`max_values`: Some(1), `max_size`: Some(50602), added: 51097, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::AvailablePropertyCount` (r:1 w:1)
+	/// Storage: `GameModule::AvailableProperties` (r:1 w:2)
+	/// Storage: `GameModule::AvailablePropertySlot` (r:1 w:2)
 	fn remove_property() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `246`
-		//  Estimated: `52087`
-		// Minimum execution time: 7_971_000 picoseconds.
 		Weight::from_parts(8_477_000, 0)
-			.saturating_add(Weight::from_parts(0, 52087))
+			.saturating_add(Weight::from_parts(0, 13539))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `GameModule::FaucetUsage` (r:1 w:1)
+	/// Storage: `GameModule::PrizePool` (r:1 w:0)
+	/// Storage: `GameModule::UnclaimedPrizes` (r:1 w:0)
+	fn request_token() -> Weight {
+		Weight::from_parts(64_117_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `GameModule::RoleMembers` (r:1 w:0)
+	/// Storage: `GameModule::GameInfo` (r:1 w:1)
+	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Storage: `GameModule::NextColorId` (r:1 w:1)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Storage: `Nfts::Collection` (r:1 w:1)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
+	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Storage: `GameModule::RoundScores` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
+	/// Storage: `Nfts::Account` (r:0 w:1)
+	fn submit_result() -> Weight {
+		Weight::from_parts(83_512_000, 0)
+			.saturating_add(Weight::from_parts(0, 4326))
+			.saturating_add(T::DbWeight::get().reads(16))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: `GameModule::ScoringConfigs` (r:0 w:1)
+	fn set_scoring_config() -> Weight {
+		Weight::from_parts(8_516_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `GameModule::GameProperties` (r:250 w:250)
+	/// Storage: `GameModule::AvailablePropertyCount` (r:1 w:1)
+	/// Storage: `GameModule::AvailableProperties` (r:0 w:250)
+	/// Storage: `GameModule::AvailablePropertySlot` (r:0 w:250)
+	/// The range of component `n` is `[1, 250]`.
+	fn add_properties(n: u32) -> Weight {
+		Weight::from_parts(7_102_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(Weight::from_parts(7_245_000, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 12549).saturating_mul(n.into()))
+	}
+	/// Storage: `GameModule::RoundActive` (r:1 w:1)
+	/// Storage: `GameModule::ScheduledRound` (r:1 w:0)
+	/// Storage: `Nfts::NextCollectionId` (r:1 w:1)
+	/// Storage: `Nfts::Collection` (r:8 w:8)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:1)
+	/// Storage: `GameModule::Rounds` (r:0 w:1)
+	/// Storage: `GameModule::RoundCollections` (r:0 w:1)
+	/// Storage: `GameModule::CollectionColor` (r:0 w:8)
+	/// Storage: `Nfts::CollectionRoleOf` (r:0 w:8)
+	/// Storage: `Nfts::CollectionConfigOf` (r:0 w:8)
+	/// Storage: `Nfts::CollectionAccount` (r:0 w:8)
+	fn start_round() -> Weight {
+		Weight::from_parts(167_903_000, 0)
+			.saturating_add(Weight::from_parts(0, 21462))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(45))
+	}
+	/// Storage: `GameModule::RoundActive` (r:1 w:1)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Storage: `GameModule::Rounds` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
+	/// Storage: `GameModule::PrizePool` (r:1 w:1)
+	/// Storage: `GameModule::PrizeDistribution` (r:1 w:0)
+	/// Storage: `GameModule::RoundPrizes` (r:0 w:10)
+	/// Storage: `GameModule::UnclaimedPrizes` (r:1 w:1)
+	fn end_round() -> Weight {
+		Weight::from_parts(32_817_000, 0)
+			.saturating_add(Weight::from_parts(0, 3961))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(15))
+	}
+	/// Storage: `GameModule::Offers` (r:1 w:1)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Storage: `Nfts::Attribute` (r:1 w:0)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
+	/// Storage: `GameModule::ListingOffers` (r:1 w:1)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Storage: `GameModule::CollectionColor` (r:1 w:0)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Storage: `GameModule::RoundScores` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
+	/// Storage: `Nfts::Account` (r:0 w:2)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Storage: `GameModule::EscrowDepositors` (r:1 w:1)
+	/// Storage: `GameModule::EscrowedNftCount` (r:1 w:1)
+	fn withdraw_offer() -> Weight {
+		Weight::from_parts(67_480_000, 0)
+			.saturating_add(Weight::from_parts(0, 4326))
+			.saturating_add(T::DbWeight::get().reads(18))
+			.saturating_add(T::DbWeight::get().writes(14))
 	}
-	/// Storage: `GameModule::Admins` (r:1 w:1)
-	/// Proof: `GameModule::Admins` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
-	fn add_to_admins() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `118`
-		//  Estimated: `1806`
-		// Minimum execution time: 9_335_000 picoseconds.
-		Weight::from_parts(9_858_000, 0)
-			.saturating_add(Weight::from_parts(0, 1806))
+	/// Storage: `GameModule::Users` (r:2 w:1)
+	/// Storage: `GameModule::Listings` (r:1 w:1)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Storage: `GameModule::ListingOffers` (r:1 w:1)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Storage: `Nfts::Attribute` (r:1 w:0)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Storage: `GameModule::CollectionColor` (r:1 w:0)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Storage: `GameModule::RoundScores` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
+	/// Storage: `Nfts::Account` (r:0 w:2)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Storage: `GameModule::EscrowDepositors` (r:1 w:1)
+	/// Storage: `GameModule::EscrowedNftCount` (r:1 w:1)
+	fn buy_nft() -> Weight {
+		Weight::from_parts(100_742_000, 0)
+			.saturating_add(Weight::from_parts(0, 8799))
+			.saturating_add(T::DbWeight::get().reads(23))
+			.saturating_add(T::DbWeight::get().writes(18))
+	}
+	/// Storage: `GameModule::NextAuctionId` (r:1 w:1)
+	/// Storage: `GameModule::AuctionsEnding` (r:1 w:1)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Storage: `Nfts::Attribute` (r:1 w:0)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Storage: `GameModule::CollectionColor` (r:1 w:0)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Storage: `GameModule::RoundScores` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
+	/// Storage: `Nfts::Account` (r:0 w:2)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Storage: `GameModule::Auctions` (r:0 w:1)
+	/// Storage: `GameModule::EscrowDepositors` (r:0 w:1)
+	/// Storage: `GameModule::EscrowedNftCount` (r:1 w:1)
+	fn create_auction() -> Weight {
+		Weight::from_parts(78_520_000, 0)
+			.saturating_add(Weight::from_parts(0, 4326))
+			.saturating_add(T::DbWeight::get().reads(17))
+			.saturating_add(T::DbWeight::get().writes(15))
+	}
+	/// Storage: `GameModule::Users` (r:1 w:0)
+	/// Storage: `GameModule::Auctions` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `GameModule::AuctionsEnding` (r:2 w:2)
+	/// Storage: `GameModule::HighestBidCount` (r:2 w:2)
+	fn bid() -> Weight {
+		Weight::from_parts(42_903_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `GameModule::Auctions` (r:1 w:1)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Storage: `Nfts::Attribute` (r:1 w:0)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Storage: `GameModule::CollectionColor` (r:1 w:0)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Storage: `GameModule::RoundScores` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
+	/// Storage: `Nfts::Account` (r:0 w:2)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Storage: `GameModule::EscrowDepositors` (r:1 w:1)
+	/// Storage: `GameModule::EscrowedNftCount` (r:1 w:1)
+	/// Storage: `GameModule::HighestBidCount` (r:1 w:1)
+	fn settle_auction() -> Weight {
+		Weight::from_parts(94_388_000, 0)
+			.saturating_add(Weight::from_parts(0, 8799))
+			.saturating_add(T::DbWeight::get().reads(22))
+			.saturating_add(T::DbWeight::get().writes(17))
+	}
+	/// Storage: `GameModule::Listings` (r:1 w:1)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Storage: `Nfts::Attribute` (r:1 w:0)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
+	/// Storage: `GameModule::ListingOffers` (r:1 w:1)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Storage: `GameModule::CollectionColor` (r:1 w:0)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Storage: `GameModule::RoundScores` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
+	/// Storage: `Nfts::Account` (r:0 w:2)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Storage: `GameModule::EscrowDepositors` (r:1 w:1)
+	/// Storage: `GameModule::EscrowedNftCount` (r:1 w:1)
+	fn expire_listing() -> Weight {
+		Weight::from_parts(68_915_000, 0)
+			.saturating_add(Weight::from_parts(0, 4326))
+			.saturating_add(T::DbWeight::get().reads(18))
+			.saturating_add(T::DbWeight::get().writes(14))
+	}
+	/// Storage: `GameModule::Offers` (r:1 w:1)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Storage: `Nfts::Attribute` (r:1 w:0)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
+	/// Storage: `GameModule::ListingOffers` (r:1 w:1)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Storage: `GameModule::CollectionColor` (r:1 w:0)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Storage: `GameModule::RoundScores` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
+	/// Storage: `Nfts::Account` (r:0 w:2)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Storage: `GameModule::EscrowDepositors` (r:1 w:1)
+	/// Storage: `GameModule::EscrowedNftCount` (r:1 w:1)
+	fn expire_offer() -> Weight {
+		Weight::from_parts(68_036_000, 0)
+			.saturating_add(Weight::from_parts(0, 4326))
+			.saturating_add(T::DbWeight::get().reads(18))
+			.saturating_add(T::DbWeight::get().writes(14))
+	}
+	/// Storage: `GameModule::NextStandingOrderId` (r:1 w:1)
+	/// Storage: `GameModule::StandingOrders` (r:1 w:1)
+	/// Storage: `GameModule::StandingOrdersByColor` (r:1 w:1)
+	/// Storage: `Nfts::Collection` (r:2 w:0)
+	/// Storage: `Nfts::Attribute` (r:2 w:0)
+	/// Storage: `Nfts::CollectionConfigOf` (r:2 w:0)
+	/// Storage: `Nfts::ItemConfigOf` (r:2 w:2)
+	/// Storage: `Nfts::Item` (r:2 w:2)
+	/// Storage: `Nfts::CollectionRoleOf` (r:2 w:0)
+	/// Storage: `GameModule::Users` (r:2 w:2)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Storage: `GameModule::CollectionColor` (r:2 w:0)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Storage: `GameModule::RoundScores` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
+	/// Storage: `Nfts::Account` (r:0 w:4)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:2)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:2)
+	/// Storage: `GameModule::EscrowDepositors` (r:0 w:1)
+	/// Storage: `GameModule::EscrowedNftCount` (r:1 w:1)
+	fn place_standing_order() -> Weight {
+		Weight::from_parts(171_905_000, 0)
+			.saturating_add(Weight::from_parts(0, 7632))
+			.saturating_add(T::DbWeight::get().reads(25))
+			.saturating_add(T::DbWeight::get().writes(23))
+	}
+	/// Storage: `GameModule::StandingOrders` (r:1 w:1)
+	/// Storage: `GameModule::StandingOrdersByColor` (r:1 w:1)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Storage: `Nfts::Attribute` (r:1 w:0)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Storage: `GameModule::CollectionColor` (r:1 w:0)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Storage: `GameModule::RoundScores` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
+	/// Storage: `Nfts::Account` (r:0 w:2)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Storage: `GameModule::EscrowDepositors` (r:1 w:1)
+	/// Storage: `GameModule::EscrowedNftCount` (r:1 w:1)
+	fn cancel_standing_order() -> Weight {
+		Weight::from_parts(69_433_000, 0)
+			.saturating_add(Weight::from_parts(0, 4326))
+			.saturating_add(T::DbWeight::get().reads(18))
+			.saturating_add(T::DbWeight::get().writes(14))
+	}
+	/// Storage: `GameModule::StandingOrders` (r:1 w:1)
+	/// Storage: `GameModule::StandingOrdersByColor` (r:8 w:1)
+	/// Storage: `Nfts::Collection` (r:2 w:0)
+	/// Storage: `Nfts::Attribute` (r:2 w:0)
+	/// Storage: `Nfts::CollectionConfigOf` (r:2 w:0)
+	/// Storage: `Nfts::ItemConfigOf` (r:2 w:2)
+	/// Storage: `Nfts::Item` (r:2 w:2)
+	/// Storage: `Nfts::CollectionRoleOf` (r:2 w:0)
+	/// Storage: `GameModule::Users` (r:2 w:2)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Storage: `GameModule::CollectionColor` (r:2 w:0)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Storage: `GameModule::RoundScores` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
+	/// Storage: `Nfts::Account` (r:0 w:4)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:2)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:2)
+	/// Storage: `GameModule::EscrowDepositors` (r:2 w:2)
+	/// Storage: `GameModule::EscrowedNftCount` (r:2 w:2)
+	fn fill_standing_order() -> Weight {
+		Weight::from_parts(135_244_000, 0)
+			.saturating_add(Weight::from_parts(0, 21070))
+			.saturating_add(T::DbWeight::get().reads(33))
+			.saturating_add(T::DbWeight::get().writes(24))
+	}
+	/// Storage: `GameModule::Users` (r:1 w:0)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `GameModule::PrizePool` (r:1 w:1)
+	/// Storage: `GameModule::UnclaimedPrizes` (r:1 w:0)
+	fn fund_prize_pool() -> Weight {
+		Weight::from_parts(42_531_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `GameModule::PrizeDistribution` (r:0 w:1)
+	fn set_prize_distribution() -> Weight {
+		Weight::from_parts(8_273_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `GameModule::Users` (r:1 w:0)
+	/// Storage: `GameModule::RoundPrizes` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `GameModule::UnclaimedPrizes` (r:1 w:1)
+	fn claim_prize() -> Weight {
+		Weight::from_parts(50_112_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `GameModule::RoleMembers` (r:1 w:1)
+	fn grant_role() -> Weight {
+		Weight::from_parts(10_783_000, 0)
+			.saturating_add(Weight::from_parts(0, 3803))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-	/// Storage: `GameModule::Admins` (r:1 w:1)
-	/// Proof: `GameModule::Admins` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
-	fn remove_from_admins() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `159`
-		//  Estimated: `1806`
-		// Minimum execution time: 10_625_000 picoseconds.
-		Weight::from_parts(11_073_000, 0)
-			.saturating_add(Weight::from_parts(0, 1806))
+	/// Storage: `GameModule::RoleMembers` (r:1 w:1)
+	fn revoke_role() -> Weight {
+		Weight::from_parts(11_937_000, 0)
+			.saturating_add(Weight::from_parts(0, 3803))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `GameModule::Users` (r:1 w:1)
-	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
-	fn request_token() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `224`
-		//  Estimated: `3566`
-		// Minimum execution time: 30_628_000 picoseconds.
-		Weight::from_parts(34_006_000, 0)
-			.saturating_add(Weight::from_parts(0, 3566))
+	/// Storage: `GameModule::InviteCodes` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `GameModule::RegistrationDeposits` (r:0 w:1)
+	/// Storage: `GameModule::BannedAccounts` (r:1 w:0)
+	/// Storage: `GameModule::SuspendedAccounts` (r:1 w:0)
+	fn register() -> Weight {
+		Weight::from_parts(42_870_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Storage: `GameModule::PlayerGames` (r:1 w:0)
+	/// Storage: `GameModule::EscrowedNftCount` (r:1 w:1)
+	/// Storage: `GameModule::HighestBidCount` (r:1 w:1)
+	/// Storage: `GameModule::Scores` (r:0 w:1)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Storage: `GameModule::RoundChampion` (r:100 w:0)
+	/// Storage: `GameModule::Rounds` (r:100 w:100)
+	/// Storage: `GameModule::RegistrationDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `GameModule::RoundScores` (r:0 w:100)
+	/// Storage: `GameModule::Appeals` (r:0 w:1)
+	/// Storage: `GameModule::PlayerRounds` (r:101 w:100)
+	/// Storage: `GameModule::SuspendedAccounts` (r:0 w:1)
+	/// The range of component `r` is `[1, 100]`.
+	fn unregister(r: u32) -> Weight {
+		Weight::from_parts(54_116_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(Weight::from_parts(6_412_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(10))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2541).saturating_mul(r.into()))
+	}
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Storage: `GameModule::PlayerGames` (r:1 w:0)
+	/// Storage: `GameModule::EscrowedNftCount` (r:1 w:1)
+	/// Storage: `GameModule::HighestBidCount` (r:1 w:1)
+	/// Storage: `Nfts::Account` (r:101 w:100)
+	/// Storage: `GameModule::CollectionColor` (r:100 w:0)
+	/// Storage: `Nfts::Collection` (r:100 w:100)
+	/// Storage: `Nfts::Item` (r:100 w:100)
+	/// Storage: `Nfts::ItemConfigOf` (r:100 w:100)
+	/// Storage: `Nfts::ItemMetadataOf` (r:100 w:0)
+	/// Storage: `GameModule::Scores` (r:0 w:1)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Storage: `GameModule::RoundLeaderboard` (r:1 w:1)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Storage: `GameModule::RoundChampion` (r:100 w:0)
+	/// Storage: `GameModule::Rounds` (r:100 w:100)
+	/// Storage: `GameModule::RegistrationDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:100)
+	/// Storage: `Nfts::ItemAttributesApprovalsOf` (r:0 w:100)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:100)
+	/// Storage: `GameModule::RoundScores` (r:0 w:100)
+	/// Storage: `GameModule::Appeals` (r:0 w:1)
+	/// Storage: `GameModule::PlayerRounds` (r:101 w:100)
+	/// Storage: `GameModule::SuspendedAccounts` (r:0 w:1)
+	/// The range of component `n` is `[0, 100]`.
+	/// The range of component `r` is `[1, 100]`.
+	fn deregister(n: u32, r: u32) -> Weight {
+		Weight::from_parts(56_377_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(Weight::from_parts(31_842_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(6_412_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(11))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 3336).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(0, 2541).saturating_mul(r.into()))
+	}
+	/// Storage: `GameModule::RoleMembers` (r:1 w:0)
+	/// Storage: `GameModule::InviteCodes` (r:100 w:100)
+	/// The range of component `n` is `[1, 100]`.
+	fn create_invite_codes(n: u32) -> Weight {
+		Weight::from_parts(6_238_000, 0)
+			.saturating_add(Weight::from_parts(0, 3803))
+			.saturating_add(Weight::from_parts(4_106_000, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2523).saturating_mul(n.into()))
+	}
+	/// Storage: `GameModule::RoleMembers` (r:1 w:0)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Storage: `GameModule::SuspensionsEnding` (r:2 w:2)
+	/// Storage: `GameModule::Appeals` (r:0 w:1)
+	fn suspend_player() -> Weight {
+		Weight::from_parts(24_730_000, 0)
+			.saturating_add(Weight::from_parts(0, 9182))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `GameModule::RoleMembers` (r:1 w:0)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Storage: `GameModule::SuspensionsEnding` (r:1 w:1)
+	/// Storage: `GameModule::Appeals` (r:0 w:1)
+	/// Storage: `GameModule::BannedAccounts` (r:0 w:1)
+	fn ban_player() -> Weight {
+		Weight::from_parts(19_842_000, 0)
+			.saturating_add(Weight::from_parts(0, 5086))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `GameModule::RoleMembers` (r:1 w:0)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Storage: `GameModule::SuspensionsEnding` (r:1 w:1)
+	/// Storage: `GameModule::Appeals` (r:0 w:1)
+	/// Storage: `GameModule::BannedAccounts` (r:1 w:1)
+	/// Storage: `GameModule::SuspendedAccounts` (r:1 w:1)
+	fn reinstate_player() -> Weight {
+		Weight::from_parts(19_655_000, 0)
+			.saturating_add(Weight::from_parts(0, 5086))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `GameModule::Users` (r:1 w:0)
+	/// Storage: `GameModule::Appeals` (r:1 w:1)
+	fn file_appeal() -> Weight {
+		Weight::from_parts(13_127_000, 0)
+			.saturating_add(Weight::from_parts(0, 3571))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `GameModule::RoleMembers` (r:1 w:0)
+	/// Storage: `GameModule::Appeals` (r:1 w:1)
+	fn reject_appeal() -> Weight {
+		Weight::from_parts(12_218_000, 0)
+			.saturating_add(Weight::from_parts(0, 3803))
+			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 }
@@ -44,6 +44,7 @@ use frame_system::{EnsureRoot, EnsureSigned};
 pub use pallet_balances::Call as BalancesCall;
 /// Import the nft pallet
 use pallet_nfts::PalletFeatures;
+pub use pallet_sudo::Call as SudoCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
 #[cfg(any(feature = "std", test))]
@@ -108,7 +109,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -260,19 +261,56 @@ pub struct MaxProperties;
 
 impl sp_core::Get<u32> for MaxProperties {
 	fn get() -> u32 {
-		100
+		10_000
 	}
 }
 
 parameter_types! {
 	pub const GamePalletId: PalletId = PalletId(*b"py/rlxdl");
 	pub const MaxOngoingGame: u32 = 200;
+	pub const MaxOngoingGamesPerPlayer: u32 = 3;
 	pub const LeaderLimit: u32 = 10;
-	pub const MaxAdmin: u32 = 10;
+	pub const MaxRoleMembers: u32 = 10;
 	pub const RequestLimits: BlockNumber = 100800;
 	pub const GameStringLimit: u32 = 500;
+	pub const GameDuration: BlockNumber = 5 * MINUTES;
+	pub const SettlementDuration: BlockNumber = 10 * MINUTES;
+	pub const MaxScoreBands: u32 = 10;
+	pub const MaxPropertiesPerBatch: u32 = 250;
+	pub const MaxOffersPerListing: u32 = 20;
+	pub const MarketplaceFee: Permill = Permill::from_percent(2);
+	pub const AuctionDuration: BlockNumber = DAYS;
+	pub const AuctionExtension: BlockNumber = 10 * MINUTES;
+	pub const MaxAuctionsPerBlock: u32 = 20;
+	pub const MaxExpiriesPerBlock: u32 = 50;
+	pub const MaxStandingOrdersPerPair: u32 = 50;
+	pub const PointsFloor: u32 = 0;
+	pub const EntryFee: Balance = 0;
+	pub const RegistrationDeposit: Balance = DOLLARS;
+	pub const MaxInviteCodesPerCall: u32 = 100;
+	pub const FaucetDripAmount: Balance = 10 * DOLLARS;
+	pub const FaucetTarget: Balance = 10 * DOLLARS;
+	pub const FaucetCap: Balance = 10_000 * DOLLARS;
+	pub const FaucetCapPeriod: BlockNumber = DAYS;
+	pub const MaxSuspensionsPerBlock: u32 = 50;
 }
 
+/// The randomness that chooses the properties of games and the colors of won nfts.
+///
+/// The randomness of `RandomnessCollectiveFlip` is derived from the hashes of the previous blocks.
+/// It can be predicted by everybody and influenced by the block authors, so it is only suitable
+/// for development chains and has to be enabled with the `insecure-randomness` feature. A runtime
+/// for a chain with real value has to run BABE and use `pallet_game::randomness::VrfRandomness`
+/// over `pallet_babe::ParentBlockRandomness` instead.
+#[cfg(feature = "insecure-randomness")]
+pub type GameRandomness = RandomnessCollectiveFlip;
+
+#[cfg(not(feature = "insecure-randomness"))]
+compile_error!(
+	"The runtime has no secure randomness source for the game pallet. Enable the \
+	`insecure-randomness` feature for development chains."
+);
+
 /// Configure the pallet-game in pallets/game.
 impl pallet_game::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
@@ -284,11 +322,33 @@ impl pallet_game::Config for Runtime {
 	type MaxProperty = MaxProperties;
 	type PalletId = GamePalletId;
 	type MaxOngoingGames = MaxOngoingGame;
-	type GameRandomness = RandomnessCollectiveFlip;
+	type MaxOngoingGamesPerPlayer = MaxOngoingGamesPerPlayer;
+	type GameRandomness = GameRandomness;
 	type StringLimit = GameStringLimit;
 	type LeaderboardLimit = LeaderLimit;
-	type MaxAdmins = MaxAdmin;
+	type MaxRoleMembers = MaxRoleMembers;
 	type RequestLimit = RequestLimits;
+	type GameDuration = GameDuration;
+	type SettlementDuration = SettlementDuration;
+	type AuthorityId = pallet_game::crypto::OracleAuthId;
+	type MaxScoreBands = MaxScoreBands;
+	type PointsFloor = PointsFloor;
+	type MaxPropertiesPerBatch = MaxPropertiesPerBatch;
+	type MaxOffersPerListing = MaxOffersPerListing;
+	type MarketplaceFee = MarketplaceFee;
+	type AuctionDuration = AuctionDuration;
+	type AuctionExtension = AuctionExtension;
+	type MaxAuctionsPerBlock = MaxAuctionsPerBlock;
+	type MaxExpiriesPerBlock = MaxExpiriesPerBlock;
+	type MaxStandingOrdersPerPair = MaxStandingOrdersPerPair;
+	type EntryFee = EntryFee;
+	type RegistrationDeposit = RegistrationDeposit;
+	type MaxInviteCodesPerCall = MaxInviteCodesPerCall;
+	type FaucetDripAmount = FaucetDripAmount;
+	type FaucetTarget = FaucetTarget;
+	type FaucetCap = FaucetCap;
+	type FaucetCapPeriod = FaucetCapPeriod;
+	type MaxSuspensionsPerBlock = MaxSuspensionsPerBlock;
 }
 
 parameter_types! {
@@ -449,7 +509,7 @@ pub type SignedExtra = (
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (pallet_game::migrations::v1::MigrateToV1<Runtime>,);
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -628,6 +688,47 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
+	impl pallet_game_runtime_api::GameApi<Block, AccountId, u32, u32, Balance, BlockNumber>
+		for Runtime
+	{
+		fn player_profile(player: AccountId) -> Option<pallet_game::PlayerProfile<BlockNumber>> {
+			GameModule::player_profile(player)
+		}
+
+		fn open_games(player: AccountId) -> Vec<pallet_game::OpenGame<BlockNumber>> {
+			GameModule::open_games(player)
+		}
+
+		fn leaderboard() -> Vec<(AccountId, u32)> {
+			GameModule::leaderboard()
+				.into_iter()
+				.map(|(account, score)| (account, score.points))
+				.collect()
+		}
+
+		fn round_leaderboard() -> Vec<(AccountId, u32)> {
+			GameModule::round_leaderboard()
+				.into_iter()
+				.map(|(account, score)| (account, score.points))
+				.collect()
+		}
+
+		fn listings(
+			owner: Option<AccountId>,
+			color: Option<pallet_game::NftColor>,
+		) -> Vec<pallet_game::ListingDetails<AccountId, u32, u32, Balance, BlockNumber>> {
+			GameModule::listings_by(owner, color)
+		}
+
+		fn offers(
+			owner: Option<AccountId>,
+			color: Option<pallet_game::NftColor>,
+		) -> Vec<pallet_game::OfferDetails<AccountId, u32, u32>> {
+			GameModule::offers_by(owner, color)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
 		for Runtime
 	{
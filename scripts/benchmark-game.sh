@@ -0,0 +1,16 @@
+#!/usr/bin/env bash
+# Regenerates the weights of `pallet_game` with the benchmark CLI of the node.
+# The weights have to be generated on the reference hardware of the chain.
+set -e
+
+cd "$(dirname "$0")/.."
+
+cargo build --release --features runtime-benchmarks
+
+./target/release/node-template benchmark pallet \
+	--chain dev \
+	--pallet pallet_game \
+	--extrinsic "*" \
+	--steps 50 \
+	--repeat 20 \
+	--output pallets/game/src/weights.rs